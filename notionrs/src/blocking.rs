@@ -0,0 +1,294 @@
+//! A synchronous Notion API client.
+//!
+//! [`Client`] exposes the same builder-style API as [`crate::Client`], but
+//! `send()` blocks the current thread until the response arrives. It drives the
+//! async client on a single-threaded tokio runtime it owns, so no runtime has to
+//! be set up by the caller.
+//!
+//! ```no_run
+//! let client = notionrs::blocking::Client::new(std::env::var("NOTION_API_KEY").unwrap());
+//!
+//! let user = client.get_self().send().unwrap();
+//! println!("{}", user.id);
+//! ```
+//!
+//! Like `reqwest::blocking`, the blocking client must not be used from within
+//! an async runtime: `send()` panics when called on a runtime thread.
+
+use std::sync::Arc;
+
+/// A blocking Notion API client.
+///
+/// Cloning is cheap: clones share the connection pool, the configuration and
+/// the runtime that drives requests.
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+/// A request builder handed out by the blocking [`Client`].
+///
+/// It has the builder methods of the async request builder `C` it wraps, and a
+/// synchronous `send()`.
+#[derive(Debug)]
+pub struct Request<C> {
+    inner: C,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl<C> Request<C> {
+    /// Applies a builder method of the wrapped request builder.
+    pub(crate) fn forward<D>(self, f: impl FnOnce(C) -> D) -> Request<D> {
+        Request {
+            inner: f(self.inner),
+            runtime: self.runtime,
+        }
+    }
+
+    /// The wrapped request builder.
+    pub(crate) fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Runs a future made from the wrapped request builder to completion.
+    pub(crate) fn block_on<F>(self, f: impl FnOnce(C) -> F) -> F::Output
+    where
+        F: std::future::Future,
+    {
+        self.runtime.block_on(f(self.inner))
+    }
+}
+
+macro_rules! requests {
+    ($($name:ident $(<$generic:ident>)? -> $client:ty;)*) => {
+        $(
+            #[doc = concat!("Blocking version of [`Client::", stringify!($name), "`](crate::Client::", stringify!($name), ").")]
+            pub fn $name $(<$generic>)? (&self) -> Request<$client> {
+                self.request(self.inner.$name())
+            }
+        )*
+    };
+}
+
+impl Client {
+    /// Create a client for `https://api.notion.com` that authenticates with `notion_api_key`.
+    ///
+    /// Use [`Client::builder`] and [`ClientBuilder::build_blocking`](crate::ClientBuilder::build_blocking)
+    /// to configure the base URL, `Notion-Version`, timeouts or additional headers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `notion_api_key` is not a valid header value or the runtime
+    /// cannot be started.
+    pub fn new(notion_api_key: impl AsRef<str>) -> Self {
+        Self::builder()
+            .notion_api_key(notion_api_key)
+            .build_blocking()
+            .expect("Failed to build the blocking client")
+    }
+
+    /// Start configuring a client. Finish with
+    /// [`ClientBuilder::build_blocking`](crate::ClientBuilder::build_blocking).
+    pub fn builder() -> crate::client::builder::ClientBuilder {
+        crate::client::builder::ClientBuilder::default()
+    }
+
+    /// Blocking version of [`Client::with_token`](crate::Client::with_token).
+    ///
+    /// The returned client also shares the runtime of `self`.
+    pub fn with_token(&self, token: impl AsRef<str>) -> Self {
+        Self {
+            inner: self.inner.with_token(token),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Wrap an async [`Client`](crate::Client), starting a runtime to drive its requests.
+    pub(crate) fn from_async(inner: crate::Client) -> Result<Self, crate::error::Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                crate::error::Error::ClientBuild(format!("Failed to start the runtime: {e}"))
+            })?;
+
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    fn request<C>(&self, inner: C) -> Request<C> {
+        Request {
+            inner,
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    requests! {
+        get_async_task -> crate::client::async_task::get_async_task::GetAsyncTaskClient;
+
+        list_users -> crate::client::user::list_users::ListUsersClient;
+        get_user -> crate::client::user::get_user::GetUserClient;
+        get_self -> crate::client::user::get_self::GetSelfClient;
+
+        list_custom_emojis -> crate::client::custom_emoji::list_custom_emojis::ListCustomEmojisClient;
+
+        get_page<T> -> crate::client::page::get_page::GetPageClient<T>;
+        get_page_property_item -> crate::client::page::get_page_property_item::GetPagePropertyItemClient;
+        create_page<T> -> crate::client::page::create_page::CreatePageClient<T>;
+        move_page<T> -> crate::client::page::move_page::MovePageClient<T>;
+        get_page_markdown -> crate::client::page::get_page_markdown::GetPageMarkdownClient;
+        update_page_markdown -> crate::client::page::update_page_markdown::UpdatePageMarkdownClient;
+        upsert_page -> crate::client::page::upsert_page::UpsertPageClient;
+
+        create_database -> crate::client::database::create_database::CreateDatabaseClient;
+        update_database -> crate::client::database::update_database::UpdateDatabaseClient;
+        retrieve_database -> crate::client::database::retrieve_database::RetrieveDatabaseClient;
+
+        query_data_source -> crate::client::data_source::query_data_source::QueryDataSourceClient;
+        create_data_source -> crate::client::data_source::create_data_source::CreateDataSourceClient;
+        retrieve_data_source -> crate::client::data_source::retrieve_data_source::RetrieveDataSourceClient;
+        update_data_source -> crate::client::data_source::update_data_source::UpdateDataSourceClient;
+        list_data_source_templates -> crate::client::data_source::list_data_source_templates::ListDataSourceTemplatesClient;
+
+        get_block -> crate::client::block::get_block::GetBlockClient;
+        get_block_children -> crate::client::block::get_block_children::GetBlockChildrenClient;
+        delete_block -> crate::client::block::delete_block::DeleteBlockClient;
+        append_block_children -> crate::client::block::append_block_children::AppendBlockChildrenClient;
+        append_block_tree -> crate::client::block::append_block_tree::AppendBlockTreeClient;
+        update_block -> crate::client::block::update_block::UpdateBlockClient;
+        create_meeting_note -> crate::client::block::create_meeting_note::CreateMeetingNoteClient;
+        query_meeting_notes -> crate::client::block::query_meeting_notes::QueryMeetingNotesClient;
+
+        search -> crate::client::search::SearchClient;
+        search_database -> crate::client::search::SearchDatabaseClient;
+        search_page -> crate::client::search::SearchPageClient;
+
+        create_comment -> crate::client::comment::create_comment::CreateCommentClient;
+        retrieve_comments -> crate::client::comment::retrieve_comments::RetrieveCommentsClient;
+        update_comment -> crate::client::comment::update_comment::UpdateCommentClient;
+        delete_comment -> crate::client::comment::delete_comment::DeleteCommentClient;
+
+        create_file_upload -> crate::client::file_upload::create_file_upload::CreateFileUploadClient;
+        send_file_upload -> crate::client::file_upload::send_file_upload::SendFileUploadClient;
+        complete_file_upload -> crate::client::file_upload::complete_file_upload::CompleteFileUploadClient;
+        retrieve_file_upload -> crate::client::file_upload::retrieve_file_upload::RetrieveFileUploadClient;
+        list_file_uploads -> crate::client::file_upload::list_file_uploads::ListFileUploadClient;
+
+        create_view -> crate::client::view::create_view::CreateViewClient;
+        retrieve_view -> crate::client::view::retrieve_view::RetrieveViewClient;
+        update_view -> crate::client::view::update_view::UpdateViewClient;
+        delete_view -> crate::client::view::delete_view::DeleteViewClient;
+        list_views -> crate::client::view::list_views::ListViewsClient;
+        create_view_query -> crate::client::view::create_view_query::CreateViewQueryClient;
+        get_view_query_results -> crate::client::view::get_view_query_results::GetViewQueryResultsClient;
+        delete_view_query -> crate::client::view::delete_view_query::DeleteViewQueryClient;
+
+        create_oauth_token -> crate::client::oauth::create_token::CreateTokenClient;
+        refresh_oauth_token -> crate::client::oauth::refresh_token::RefreshTokenClient;
+        introspect_oauth_token -> crate::client::oauth::introspect_token::IntrospectTokenClient;
+        revoke_oauth_token -> crate::client::oauth::revoke_token::RevokeTokenClient;
+    }
+
+    /// Blocking version of [`Client::update_page`](crate::Client::update_page).
+    pub fn update_page<T>(&self) -> Request<crate::client::page::update_page::UpdatePageClient<T>>
+    where
+        T: Default,
+    {
+        self.request(self.inner.update_page())
+    }
+
+    /// Blocking version of [`Client::wait_for_async_task`](crate::Client::wait_for_async_task).
+    pub fn wait_for_async_task<T>(
+        &self,
+        task_id: T,
+    ) -> Request<crate::client::async_task::wait_for_async_task::WaitForAsyncTaskClient>
+    where
+        T: AsRef<str>,
+    {
+        self.request(self.inner.wait_for_async_task(task_id))
+    }
+
+    /// Blocking version of [`Client::duplicate_page`](crate::Client::duplicate_page).
+    pub fn duplicate_page<T>(
+        &self,
+        page_id: T,
+    ) -> Request<crate::client::page::duplicate_page::DuplicatePageClient>
+    where
+        T: AsRef<str>,
+    {
+        self.request(self.inner.duplicate_page(page_id))
+    }
+
+    /// Blocking version of [`Client::get_block_tree`](crate::Client::get_block_tree).
+    pub fn get_block_tree<T>(
+        &self,
+        block_id: T,
+    ) -> Request<crate::client::block::get_block_tree::GetBlockTreeClient>
+    where
+        T: AsRef<str>,
+    {
+        self.request(self.inner.get_block_tree(block_id))
+    }
+
+    /// Same as [`Client::oauth_authorization_url`](crate::Client::oauth_authorization_url);
+    /// building the URL sends no request.
+    pub fn oauth_authorization_url(
+        &self,
+    ) -> crate::client::oauth::authorization_url::AuthorizationUrlBuilder {
+        self.inner.oauth_authorization_url()
+    }
+
+    /// Blocking version of [`Client::to_markdown`](crate::Client::to_markdown).
+    pub fn to_markdown<T>(&self, block_id: T) -> Result<Vec<String>, crate::error::Error>
+    where
+        T: AsRef<str>,
+    {
+        self.runtime.block_on(self.inner.to_markdown(block_id))
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    const USER_BODY: &str = r#"{"object":"user","id":"user-id","type":"bot","bot":{}}"#;
+
+    #[test]
+    fn send_blocks_until_the_response_arrives() {
+        // The test server needs a runtime of its own; the blocking client brings one.
+        let server_runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = server_runtime.block_on(TestServer::start(vec![
+            TestResponse::json(200, USER_BODY),
+            TestResponse::json(
+                404,
+                r#"{"object":"error","status":404,"code":"object_not_found","message":"Not found."}"#,
+            ),
+        ]));
+
+        let client = crate::blocking::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build_blocking()
+            .unwrap();
+
+        let user = client.get_self().send().unwrap();
+        assert_eq!(user.id, "user-id");
+
+        let error = client.get_user().user_id("missing").send().unwrap_err();
+        assert!(error.is_not_found());
+
+        let requests = server_runtime.block_on(server.requests());
+        assert_eq!(requests[0].path, "/v1/users/me");
+        assert_eq!(requests[0].header("authorization"), Some("Bearer secret"));
+        assert_eq!(requests[1].path, "/v1/users/missing");
+    }
+}
@@ -1,33 +1,32 @@
 /// <https://developers.notion.com/reference/retrieve-an-async-task>
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct GetAsyncTaskClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the async task to retrieve.
     pub(crate) task_id: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl GetAsyncTaskClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::async_task::AsyncTaskResponse, crate::error::Error> {
-        let task_id = self.task_id.ok_or(crate::error::Error::RequestParameter(
-            "`task_id` is not set.".to_string(),
-        ))?;
-
-        let url = format!("https://api.notion.com/v1/async_tasks/{}", task_id);
-
-        let request = self.reqwest_client.get(url);
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
         let body = response
             .bytes()
@@ -39,6 +38,26 @@ impl GetAsyncTaskClient {
 
         Ok(task)
     }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let task_id = self.task_id.ok_or(crate::error::Error::RequestParameter(
+            "`task_id` is not set.".to_string(),
+        ))?;
+
+        let url = format!("{}/v1/async_tasks/{}", self.config.base_url, task_id);
+
+        let request = self.reqwest_client.get(url);
+
+        Ok((self.config, request))
+    }
 }
 
 // # --------------------------------------------------------------------------------
@@ -1 +1,2 @@
 pub mod get_async_task;
+pub mod wait_for_async_task;
@@ -0,0 +1,204 @@
+use notionrs_types::object::async_task::{AsyncTaskResponse, AsyncTaskSucceeded};
+
+/// Poll an async task until it succeeds or fails.
+///
+/// Between polls it waits for the `poll_after_seconds` reported by the task,
+/// or for `poll_interval` when the task reports none. To cancel waiting, drop
+/// the future returned by `send()`; the task itself keeps running on Notion's side.
+///
+/// <https://developers.notion.com/reference/retrieve-an-async-task>
+#[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
+pub struct WaitForAsyncTaskClient {
+    /// The reqwest http client
+    pub(crate) reqwest_client: reqwest::Client,
+
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// The ID of the async task to wait for.
+    pub(crate) task_id: Option<String>,
+
+    /// How long to wait for the task in total before giving up with
+    /// `Error::AsyncTaskTimedOut`. Waits without limit by default.
+    pub(crate) max_wait: Option<std::time::Duration>,
+
+    /// How long to wait between polls when the task doesn't report
+    /// `poll_after_seconds`. Defaults to 1 second.
+    pub(crate) poll_interval: Option<std::time::Duration>,
+}
+
+const DEFAULT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+#[notionrs_macro::blocking]
+impl WaitForAsyncTaskClient {
+    /// Returns the succeeded task, `Error::AsyncTaskFailed` if it failed, or
+    /// `Error::AsyncTaskTimedOut` once `max_wait` has elapsed.
+    pub async fn send(self) -> Result<AsyncTaskSucceeded, crate::error::Error> {
+        let task_id = self
+            .task_id
+            .clone()
+            .ok_or(crate::error::Error::RequestParameter(
+                "`task_id` is not set.".to_string(),
+            ))?;
+
+        match self.max_wait {
+            None => self.poll(task_id).await,
+            Some(max_wait) => tokio::time::timeout(max_wait, self.poll(task_id.clone()))
+                .await
+                .unwrap_or(Err(crate::error::Error::AsyncTaskTimedOut {
+                    task_id,
+                    waited: max_wait,
+                })),
+        }
+    }
+
+    async fn poll(&self, task_id: String) -> Result<AsyncTaskSucceeded, crate::error::Error> {
+        let poll_interval = self.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+
+        loop {
+            let task = crate::client::async_task::get_async_task::GetAsyncTaskClient {
+                reqwest_client: self.reqwest_client.clone(),
+                config: self.config.clone(),
+                task_id: Some(task_id.clone()),
+            }
+            .send()
+            .await?;
+
+            let progress = match task {
+                AsyncTaskResponse::Succeeded(succeeded) => return Ok(succeeded),
+                AsyncTaskResponse::Failed(failed) => {
+                    return Err(crate::error::Error::AsyncTaskFailed {
+                        task_id,
+                        code: failed.error.code,
+                        message: failed.error.message,
+                    });
+                }
+                AsyncTaskResponse::Queued(progress)
+                | AsyncTaskResponse::Running(progress)
+                | AsyncTaskResponse::Retrying(progress) => progress,
+            };
+
+            let delay = progress
+                .poll_after_seconds
+                .map(std::time::Duration::from_secs)
+                .unwrap_or(poll_interval);
+
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::time::Duration;
+
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    fn task(status: &str, extra: &str) -> String {
+        format!(
+            r#"{{
+                "object": "async_task",
+                "id": "task-id-123",
+                "status": "{status}",
+                "status_url": "https://api.notion.com/v1/async_tasks/task-id-123",
+                "created_time": "2026-01-01T00:00:00.000Z",
+                "operation": {{ "surface": "rest", "name": "create_page" }}
+                {extra}
+            }}"#
+        )
+    }
+
+    fn client(server: &TestServer) -> crate::Client {
+        crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn polls_until_the_task_succeeds() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &task("queued", r#", "poll_after_seconds": 0"#)),
+            TestResponse::json(200, &task("running", "")),
+            TestResponse::json(
+                200,
+                &task("succeeded", r#", "result": { "id": "page-id-456" }"#),
+            ),
+        ])
+        .await;
+
+        let succeeded = client(&server)
+            .wait_for_async_task("task-id-123")
+            .poll_interval(Duration::from_millis(1))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(succeeded.result["id"], "page-id-456");
+
+        let requests = server.requests().await;
+        assert_eq!(requests.len(), 3);
+        assert!(
+            requests
+                .iter()
+                .all(|request| request.path == "/v1/async_tasks/task-id-123")
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_task_is_an_error() {
+        let server = TestServer::start(vec![TestResponse::json(
+            200,
+            &task(
+                "failed",
+                r#", "error": { "code": "service_overload", "message": "Try again later." }"#,
+            ),
+        )])
+        .await;
+
+        let error = client(&server)
+            .wait_for_async_task("task-id-123")
+            .send()
+            .await
+            .unwrap_err();
+
+        match error {
+            crate::Error::AsyncTaskFailed {
+                task_id, message, ..
+            } => {
+                assert_eq!(task_id, "task-id-123");
+                assert_eq!(message, "Try again later.");
+            }
+            other => panic!("Expected AsyncTaskFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_wait() {
+        let server = TestServer::start(vec![TestResponse::json(
+            200,
+            &task("running", r#", "poll_after_seconds": 60"#),
+        )])
+        .await;
+
+        let error = client(&server)
+            .wait_for_async_task("task-id-123")
+            .max_wait(Duration::from_millis(50))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            crate::Error::AsyncTaskTimedOut { ref task_id, .. } if task_id == "task-id-123"
+        ));
+    }
+}
@@ -0,0 +1,881 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures::TryStreamExt;
+use notionrs_types::object::block::{Block, BlockResponse};
+
+use super::append_block_children::{AppendBlockChildrenAfterBlock, AppendBlockChildrenPosition};
+use crate::PaginateExt;
+
+/// Notion accepts at most 100 blocks in each `children` array.
+const MAX_SIBLINGS: usize = 100;
+
+/// Notion accepts at most 1000 blocks per request.
+const MAX_BLOCKS: usize = 1000;
+
+/// Notion rejects payloads over 500KB; keep some room for the request envelope.
+const MAX_PAYLOAD_BYTES: usize = 450 * 1000;
+
+type BoxFuture<'a, T> = std::pin::Pin<
+    Box<dyn std::future::Future<Output = Result<T, crate::error::Error>> + Send + 'a>,
+>;
+
+/// The children of a column, split into the blocks it can be created with and
+/// the ones appended once it exists.
+#[derive(Debug)]
+struct ColumnChildren {
+    /// The blocks created with the column.
+    inline: Vec<Block>,
+
+    /// The children of each inline block, appended once it exists.
+    nested: Vec<Vec<Block>>,
+
+    /// Tables and column lists, in runs appended after the inline block at the
+    /// given index, or at the start of the column.
+    deferred: Vec<(Option<usize>, Vec<Block>)>,
+
+    /// The blocks beyond the ones created with the column.
+    overflow: Vec<Block>,
+}
+
+impl ColumnChildren {
+    /// A table or column list in a column can't be created with its rows or
+    /// columns, which would be nested too deep, nor without them.
+    ///
+    /// The blocks created with the column take from `budget`, shared by the
+    /// columns of a column list; once it runs out, the rest overflow.
+    fn split(children: Vec<Block>, budget: &mut Budget) -> Result<Self, crate::error::Error> {
+        let mut split = Self {
+            inline: Vec::new(),
+            nested: Vec::new(),
+            deferred: Vec::new(),
+            overflow: Vec::new(),
+        };
+
+        for mut child in children {
+            if !split.overflow.is_empty() || split.inline.len() == MAX_SIBLINGS {
+                split.overflow.push(child);
+            } else if matches!(child, Block::Table { .. } | Block::ColumnList { .. }) {
+                let anchor = split.inline.len().checked_sub(1);
+                match split.deferred.last_mut() {
+                    Some((last, run)) if *last == anchor => run.push(child),
+                    _ => split.deferred.push((anchor, vec![child])),
+                }
+            } else {
+                let nested = child
+                    .children_mut()
+                    .and_then(Option::take)
+                    .unwrap_or_default();
+
+                if budget.take(serde_json::to_vec(&child)?.len()) {
+                    split.inline.push(child);
+                    split.nested.push(nested);
+                } else {
+                    if let Some(slot) = child.children_mut() {
+                        *slot = Some(nested);
+                    }
+                    split.overflow.push(child);
+                }
+            }
+        }
+
+        Ok(split)
+    }
+
+    /// A column can't be created empty, so one whose children are all
+    /// deferred or overflow is created with a placeholder, deleted once they
+    /// are appended.
+    fn needs_placeholder(&self) -> bool {
+        self.inline.is_empty() && !(self.deferred.is_empty() && self.overflow.is_empty())
+    }
+}
+
+/// The blocks and bytes left for the children of the columns in a request.
+#[derive(Debug)]
+struct Budget {
+    blocks: usize,
+    bytes: usize,
+}
+
+impl Budget {
+    /// Take one block of `bytes` from the budget, if it fits.
+    fn take(&mut self, bytes: usize) -> bool {
+        if self.blocks == 0 || bytes > self.bytes {
+            return false;
+        }
+
+        self.blocks -= 1;
+        self.bytes -= bytes;
+        true
+    }
+}
+
+/// Where to append the deferred run anchored at `anchor`, given the children
+/// the column was created with.
+fn deferred_position(
+    anchor: Option<usize>,
+    created: &[BlockResponse],
+) -> AppendBlockChildrenPosition {
+    match anchor.and_then(|index| created.get(index)) {
+        Some(block) => after_block(&block.id),
+        None => AppendBlockChildrenPosition::Start,
+    }
+}
+
+/// The empty paragraph a column is created with when it has nothing else.
+fn placeholder() -> Block {
+    Block::Paragraph {
+        paragraph: Default::default(),
+    }
+}
+
+fn after_block(id: &str) -> AppendBlockChildrenPosition {
+    AppendBlockChildrenPosition::AfterBlock {
+        after_block: AppendBlockChildrenAfterBlock { id: id.to_string() },
+    }
+}
+
+/// Append blocks nested to any depth, in any number.
+///
+/// `append_block_children` sends the blocks as they are, so the API rejects
+/// more than 100 children in a list, more than two levels of nesting, or a
+/// payload that is too large. This client splits the blocks into requests
+/// that fit: siblings are appended in chunks, each chunk after the last block
+/// of the previous one, and nested blocks are appended into their parent once
+/// it exists.
+///
+/// Tables and column lists are created with their first rows and columns, as
+/// the API requires. Tables and column lists inside a column are appended to
+/// it once it exists. When a request fails, the blocks appended so far are
+/// left in place and `Error::AppendBlockTreeIncomplete` reports them.
+#[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
+pub struct AppendBlockTreeClient {
+    /// The reqwest http client
+    pub(crate) reqwest_client: reqwest::Client,
+
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// Identifier for a block. Also accepts a page ID.
+    pub(crate) block_id: Option<String>,
+
+    /// Where to insert the first-level blocks. At the end by default.
+    #[setter(skip)]
+    pub(crate) position: Option<AppendBlockChildrenPosition>,
+
+    /// The blocks to append, with their nested blocks in `children`.
+    pub(crate) children: Vec<Block>,
+}
+
+#[notionrs_macro::blocking]
+impl AppendBlockTreeClient {
+    /// Insert the new blocks after the block with the given ID.
+    pub fn position_after_block<T: AsRef<str>>(mut self, block_id: T) -> Self {
+        self.position = Some(AppendBlockChildrenPosition::AfterBlock {
+            after_block: AppendBlockChildrenAfterBlock {
+                id: block_id.as_ref().to_string(),
+            },
+        });
+        self
+    }
+
+    /// Insert the new blocks at the start of the parent block.
+    pub fn position_start(mut self) -> Self {
+        self.position = Some(AppendBlockChildrenPosition::Start);
+        self
+    }
+
+    /// Insert the new blocks at the end of the parent block (default behavior).
+    pub fn position_end(mut self) -> Self {
+        self.position = Some(AppendBlockChildrenPosition::End);
+        self
+    }
+
+    /// Returns the created first-level blocks, in order.
+    pub async fn send(self) -> Result<Vec<BlockResponse>, crate::error::Error> {
+        let block_id = self.block_id.ok_or(crate::error::Error::RequestParameter(
+            "`block_id` is not set.".to_string(),
+        ))?;
+
+        let appender = Appender {
+            client: crate::Client {
+                reqwest_client: self.reqwest_client,
+                config: self.config,
+            },
+            appended: AtomicUsize::new(0),
+            created: Mutex::new(Vec::new()),
+        };
+
+        let total = count(&self.children);
+
+        match appender
+            .append(&block_id, self.children, self.position, true)
+            .await
+        {
+            Ok(()) => Ok(appender.created.into_inner().unwrap()),
+            Err(error) => Err(crate::error::Error::AppendBlockTreeIncomplete {
+                appended: appender.appended.load(Ordering::Relaxed),
+                total,
+                block_ids: appender
+                    .created
+                    .into_inner()
+                    .unwrap()
+                    .into_iter()
+                    .map(|block| block.id)
+                    .collect(),
+                source: Box::new(error),
+            }),
+        }
+    }
+}
+
+/// The number of blocks, nested ones included.
+fn count(blocks: &[Block]) -> usize {
+    blocks
+        .iter()
+        .map(|block| 1 + block.children().and_then(Option::as_deref).map_or(0, count))
+        .sum()
+}
+
+/// A block to send, with the nested blocks to append once it exists.
+#[derive(Debug)]
+struct Prepared {
+    block: Block,
+
+    /// The number of blocks sent with `block`, itself included.
+    size: usize,
+
+    rest: Rest,
+}
+
+#[derive(Debug)]
+enum Rest {
+    /// Children of the block.
+    Children(Vec<Block>),
+
+    /// Table rows beyond the ones created with the table.
+    TableRows(Vec<Block>),
+
+    /// What is left to append to each column.
+    Columns(Vec<ColumnRest>),
+}
+
+#[derive(Debug)]
+struct ColumnRest {
+    /// The children of the blocks created with the column.
+    nested: Vec<Vec<Block>>,
+
+    deferred: Vec<(Option<usize>, Vec<Block>)>,
+
+    overflow: Vec<Block>,
+
+    /// Whether the column was created with a placeholder.
+    placeholder: bool,
+}
+
+impl ColumnRest {
+    fn is_empty(&self) -> bool {
+        self.deferred.is_empty()
+            && self.overflow.is_empty()
+            && self.nested.iter().all(Vec::is_empty)
+    }
+}
+
+/// Split the nested blocks the API can't create along with `block`.
+fn prepare(mut block: Block) -> Result<Prepared, crate::error::Error> {
+    let mut children = block
+        .children_mut()
+        .and_then(Option::take)
+        .unwrap_or_default();
+
+    let (inline, rest) = match &block {
+        Block::Table { .. } => {
+            let rows = children.split_off(children.len().min(MAX_SIBLINGS));
+            (Some(std::mem::replace(&mut children, rows)), None)
+        }
+        Block::ColumnList { .. } => {
+            let mut columns = Vec::new();
+            let mut rests = Vec::new();
+
+            let column_children = children
+                .iter_mut()
+                .map(|column| {
+                    column
+                        .children_mut()
+                        .and_then(Option::take)
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+
+            // Keep room for the list itself and each column with a placeholder.
+            let mut skeleton = serde_json::to_vec(&block)?.len();
+            for column in &children {
+                skeleton +=
+                    serde_json::to_vec(column)?.len() + serde_json::to_vec(&placeholder())?.len();
+            }
+            let mut budget = Budget {
+                blocks: MAX_BLOCKS.saturating_sub(1 + 2 * children.len()),
+                bytes: MAX_PAYLOAD_BYTES.saturating_sub(skeleton),
+            };
+
+            for (mut column, column_children) in children.drain(..).zip(column_children) {
+                let mut split = ColumnChildren::split(column_children, &mut budget)?;
+                let needs_placeholder = split.needs_placeholder();
+
+                if needs_placeholder {
+                    split.inline.push(placeholder());
+                }
+
+                if let Some(slot) = column.children_mut() {
+                    *slot = Some(split.inline);
+                }
+
+                columns.push(column);
+                rests.push(ColumnRest {
+                    nested: split.nested,
+                    deferred: split.deferred,
+                    overflow: split.overflow,
+                    placeholder: needs_placeholder,
+                });
+            }
+
+            (Some(columns), Some(Rest::Columns(rests)))
+        }
+        _ => (None, None),
+    };
+
+    let rest = match (&block, rest) {
+        (_, Some(rest)) => rest,
+        (Block::Table { .. }, None) => Rest::TableRows(children),
+        _ => Rest::Children(children),
+    };
+
+    let size = 1 + inline.as_deref().map_or(0, count);
+
+    if let (Some(inline), Some(slot)) = (inline, block.children_mut()) {
+        *slot = Some(inline);
+    }
+
+    Ok(Prepared { block, size, rest })
+}
+
+#[derive(Debug)]
+struct Appender {
+    client: crate::Client,
+
+    /// The number of blocks created so far, nested ones included.
+    appended: AtomicUsize,
+
+    /// The created first-level blocks.
+    created: Mutex<Vec<BlockResponse>>,
+}
+
+impl Appender {
+    /// Append `blocks` to `parent` in as many requests as needed, then their
+    /// nested blocks.
+    fn append<'a>(
+        &'a self,
+        parent: &'a str,
+        blocks: Vec<Block>,
+        mut position: Option<AppendBlockChildrenPosition>,
+        first_level: bool,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let prepared = blocks
+                .into_iter()
+                .map(prepare)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for prepared in &prepared {
+                let bytes = serde_json::to_vec(&prepared.block)?.len();
+                if prepared.size > MAX_BLOCKS || bytes > MAX_PAYLOAD_BYTES {
+                    return Err(crate::error::Error::RequestParameter(format!(
+                        "A block with {} nested blocks in {bytes} bytes can't be sent in one request.",
+                        prepared.size - 1
+                    )));
+                }
+            }
+
+            let mut prepared = prepared.into_iter().peekable();
+
+            while prepared.peek().is_some() {
+                let mut chunk = Vec::new();
+                let (mut size, mut bytes) = (0, 0);
+
+                while let Some(next) = prepared.peek() {
+                    let next_bytes = serde_json::to_vec(&next.block)?.len();
+
+                    if !chunk.is_empty()
+                        && (chunk.len() == MAX_SIBLINGS
+                            || size + next.size > MAX_BLOCKS
+                            || bytes + next_bytes > MAX_PAYLOAD_BYTES)
+                    {
+                        break;
+                    }
+
+                    size += next.size;
+                    bytes += next_bytes;
+                    chunk.extend(prepared.next());
+                }
+
+                let mut request = self
+                    .client
+                    .append_block_children()
+                    .block_id(parent)
+                    .children(
+                        chunk
+                            .iter()
+                            .map(|prepared| prepared.block.clone())
+                            .collect(),
+                    );
+                request.position = position.take();
+
+                let created = request.send().await?.results;
+
+                self.appended.fetch_add(size, Ordering::Relaxed);
+
+                if let Some(last) = created.last() {
+                    position = Some(after_block(&last.id));
+                }
+
+                let ids = created
+                    .iter()
+                    .map(|block| block.id.clone())
+                    .collect::<Vec<_>>();
+
+                if first_level {
+                    self.created.lock().unwrap().extend(created);
+                }
+
+                for (prepared, id) in chunk.into_iter().zip(ids) {
+                    self.finish(prepared.rest, &id).await?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Append what the block `id` couldn't be created with.
+    async fn finish(&self, rest: Rest, id: &str) -> Result<(), crate::error::Error> {
+        match rest {
+            Rest::Children(children) | Rest::TableRows(children) => {
+                if !children.is_empty() {
+                    self.append(id, children, None, false).await?;
+                }
+            }
+            Rest::Columns(columns) => {
+                if columns.iter().all(ColumnRest::is_empty) {
+                    return Ok(());
+                }
+
+                let created_columns = self.children_of(id).await?;
+
+                for (rest, column) in columns.into_iter().zip(created_columns) {
+                    let created_children = if rest.placeholder
+                        || !rest.deferred.is_empty()
+                        || rest.nested.iter().any(|n| !n.is_empty())
+                    {
+                        self.children_of(&column.id).await?
+                    } else {
+                        Vec::new()
+                    };
+
+                    for (children, child) in rest.nested.into_iter().zip(&created_children) {
+                        if !children.is_empty() {
+                            self.append(&child.id, children, None, false).await?;
+                        }
+                    }
+
+                    for (anchor, run) in rest.deferred {
+                        let position = deferred_position(anchor, &created_children);
+                        self.append(&column.id, run, Some(position), false).await?;
+                    }
+
+                    if !rest.overflow.is_empty() {
+                        self.append(&column.id, rest.overflow, None, false).await?;
+                    }
+
+                    if rest.placeholder {
+                        if let Some(placeholder) = created_children.first() {
+                            self.client
+                                .delete_block()
+                                .block_id(&placeholder.id)
+                                .send()
+                                .await?;
+                            self.appended.fetch_sub(1, Ordering::Relaxed);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn children_of(&self, id: &str) -> Result<Vec<BlockResponse>, crate::error::Error> {
+        self.client
+            .get_block_children()
+            .block_id(id)
+            .into_stream()
+            .try_collect()
+            .await
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::util::test_server::{TestResponse, TestServer, block_json, list_json};
+
+    fn paragraph(text: &str, children: Vec<Block>) -> Block {
+        let mut block = serde_json::from_value::<Block>(serde_json::json!({
+            "type": "paragraph",
+            "paragraph": {
+                "rich_text": [{
+                    "type": "text",
+                    "text": { "content": text, "link": null },
+                    "annotations": {
+                        "bold": false, "italic": false, "strikethrough": false,
+                        "underline": false, "code": false, "color": "default"
+                    },
+                    "plain_text": text,
+                    "href": null
+                }],
+                "color": "default"
+            }
+        }))
+        .unwrap();
+
+        if !children.is_empty() {
+            *block.children_mut().unwrap() = Some(children);
+        }
+
+        block
+    }
+
+    fn created_block(id: &str) -> serde_json::Value {
+        block_json(
+            id,
+            false,
+            serde_json::json!({
+                "type": "paragraph",
+                "paragraph": { "rich_text": [], "color": "default" }
+            }),
+        )
+    }
+
+    fn created(ids: impl IntoIterator<Item = String>) -> String {
+        list_json(ids.into_iter().map(|id| created_block(&id)).collect()).to_string()
+    }
+
+    fn table(rows: usize) -> Block {
+        let row = serde_json::json!({
+            "type": "table_row",
+            "table_row": { "cells": [[]] }
+        });
+        serde_json::from_value::<Block>(serde_json::json!({
+            "type": "table",
+            "table": {
+                "table_width": 1,
+                "has_column_header": false,
+                "has_row_header": false,
+                "children": vec![row; rows]
+            }
+        }))
+        .unwrap()
+    }
+
+    fn column_list(columns: Vec<Vec<Block>>) -> Block {
+        Block::ColumnList {
+            column_list: notionrs_types::object::block::column_list::ColumnListBlock {
+                children: Some(
+                    columns
+                        .into_iter()
+                        .map(|children| Block::Column {
+                            column: notionrs_types::object::block::column::ColumnBlock {
+                                children: Some(children),
+                                width_ratio: 0.5,
+                            },
+                        })
+                        .collect(),
+                ),
+            },
+        }
+    }
+
+    fn client(server: &TestServer) -> crate::Client {
+        crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap()
+    }
+
+    /// 101 paragraphs, the first one nested three levels deep.
+    fn blocks() -> Vec<Block> {
+        let mut blocks = vec![paragraph(
+            "p0",
+            vec![paragraph("p0.0", vec![paragraph("p0.0.0", vec![])])],
+        )];
+        blocks.extend((1..101).map(|i| paragraph(&format!("p{i}"), vec![])));
+        blocks
+    }
+
+    #[tokio::test]
+    async fn append_in_chunks_and_levels() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &created((0..100).map(|i| format!("b{i}")))),
+            TestResponse::json(200, &created(["b0.0".to_string()])),
+            TestResponse::json(200, &created(["b0.0.0".to_string()])),
+            TestResponse::json(200, &created(["b100".to_string()])),
+        ])
+        .await;
+
+        let created = client(&server)
+            .append_block_tree()
+            .block_id("page-id")
+            .children(blocks())
+            .position_start()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(created.len(), 101);
+        assert_eq!(created[100].id, "b100");
+
+        let requests = server.requests().await;
+        let paths = requests
+            .iter()
+            .map(|request| request.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/v1/blocks/page-id/children",
+                "/v1/blocks/b0/children",
+                "/v1/blocks/b0.0/children",
+                "/v1/blocks/page-id/children",
+            ]
+        );
+
+        let first = requests[0].json();
+        assert_eq!(first["children"].as_array().unwrap().len(), 100);
+        assert!(first["children"][0]["paragraph"].get("children").is_none());
+        assert_eq!(first["position"]["type"], "start");
+
+        assert_eq!(
+            requests[1].json()["children"][0]["paragraph"]["rich_text"][0]["plain_text"],
+            "p0.0"
+        );
+
+        let last = requests[3].json();
+        assert_eq!(last["children"].as_array().unwrap().len(), 1);
+        assert_eq!(
+            last["position"],
+            serde_json::json!({ "type": "after_block", "after_block": { "id": "b99" } })
+        );
+    }
+
+    #[tokio::test]
+    async fn report_progress_on_failure() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &created((0..100).map(|i| format!("b{i}")))),
+            TestResponse::json(
+                400,
+                r#"{ "object": "error", "status": 400, "code": "validation_error", "message": "Invalid block." }"#,
+            ),
+        ])
+        .await;
+
+        let error = client(&server)
+            .append_block_tree()
+            .block_id("page-id")
+            .children(blocks())
+            .send()
+            .await
+            .unwrap_err();
+
+        match error {
+            crate::Error::AppendBlockTreeIncomplete {
+                appended,
+                total,
+                block_ids,
+                source,
+            } => {
+                assert_eq!(appended, 100);
+                assert_eq!(total, 103);
+                assert_eq!(block_ids.len(), 100);
+                assert!(matches!(*source, crate::Error::Http { status: 400, .. }));
+            }
+            other => panic!("Expected AppendBlockTreeIncomplete, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn create_tables_with_their_first_rows() {
+        let prepared = prepare(table(150)).unwrap();
+
+        assert_eq!(prepared.size, 101);
+        match prepared.rest {
+            Rest::TableRows(rows) => assert_eq!(rows.len(), 50),
+            other => panic!("Expected the remaining rows, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn overflow_column_children_beyond_the_request_limits() {
+        let columns = (0..20)
+            .map(|c| {
+                (0..100)
+                    .map(|i| paragraph(&format!("c{c}.{i}"), vec![]))
+                    .collect()
+            })
+            .collect();
+
+        let prepared = prepare(column_list(columns)).unwrap();
+
+        assert!(prepared.size <= MAX_BLOCKS);
+        assert!(serde_json::to_vec(&prepared.block).unwrap().len() <= MAX_PAYLOAD_BYTES);
+
+        let Rest::Columns(rests) = prepared.rest else {
+            panic!("Expected the rest of the columns, got {:?}", prepared.rest);
+        };
+        let kept = rests
+            .iter()
+            .map(|rest| rest.nested.len() + rest.overflow.len())
+            .sum::<usize>();
+        assert_eq!(kept, 2000);
+        assert!(rests[0].overflow.is_empty());
+        assert!(rests[19].placeholder);
+        assert_eq!(rests[19].overflow.len(), 100);
+    }
+
+    #[tokio::test]
+    async fn reject_a_block_too_large_for_one_request() {
+        let server = TestServer::start(vec![]).await;
+
+        let cell = serde_json::json!([{
+            "type": "text",
+            "text": { "content": "x".repeat(5000), "link": null },
+            "annotations": {
+                "bold": false, "italic": false, "strikethrough": false,
+                "underline": false, "code": false, "color": "default"
+            },
+            "plain_text": "",
+            "href": null
+        }]);
+        let mut large = table(100);
+        for row in large.children_mut().unwrap().as_mut().unwrap() {
+            *row = serde_json::from_value(serde_json::json!({
+                "type": "table_row",
+                "table_row": { "cells": [cell] }
+            }))
+            .unwrap();
+        }
+
+        let error = client(&server)
+            .append_block_tree()
+            .block_id("page-id")
+            .children(vec![large])
+            .send()
+            .await
+            .unwrap_err();
+
+        match error {
+            crate::Error::AppendBlockTreeIncomplete {
+                appended, source, ..
+            } => {
+                assert_eq!(appended, 0);
+                assert!(matches!(*source, crate::Error::RequestParameter(_)));
+            }
+            other => panic!("Expected AppendBlockTreeIncomplete, got {other:?}"),
+        }
+        assert!(server.requests().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn append_tables_in_columns_once_the_column_exists() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &created(["cl".to_string()])),
+            TestResponse::json(200, &created(["c1".to_string(), "c2".to_string()])),
+            TestResponse::json(200, &created(["a1".to_string()])),
+            TestResponse::json(200, &created(["t1".to_string()])),
+            TestResponse::json(200, &created(["ph".to_string()])),
+            TestResponse::json(200, &created(["t2".to_string()])),
+            TestResponse::json(200, &created_block("ph").to_string()),
+        ])
+        .await;
+
+        client(&server)
+            .append_block_tree()
+            .block_id("page-id")
+            .children(vec![column_list(vec![
+                vec![paragraph("a", vec![]), table(2)],
+                vec![table(1)],
+            ])])
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+        let calls = requests
+            .iter()
+            .map(|request| {
+                let path = request.path.split('?').next().unwrap();
+                format!("{} {path}", request.method)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            calls,
+            vec![
+                "PATCH /v1/blocks/page-id/children",
+                "GET /v1/blocks/cl/children",
+                "GET /v1/blocks/c1/children",
+                "PATCH /v1/blocks/c1/children",
+                "GET /v1/blocks/c2/children",
+                "PATCH /v1/blocks/c2/children",
+                "DELETE /v1/blocks/ph",
+            ]
+        );
+
+        let columns = &requests[0].json()["children"][0]["column_list"]["children"];
+        let first = columns[0]["column"]["children"].as_array().unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0]["paragraph"]["rich_text"][0]["plain_text"], "a");
+        assert_eq!(
+            columns[1]["column"]["children"][0]["paragraph"]["rich_text"],
+            serde_json::json!([])
+        );
+
+        let table_in_first = requests[3].json();
+        assert_eq!(
+            table_in_first["position"],
+            serde_json::json!({ "type": "after_block", "after_block": { "id": "a1" } })
+        );
+        assert_eq!(
+            table_in_first["children"][0]["table"]["children"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let table_in_second = requests[5].json();
+        assert_eq!(table_in_second["position"]["type"], "start");
+        assert_eq!(
+            table_in_second["children"][0]["table"]["children"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -10,10 +10,14 @@ use serde::Serialize;
 ///
 /// <https://developers.notion.com/reference/create-meeting-note>
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct CreateMeetingNoteClient {
     /// The reqwest HTTP client.
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) source: Option<CreateMeetingNoteSource>,
 
     pub(crate) parent_page_id: Option<String>,
@@ -53,6 +57,7 @@ struct CreateMeetingNoteOptions {
     kickoff_summary: bool,
 }
 
+#[notionrs_macro::blocking]
 impl CreateMeetingNoteClient {
     /// Use a completed public API file upload as the source.
     pub fn file_upload_source(mut self, file_upload_id: impl AsRef<str>) -> Self {
@@ -102,20 +107,44 @@ impl CreateMeetingNoteClient {
         })
     }
 
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     /// Create a meeting note and begin processing its source media.
     ///
     /// <https://developers.notion.com/reference/create-meeting-note>
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::block::CreateMeetingNoteResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+
+        crate::util::send_and_convert(&config, request).await
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let reqwest_client = self.reqwest_client.clone();
+        let config = self.config.clone();
+        let url = format!("{}/v1/blocks/meeting_notes", config.base_url);
         let request_body = serde_json::to_string(&self.into_request_body()?)?;
         let request = reqwest_client
-            .post("https://api.notion.com/v1/blocks/meeting_notes")
+            .post(url)
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        crate::util::send_and_convert(request).await
+        Ok((config, request))
     }
 }
 
@@ -1,32 +1,31 @@
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct DeleteBlockClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) block_id: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl DeleteBlockClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     // TODO: docs for send
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::block::BlockResponse, crate::error::Error> {
-        let block_id = self.block_id.ok_or(crate::error::Error::RequestParameter(
-            "`block_id` is not set.".to_string(),
-        ))?;
-
-        let url = format!("https://api.notion.com/v1/blocks/{}", block_id);
-
-        let request = self.reqwest_client.delete(url);
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
         let body = response
             .bytes()
@@ -37,4 +36,24 @@ impl DeleteBlockClient {
 
         Ok(block)
     }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let block_id = self.block_id.ok_or(crate::error::Error::RequestParameter(
+            "`block_id` is not set.".to_string(),
+        ))?;
+
+        let url = format!("{}/v1/blocks/{}", self.config.base_url, block_id);
+
+        let request = self.reqwest_client.delete(url);
+
+        Ok((self.config, request))
+    }
 }
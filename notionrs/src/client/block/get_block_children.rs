@@ -1,8 +1,12 @@
 #[derive(Debug, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct GetBlockChildrenClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) block_id: Option<String>,
 
     pub(crate) page_size: u64,
@@ -19,6 +23,7 @@ impl Default for GetBlockChildrenClient {
     fn default() -> Self {
         Self {
             reqwest_client: reqwest::Client::default(),
+            config: Default::default(),
             block_id: None,
             page_size: 100,
             start_cursor: None,
@@ -26,7 +31,16 @@ impl Default for GetBlockChildrenClient {
     }
 }
 
+#[notionrs_macro::blocking]
 impl GetBlockChildrenClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     // TODO: docs for send
     pub async fn send(
         self,
@@ -36,15 +50,39 @@ impl GetBlockChildrenClient {
         >,
         crate::error::Error,
     > {
-        let mut result_blocks: Vec<notionrs_types::object::block::BlockResponse> = vec![];
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let list_response = serde_json::from_slice::<
+            notionrs_types::object::response::ListResponse<
+                notionrs_types::object::block::BlockResponse,
+            >,
+        >(&body)?;
 
+        Ok(list_response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let block_id = &self.block_id.ok_or(crate::error::Error::RequestParameter(
             "`block_id` is not set.".to_string(),
         ))?;
 
         let start_cursor = self.start_cursor;
 
-        let url = format!("https://api.notion.com/v1/blocks/{}/children", block_id);
+        let url = format!("{}/v1/blocks/{}/children", self.config.base_url, block_id);
 
         let mut query_params: Vec<(String, String)> =
             vec![("page_size".to_string(), self.page_size.to_string())];
@@ -55,35 +93,6 @@ impl GetBlockChildrenClient {
 
         let request = self.reqwest_client.get(url).query(&query_params);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let list_response = serde_json::from_slice::<
-            notionrs_types::object::response::ListResponse<
-                notionrs_types::object::block::BlockResponse,
-            >,
-        >(&body)?;
-
-        result_blocks.extend(list_response.results);
-
-        Ok(notionrs_types::object::response::ListResponse {
-            object: "list".into(),
-            results: result_blocks,
-            next_cursor: start_cursor.clone(),
-            has_more: Some(start_cursor.is_some()),
-            r#type: Some("list".into()),
-            request_status: None,
-        })
+        Ok((self.config, request))
     }
 }
@@ -0,0 +1,325 @@
+use futures::{StreamExt, TryStreamExt};
+use notionrs_types::object::block::{
+    Block, BlockResponse,
+    tree::{BlockNode, BlockTree},
+};
+
+use crate::PaginateExt;
+
+/// Fetch the blocks under a page or block, with all their descendants.
+///
+/// Children are fetched one level at a time, with up to `concurrency`
+/// `get_block_children` calls in flight. Synced blocks that copy another
+/// block get the children of the original; when the original isn't shared
+/// with the integration, they are left without children. Child pages and
+/// child databases are not descended into unless `descend_into_child_pages`
+/// is set.
+#[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
+pub struct GetBlockTreeClient {
+    /// The reqwest http client
+    pub(crate) reqwest_client: reqwest::Client,
+
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// The ID of the page or block to fetch the tree of.
+    pub(crate) block_id: Option<String>,
+
+    /// How many levels of blocks to fetch, `1` being the children of
+    /// `block_id` only. Unlimited by default.
+    pub(crate) max_depth: Option<usize>,
+
+    /// How many children lists to fetch at once. Defaults to 3, Notion's
+    /// average rate limit per second.
+    pub(crate) concurrency: Option<usize>,
+
+    /// Whether to also fetch the content of child pages and child databases.
+    pub(crate) descend_into_child_pages: bool,
+}
+
+const DEFAULT_CONCURRENCY: usize = 3;
+
+#[notionrs_macro::blocking]
+impl GetBlockTreeClient {
+    pub async fn send(self) -> Result<BlockTree, crate::error::Error> {
+        let block_id = self.block_id.ok_or(crate::error::Error::RequestParameter(
+            "`block_id` is not set.".to_string(),
+        ))?;
+
+        let client = crate::Client {
+            reqwest_client: self.reqwest_client,
+            config: self.config,
+        };
+        let concurrency = self.concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1);
+
+        // Blocks are kept flat while fetching, each with the indexes of its
+        // children, and nested once every level is fetched.
+        let mut blocks = Vec::<Option<BlockResponse>>::new();
+        let mut children = Vec::<Vec<usize>>::new();
+        let mut roots = Vec::new();
+
+        // (index of the parent block, ID of the block to list the children of,
+        // whether that block is the original of a synced block)
+        let mut frontier = vec![(None, block_id.clone(), false)];
+        let mut depth = 0;
+
+        while !frontier.is_empty() && self.max_depth.is_none_or(|max| depth < max) {
+            let fetched = futures::stream::iter(frontier)
+                .map(|(parent, id, synced)| {
+                    let client = &client;
+                    async move {
+                        let result = client
+                            .get_block_children()
+                            .block_id(&id)
+                            .into_stream()
+                            .try_collect::<Vec<_>>()
+                            .await;
+
+                        match result {
+                            Ok(blocks) => Ok((parent, blocks)),
+                            // The original of a synced block may not be shared with the integration.
+                            Err(crate::error::Error::Http { status: 404, .. }) if synced => {
+                                Ok((parent, Vec::new()))
+                            }
+                            Err(error) => Err(error),
+                        }
+                    }
+                })
+                .buffered(concurrency)
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            frontier = Vec::new();
+            depth += 1;
+
+            for (parent, fetched) in fetched {
+                for block in fetched {
+                    let index = blocks.len();
+
+                    if let Some((id, synced)) =
+                        children_source(&block, self.descend_into_child_pages)
+                    {
+                        frontier.push((Some(index), id, synced));
+                    }
+
+                    blocks.push(Some(block));
+                    children.push(Vec::new());
+
+                    match parent {
+                        Some(parent) => children[parent].push(index),
+                        None => roots.push(index),
+                    }
+                }
+            }
+        }
+
+        fn nest(
+            index: usize,
+            blocks: &mut [Option<BlockResponse>],
+            children: &[Vec<usize>],
+        ) -> BlockNode {
+            BlockNode {
+                block: blocks[index].take().expect("every block is nested once"),
+                children: children[index]
+                    .iter()
+                    .map(|&child| nest(child, blocks, children))
+                    .collect(),
+            }
+        }
+
+        Ok(BlockTree {
+            block_id,
+            children: roots
+                .into_iter()
+                .map(|index| nest(index, &mut blocks, &children))
+                .collect(),
+        })
+    }
+}
+
+/// The block to list the children of for `block`, and whether it is the
+/// original of a synced block, or `None` when its children aren't fetched.
+fn children_source(
+    block: &BlockResponse,
+    descend_into_child_pages: bool,
+) -> Option<(String, bool)> {
+    match &block.block {
+        Block::SyncedBlock { synced_block } => match &synced_block.synced_from {
+            Some(synced_from) => Some((synced_from.block_id.clone(), true)),
+            None => block.has_children.then(|| (block.id.clone(), false)),
+        },
+        Block::ChildPage { .. } | Block::ChildDatabase { .. } if !descend_into_child_pages => None,
+        _ => block.has_children.then(|| (block.id.clone(), false)),
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::util::test_server::{TestResponse, TestServer, block_json, list_json};
+
+    fn toggle(id: &str, has_children: bool) -> serde_json::Value {
+        block_json(
+            id,
+            has_children,
+            serde_json::json!({
+                "type": "toggle",
+                "toggle": { "rich_text": [], "color": "default" }
+            }),
+        )
+    }
+
+    fn child_page(id: &str) -> serde_json::Value {
+        block_json(
+            id,
+            true,
+            serde_json::json!({ "type": "child_page", "child_page": { "title": "Child" } }),
+        )
+    }
+
+    fn synced_copy(id: &str, original_id: &str) -> serde_json::Value {
+        block_json(
+            id,
+            false,
+            serde_json::json!({
+                "type": "synced_block",
+                "synced_block": {
+                    "synced_from": { "type": "block_id", "block_id": original_id }
+                }
+            }),
+        )
+    }
+
+    fn client(server: &TestServer) -> crate::Client {
+        crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetch_tree_level_by_level() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &list_json(vec![
+                    toggle("a", true),
+                    synced_copy("b", "original"),
+                    child_page("c"),
+                ])
+                .to_string(),
+            ),
+            TestResponse::json(200, &list_json(vec![toggle("a1", true)]).to_string()),
+            TestResponse::json(
+                200,
+                &list_json(vec![toggle("original-1", false)]).to_string(),
+            ),
+            TestResponse::json(200, &list_json(vec![toggle("a1x", false)]).to_string()),
+        ])
+        .await;
+
+        let tree = client(&server)
+            .get_block_tree("page-id")
+            .concurrency(1)
+            .send()
+            .await
+            .unwrap();
+
+        let visited = tree
+            .iter()
+            .map(|(depth, node)| (depth, node.block.id.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            visited,
+            vec![
+                (0, "a"),
+                (1, "a1"),
+                (2, "a1x"),
+                (0, "b"),
+                (1, "original-1"),
+                (0, "c"),
+            ]
+        );
+
+        let paths = server
+            .requests()
+            .await
+            .into_iter()
+            .map(|request| request.path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            vec![
+                "/v1/blocks/page-id/children?page_size=100",
+                "/v1/blocks/a/children?page_size=100",
+                "/v1/blocks/original/children?page_size=100",
+                "/v1/blocks/a1/children?page_size=100",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stop_at_max_depth() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &list_json(vec![toggle("a", true)]).to_string()),
+            TestResponse::json(200, &list_json(vec![toggle("a1", true)]).to_string()),
+        ])
+        .await;
+
+        let tree = client(&server)
+            .get_block_tree("page-id")
+            .max_depth(2)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(tree.len(), 2);
+        assert!(tree.children[0].children[0].children.is_empty());
+        assert_eq!(server.requests().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn descend_into_child_pages() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &list_json(vec![child_page("c")]).to_string()),
+            TestResponse::json(200, &list_json(vec![toggle("c1", false)]).to_string()),
+        ])
+        .await;
+
+        let tree = client(&server)
+            .get_block_tree("page-id")
+            .descend_into_child_pages(true)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(tree.children[0].children[0].block.id, "c1");
+    }
+
+    #[tokio::test]
+    async fn missing_synced_original_has_no_children() {
+        let server = TestServer::start(vec![TestResponse::json(
+            200,
+            &list_json(vec![synced_copy("b", "original")]).to_string(),
+        )])
+        .await;
+
+        let tree = client(&server)
+            .get_block_tree("page-id")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(tree.len(), 1);
+        assert!(tree.children[0].children.is_empty());
+    }
+}
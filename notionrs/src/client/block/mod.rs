@@ -1,7 +1,9 @@
 pub mod append_block_children;
+pub mod append_block_tree;
 pub mod create_meeting_note;
 pub mod delete_block;
 pub mod get_block;
 pub mod get_block_children;
+pub mod get_block_tree;
 pub mod query_meeting_notes;
 pub mod update_block;
@@ -7,18 +7,23 @@ use notionrs_types::object::request::meeting_notes::{
 /// Client for the query meeting notes endpoint.
 ///
 /// <https://developers.notion.com/reference/query-meeting-notes>
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct QueryMeetingNotesClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) filter: Option<MeetingNotesCombinatorFilter>,
 
     pub(crate) sort: Vec<MeetingNotesSort>,
 
     pub(crate) limit: Option<u32>,
+
+    pub(crate) start_cursor: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl QueryMeetingNotesClient {
     /// Set the filter for the meeting notes query.
     pub fn filter(mut self, filter: MeetingNotesCombinatorFilter) -> Self {
@@ -37,6 +42,52 @@ impl QueryMeetingNotesClient {
         self.limit = Some(limit);
         self
     }
+
+    /// Set the cursor to continue from, the `next_cursor` of a previous response.
+    pub fn start_cursor<S: AsRef<str>>(mut self, start_cursor: S) -> Self {
+        self.start_cursor = Some(start_cursor.as_ref().to_string());
+        self
+    }
+}
+
+impl crate::r#trait::Paginate<notionrs_types::object::block::MeetingNotesBlockResponse>
+    for QueryMeetingNotesClient
+{
+    fn paginate_start_cursor(self, start_cursor: Option<String>) -> Self {
+        match start_cursor {
+            Some(c) => self.start_cursor(c),
+            None => self,
+        }
+    }
+
+    fn paginate_send(
+        self,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<
+                        notionrs_types::object::response::ListResponse<
+                            notionrs_types::object::block::MeetingNotesBlockResponse,
+                        >,
+                        crate::error::Error,
+                    >,
+                > + Send
+                + Sync,
+        >,
+    > {
+        Box::pin(async {
+            let response = self.send().await?;
+
+            Ok(notionrs_types::object::response::ListResponse {
+                object: "list".to_string(),
+                results: response.results,
+                next_cursor: response.next_cursor,
+                has_more: Some(response.has_more),
+                r#type: None,
+                request_status: None,
+            })
+        })
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -49,24 +100,58 @@ pub struct QueryMeetingNotesRequestBody {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) start_cursor: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl QueryMeetingNotesClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     /// Send the query meeting notes request.
     ///
     /// <https://developers.notion.com/reference/query-meeting-notes>
     pub async fn send(
         self,
+    ) -> Result<notionrs_types::object::block::QueryMeetingNotesResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let result = serde_json::from_slice::<
+            notionrs_types::object::block::QueryMeetingNotesResponse,
+        >(&body)?;
+
+        Ok(result)
+    }
+
+    fn into_request(
+        self,
     ) -> Result<
-        notionrs_types::object::block::QueryMeetingNotesResponse,
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
         crate::error::Error,
     > {
-        let url = "https://api.notion.com/v1/blocks/meeting_notes/query";
+        let url = format!("{}/v1/blocks/meeting_notes/query", self.config.base_url);
 
         let request_body = serde_json::to_string(&QueryMeetingNotesRequestBody {
             filter: self.filter,
             sort: self.sort,
             limit: self.limit,
+            start_cursor: self.start_cursor,
         })?;
 
         let request = self
@@ -75,24 +160,53 @@ impl QueryMeetingNotesClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+        Ok((self.config, request))
+    }
+}
 
-        let body = response
-            .bytes()
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use futures::TryStreamExt;
+
+    use crate::PaginateExt;
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    #[tokio::test]
+    async fn into_page_stream_follows_next_cursor() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                r#"{"results":[],"has_more":true,"next_cursor":"cursor-2"}"#,
+            ),
+            TestResponse::json(200, r#"{"results":[],"has_more":false}"#),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let pages = client
+            .query_meeting_notes()
+            .limit(10)
+            .into_page_stream()
+            .try_collect::<Vec<_>>()
             .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let result = serde_json::from_slice::<
-            notionrs_types::object::block::QueryMeetingNotesResponse,
-        >(&body)?;
-
-        Ok(result)
+            .unwrap();
+        assert_eq!(pages.len(), 2);
+
+        let requests = server.requests().await;
+        assert_eq!(requests[0].json(), serde_json::json!({ "limit": 10 }));
+        assert_eq!(
+            requests[1].json(),
+            serde_json::json!({ "limit": 10, "start_cursor": "cursor-2" })
+        );
     }
 }
@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct UpdateBlockClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// Identifier for a block. Also accepts a page ID.
     pub(crate) block_id: Option<String>,
 
@@ -25,6 +29,7 @@ pub struct UpdateBlockRequestBody {
     pub(crate) archived: Option<bool>,
 }
 
+#[notionrs_macro::blocking]
 impl UpdateBlockClient {
     /// Set the value of the `archived` field.
     #[deprecated(note = "Use `in_trash` instead. Deprecated in 2026-03-11 API version.")]
@@ -33,10 +38,40 @@ impl UpdateBlockClient {
         self
     }
 
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     // TODO: docs for send
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::block::BlockResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let block = serde_json::from_slice::<notionrs_types::object::block::BlockResponse>(&body)?;
+
+        Ok(block)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let block_id = self.block_id.ok_or(crate::error::Error::RequestParameter(
             "`block_id` is not set.".to_string(),
         ))?;
@@ -52,7 +87,7 @@ impl UpdateBlockClient {
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = format!("https://api.notion.com/v1/blocks/{}", block_id);
+        let url = format!("{}/v1/blocks/{}", self.config.base_url, block_id);
 
         let request = self
             .reqwest_client
@@ -60,22 +95,6 @@ impl UpdateBlockClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let block = serde_json::from_slice::<notionrs_types::object::block::BlockResponse>(&body)?;
-
-        Ok(block)
+        Ok((self.config, request))
     }
 }
@@ -0,0 +1,321 @@
+/// The base URL of the Notion API. Endpoint paths (`/v1/...`) are appended to it.
+pub const DEFAULT_BASE_URL: &str = "https://api.notion.com";
+
+/// The `Notion-Version` header sent when none is configured.
+pub const DEFAULT_NOTION_VERSION: &str = "2026-03-11";
+
+/// Builder for [`Client`](crate::Client).
+///
+/// ```no_run
+/// let client = notionrs::Client::builder()
+///     .notion_api_key(std::env::var("NOTION_API_KEY").unwrap())
+///     .base_url("http://127.0.0.1:8080")
+///     .timeout(std::time::Duration::from_secs(30))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, notionrs_macro::Setter)]
+pub struct ClientBuilder {
+    /// Integration token or OAuth access token, sent as `Authorization: Bearer <token>`.
+    notion_api_key: Option<String>,
+
+    /// Scheme and host (optionally followed by a path prefix) that endpoint paths
+    /// such as `/v1/pages` are appended to. Defaults to `https://api.notion.com`.
+    base_url: String,
+
+    /// Value of the `Notion-Version` header.
+    notion_version: String,
+
+    /// Value of the `User-Agent` header. No `User-Agent` is sent when unset.
+    user_agent: Option<String>,
+
+    /// Total timeout for each request, from connecting until the response body has been read.
+    timeout: Option<std::time::Duration>,
+
+    /// Timeout for the connect phase of each request.
+    connect_timeout: Option<std::time::Duration>,
+
+    /// Headers sent with every request. `Authorization` and `Notion-Version`
+    /// configured on the builder take precedence over entries here.
+    default_headers: reqwest::header::HeaderMap,
+
+    /// Retry transient errors (rate limits, conflicts, 503 and 504 responses) with backoff.
+    /// Requests are not retried when unset.
+    retry_policy: Option<crate::retry::RetryPolicy>,
+
+    /// Throttle requests with a token bucket shared by this client, its clones and
+    /// every request builder it hands out. Requests are not throttled when unset.
+    rate_limiter: Option<crate::rate_limit::RateLimiter>,
+
+    #[setter(skip)]
+    middlewares: Vec<std::sync::Arc<dyn crate::middleware::Middleware>>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            notion_api_key: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            notion_version: DEFAULT_NOTION_VERSION.to_string(),
+            user_agent: None,
+            timeout: None,
+            connect_timeout: None,
+            default_headers: reqwest::header::HeaderMap::new(),
+            retry_policy: None,
+            rate_limiter: None,
+            middlewares: Vec::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Add a single header sent with every request.
+    pub fn default_header<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: reqwest::header::IntoHeaderName,
+        V: Into<reqwest::header::HeaderValue>,
+    {
+        self.default_headers.insert(name, value.into());
+        self
+    }
+
+    /// Add a [`Middleware`](crate::middleware::Middleware) that runs around every request.
+    ///
+    /// Middlewares run in the order they are added; the first one added sees the
+    /// request first and the response last.
+    pub fn middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: crate::middleware::Middleware,
+    {
+        self.middlewares.push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Build the [`Client`](crate::Client).
+    ///
+    /// Returns [`Error::ClientBuild`](crate::Error::ClientBuild) when the base URL
+    /// or a header value is invalid, or when the underlying HTTP client cannot be created.
+    pub fn build(self) -> Result<crate::Client, crate::error::Error> {
+        let base_url = reqwest::Url::parse(&self.base_url).map_err(|e| {
+            crate::error::Error::ClientBuild(format!("`base_url` is not a valid URL: {e}"))
+        })?;
+
+        if !matches!(base_url.scheme(), "http" | "https") {
+            return Err(crate::error::Error::ClientBuild(format!(
+                "`base_url` must use the http or https scheme: {}",
+                self.base_url
+            )));
+        }
+
+        let mut headers = self.default_headers;
+
+        headers.insert(
+            "Notion-Version",
+            reqwest::header::HeaderValue::from_str(&self.notion_version).map_err(|e| {
+                crate::error::Error::ClientBuild(format!(
+                    "`notion_version` is not a valid header value: {e}"
+                ))
+            })?,
+        );
+
+        // What `build_request()` reports; the HTTP client adds these to every request.
+        let mut prepared_headers = headers.clone();
+        if let Some(user_agent) = &self.user_agent {
+            prepared_headers.insert(
+                reqwest::header::USER_AGENT,
+                reqwest::header::HeaderValue::from_str(user_agent).map_err(|e| {
+                    crate::error::Error::ClientBuild(format!(
+                        "`user_agent` is not a valid header value: {e}"
+                    ))
+                })?,
+            );
+        }
+        prepared_headers.remove(reqwest::header::AUTHORIZATION);
+
+        if let Some(secret) = self.notion_api_key {
+            let mut authorization =
+                reqwest::header::HeaderValue::from_str(&format!("Bearer {}", secret)).map_err(
+                    |e| {
+                        crate::error::Error::ClientBuild(format!(
+                            "`notion_api_key` is not a valid header value: {e}"
+                        ))
+                    },
+                )?;
+            authorization.set_sensitive(true);
+            headers.insert("Authorization", authorization);
+        }
+
+        let mut builder = reqwest::Client::builder().default_headers(headers);
+
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        let reqwest_client = builder
+            .build()
+            .map_err(|e| crate::error::Error::ClientBuild(e.to_string()))?;
+
+        Ok(crate::Client {
+            reqwest_client,
+            config: std::sync::Arc::new(ClientConfig {
+                base_url: self.base_url.trim_end_matches('/').to_string(),
+                retry_policy: self.retry_policy,
+                rate_limiter: self.rate_limiter,
+                middlewares: self.middlewares,
+                default_headers: prepared_headers,
+                access_token: None,
+                upsert_locks: Default::default(),
+            }),
+        })
+    }
+
+    /// Build a [`blocking::Client`](crate::blocking::Client).
+    ///
+    /// Fails like [`build`](Self::build), and also when the runtime that drives
+    /// its requests cannot be started.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::Client, crate::error::Error> {
+        crate::blocking::Client::from_async(self.build()?)
+    }
+}
+
+/// Settings shared by a [`Client`](crate::Client) and every request builder it hands out.
+#[derive(Debug, Clone)]
+pub(crate) struct ClientConfig {
+    /// Base URL without a trailing slash.
+    pub(crate) base_url: String,
+
+    pub(crate) retry_policy: Option<crate::retry::RetryPolicy>,
+
+    pub(crate) rate_limiter: Option<crate::rate_limit::RateLimiter>,
+
+    pub(crate) middlewares: Vec<std::sync::Arc<dyn crate::middleware::Middleware>>,
+
+    /// Headers the HTTP client adds to every request, without `Authorization`.
+    /// Reported by `build_request()`.
+    pub(crate) default_headers: reqwest::header::HeaderMap,
+
+    /// Token that replaces the client's `Authorization` header, set by
+    /// [`Client::with_token`](crate::Client::with_token).
+    pub(crate) access_token: Option<String>,
+
+    /// Serializes [`Client::upsert_page`](crate::Client::upsert_page) calls
+    /// for the same key value.
+    pub(crate) upsert_locks: crate::client::page::upsert_page::UpsertLocks,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            retry_policy: None,
+            rate_limiter: None,
+            middlewares: Vec::new(),
+            default_headers: reqwest::header::HeaderMap::new(),
+            access_token: None,
+            upsert_locks: Default::default(),
+        }
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn build_with_defaults() {
+        let client = ClientBuilder::default().build().unwrap();
+
+        assert_eq!(client.config.base_url, "https://api.notion.com");
+    }
+
+    #[test]
+    fn build_trims_trailing_slash_from_base_url() {
+        let client = ClientBuilder::default()
+            .base_url("http://127.0.0.1:8080/notion/")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.config.base_url, "http://127.0.0.1:8080/notion");
+    }
+
+    #[test]
+    fn build_rejects_invalid_base_url() {
+        let error = ClientBuilder::default()
+            .base_url("not a url")
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, crate::error::Error::ClientBuild(_)));
+
+        let error = ClientBuilder::default()
+            .base_url("ftp://example.com")
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, crate::error::Error::ClientBuild(_)));
+    }
+
+    #[test]
+    fn build_rejects_invalid_header_values() {
+        let error = ClientBuilder::default()
+            .notion_api_key("secret\n")
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, crate::error::Error::ClientBuild(_)));
+
+        let error = ClientBuilder::default()
+            .notion_version("2026-03-11\r\n")
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, crate::error::Error::ClientBuild(_)));
+    }
+
+    #[tokio::test]
+    async fn requests_are_sent_to_the_configured_base_url() {
+        let server = crate::util::test_server::TestServer::start(vec![
+            crate::util::test_server::TestResponse::json(
+                200,
+                r#"{"object":"user","id":"user-id","type":"bot","bot":{}}"#,
+            ),
+        ])
+        .await;
+
+        let client = ClientBuilder::default()
+            .notion_api_key("secret_token")
+            .base_url(format!("{}/prefix/", server.base_url()))
+            .notion_version("2022-06-28")
+            .user_agent("my-integration/1.0")
+            .default_header(
+                "X-Egress-Route",
+                reqwest::header::HeaderValue::from_static("notion"),
+            )
+            .build()
+            .unwrap();
+
+        client.get_self().send().await.unwrap();
+
+        let requests = server.requests().await;
+        assert_eq!(requests.len(), 1);
+
+        let request = &requests[0];
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/prefix/v1/users/me");
+        assert_eq!(request.header("authorization"), Some("Bearer secret_token"));
+        assert_eq!(request.header("notion-version"), Some("2022-06-28"));
+        assert_eq!(request.header("user-agent"), Some("my-integration/1.0"));
+        assert_eq!(request.header("x-egress-route"), Some("notion"));
+    }
+}
@@ -4,10 +4,14 @@ use serde::Serialize;
 ///
 /// Note: `rich_text` and `markdown` are mutually exclusive. Please set only one of them.
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct CreateCommentClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) page_id: Option<String>,
 
     pub(crate) discussion_id: Option<String>,
@@ -39,10 +43,41 @@ pub struct CreateCommentRequestBody {
     markdown: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl CreateCommentClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::comment::Comment, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let comment = serde_json::from_slice::<notionrs_types::object::comment::Comment>(&body)?;
+
+        Ok(comment)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         if self.rich_text.is_none() && self.markdown.is_none() {
             return Err(crate::error::Error::RequestParameter(
                 "either rich_text or markdown must be provided".to_string(),
@@ -68,7 +103,7 @@ impl CreateCommentClient {
             ));
         }
 
-        let url = "https://api.notion.com/v1/comments";
+        let url = format!("{}/v1/comments", self.config.base_url);
 
         let body = CreateCommentRequestBody {
             parent: self.page_id.map(|page_id| {
@@ -92,23 +127,7 @@ impl CreateCommentClient {
             .header("Content-Type", "application/json")
             .body(body_string);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let comment = serde_json::from_slice::<notionrs_types::object::comment::Comment>(&body)?;
-
-        Ok(comment)
+        Ok((self.config, request))
     }
 }
 
@@ -1,10 +1,14 @@
 use serde::Serialize;
 
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct RetrieveCommentsClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) block_id: Option<String>,
 
     pub(crate) page_size: Option<u8>,
@@ -26,19 +30,54 @@ struct RetrieveCommentsQueryParams {
     page_size: Option<u8>,
 }
 
+#[notionrs_macro::blocking]
 impl RetrieveCommentsClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<
         notionrs_types::object::response::ListResponse<notionrs_types::object::comment::Comment>,
         crate::error::Error,
+    > {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let comments = serde_json::from_slice::<
+            notionrs_types::object::response::ListResponse<
+                notionrs_types::object::comment::Comment,
+            >,
+        >(&body)?;
+
+        Ok(comments)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
     > {
         match self.block_id {
             None => Err(crate::error::Error::RequestParameter(
                 "block_id is not set.".to_string(),
             )),
             Some(block_id) => {
-                let url = "https://api.notion.com/v1/comments";
+                let url = format!("{}/v1/comments", self.config.base_url);
 
                 let query = RetrieveCommentsQueryParams {
                     block_id,
@@ -48,27 +87,7 @@ impl RetrieveCommentsClient {
 
                 let request = self.reqwest_client.get(url).query(&query);
 
-                let response = request
-                    .send()
-                    .await
-                    .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-                if !response.status().is_success() {
-                    return Err(crate::error::Error::try_from_response_async(response).await);
-                }
-
-                let body = response
-                    .bytes()
-                    .await
-                    .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-                let comments = serde_json::from_slice::<
-                    notionrs_types::object::response::ListResponse<
-                        notionrs_types::object::comment::Comment,
-                    >,
-                >(&body)?;
-
-                Ok(comments)
+                Ok((self.config, request))
             }
         }
     }
@@ -4,10 +4,14 @@ use serde::Serialize;
 ///
 /// Note: `rich_text` and `markdown` are mutually exclusive. Please set only one of them.
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct UpdateCommentClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) comment_id: Option<String>,
 
     /// Mutually exclusive with `markdown`.
@@ -31,10 +35,41 @@ pub struct UpdateCommentRequestBody {
     markdown: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl UpdateCommentClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::comment::Comment, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let comment = serde_json::from_slice::<notionrs_types::object::comment::Comment>(&body)?;
+
+        Ok(comment)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let comment_id = self
             .comment_id
             .ok_or(crate::error::Error::RequestParameter(
@@ -54,7 +89,7 @@ impl UpdateCommentClient {
             ));
         }
 
-        let url = format!("https://api.notion.com/v1/comments/{}", comment_id);
+        let url = format!("{}/v1/comments/{}", self.config.base_url, comment_id);
 
         let body = UpdateCommentRequestBody {
             rich_text: self.rich_text,
@@ -69,23 +104,7 @@ impl UpdateCommentClient {
             .header("Content-Type", "application/json")
             .body(body_string);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let comment = serde_json::from_slice::<notionrs_types::object::comment::Comment>(&body)?;
-
-        Ok(comment)
+        Ok((self.config, request))
     }
 }
 
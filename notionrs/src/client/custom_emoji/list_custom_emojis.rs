@@ -4,10 +4,14 @@ use notionrs_types::prelude::*;
 
 /// A request builder for performing `list_custom_emojis` operations.
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct ListCustomEmojisClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The starting cursor position for pagination
     pub(crate) start_cursor: Option<String>,
 
@@ -38,10 +42,36 @@ struct ListCustomEmojisQueryParams {
 
 crate::impl_paginate!(ListCustomEmojisClient, CustomEmojiContent);
 
+#[notionrs_macro::blocking]
 impl ListCustomEmojisClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     /// Send a request to the API endpoint of Notion.
     pub async fn send(self) -> Result<ListResponse<CustomEmojiContent>, crate::error::Error> {
-        let url = "https://api.notion.com/v1/custom_emojis";
+        let (config, request) = self.into_request()?;
+
+        let response: ListResponse<CustomEmojiContent> =
+            crate::util::send_and_convert(&config, request).await?;
+
+        Ok(response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let url = format!("{}/v1/custom_emojis", self.config.base_url);
 
         let params = ListCustomEmojisQueryParams {
             start_cursor: self.start_cursor.clone(),
@@ -51,9 +81,6 @@ impl ListCustomEmojisClient {
 
         let request = self.reqwest_client.get(url).query(&params);
 
-        let response: ListResponse<CustomEmojiContent> =
-            crate::util::send_and_convert(request).await?;
-
-        Ok(response)
+        Ok((self.config, request))
     }
 }
@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 
 /// @see <https://developers.notion.com/reference/create-a-data-source>
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct CreateDataSourceClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the parent database (with or without dashes),
     pub(crate) database_id: Option<String>,
 
@@ -32,11 +36,44 @@ pub struct CreateDataSourceRequestBody {
     pub(crate) icon: Option<notionrs_types::object::emoji_and_icon::EmojiAndIcon>,
 }
 
+#[notionrs_macro::blocking]
 impl CreateDataSourceClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::data_source::DataSourceResponse, crate::error::Error> {
-        let url = "https://api.notion.com/v1/data_sources";
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let data_source = serde_json::from_slice::<
+            notionrs_types::object::data_source::DataSourceResponse,
+        >(&body)?;
+
+        Ok(data_source)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let url = format!("{}/v1/data_sources", self.config.base_url);
 
         let request_body = if let Some(database_id) = self.database_id {
             let req = CreateDataSourceRequestBody {
@@ -64,24 +101,6 @@ impl CreateDataSourceClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let data_source = serde_json::from_slice::<
-            notionrs_types::object::data_source::DataSourceResponse,
-        >(&body)?;
-
-        Ok(data_source)
+        Ok((self.config, request))
     }
 }
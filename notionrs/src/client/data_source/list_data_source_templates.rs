@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 
 /// @see <https://developers.notion.com/reference/list-data-source-templates>
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct ListDataSourceTemplatesClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) data_source_id: Option<String>,
 
     pub(crate) name: Option<String>,
@@ -28,8 +32,39 @@ pub struct ListDataSourceTemplatesRequestParams {
     pub(crate) page_size: Option<u8>,
 }
 
+#[notionrs_macro::blocking]
 impl ListDataSourceTemplatesClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(self) -> Result<DataSourceTemplateListResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let pages = serde_json::from_slice::<DataSourceTemplateListResponse>(&body)?;
+
+        Ok(pages)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let data_source_id = match self.data_source_id {
             Some(id) => id,
             None => {
@@ -46,7 +81,8 @@ impl ListDataSourceTemplatesClient {
         })?;
 
         let url = format!(
-            "https://api.notion.com/v1/data_sources/{data_source_id}/templates?{request_params}"
+            "{}/v1/data_sources/{data_source_id}/templates?{request_params}",
+            self.config.base_url
         );
 
         let request = self
@@ -54,22 +90,6 @@ impl ListDataSourceTemplatesClient {
             .get(url)
             .header("Content-Type", "application/json");
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let pages = serde_json::from_slice::<DataSourceTemplateListResponse>(&body)?;
-
-        Ok(pages)
+        Ok((self.config, request))
     }
 }
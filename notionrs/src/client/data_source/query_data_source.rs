@@ -1,5 +1,7 @@
 use std::marker::PhantomData;
 
+use futures::{StreamExt, TryStreamExt};
+
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 use notionrs_types::prelude::*;
@@ -11,6 +13,8 @@ pub struct QueryDataSourceClient<
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) data_source_id: Option<String>,
 
     pub(crate) filter: Option<notionrs_types::object::request::filter::Filter>,
@@ -21,6 +25,14 @@ pub struct QueryDataSourceClient<
 
     pub(crate) page_size: Option<u32>,
 
+    pub(crate) filter_properties: Option<Vec<String>>,
+
+    pub(crate) in_trash: Option<bool>,
+
+    pub(crate) result_type: Option<QueryDataSourceResultType>,
+
+    pub(crate) hydrate_truncated_properties: bool,
+
     pub(crate) _phantom: PhantomData<T>,
 }
 
@@ -28,16 +40,22 @@ impl<T> Default for QueryDataSourceClient<T> {
     fn default() -> Self {
         Self {
             reqwest_client: reqwest::Client::default(),
+            config: Default::default(),
             data_source_id: None,
             filter: None,
             sorts: Vec::new(),
             start_cursor: None,
             page_size: None,
+            filter_properties: None,
+            in_trash: None,
+            result_type: None,
+            hydrate_truncated_properties: false,
             _phantom: PhantomData,
         }
     }
 }
 
+#[notionrs_macro::blocking]
 impl<T> QueryDataSourceClient<T> {
     /// Change the page-property type used to deserialize query results.
     /// Call this when you want to map properties into a custom struct instead
@@ -45,11 +63,16 @@ impl<T> QueryDataSourceClient<T> {
     pub fn typed<U>(self) -> QueryDataSourceClient<U> {
         QueryDataSourceClient {
             reqwest_client: self.reqwest_client,
+            config: self.config,
             data_source_id: self.data_source_id,
             filter: self.filter,
             sorts: self.sorts,
             start_cursor: self.start_cursor,
             page_size: self.page_size,
+            filter_properties: self.filter_properties,
+            in_trash: self.in_trash,
+            result_type: self.result_type,
+            hydrate_truncated_properties: self.hydrate_truncated_properties,
             _phantom: PhantomData,
         }
     }
@@ -83,6 +106,38 @@ impl<T> QueryDataSourceClient<T> {
         self.page_size = Some(page_size);
         self
     }
+
+    /// Property IDs or names to include in the `properties` of the returned
+    /// pages. IDs may be passed in the URL-encoded form returned by Notion.
+    pub fn filter_properties(mut self, filter_properties: Vec<String>) -> Self {
+        self.filter_properties = Some(filter_properties);
+        self
+    }
+
+    /// Whether to query the pages in the trash instead of the live ones.
+    pub fn in_trash(mut self, in_trash: bool) -> Self {
+        self.in_trash = Some(in_trash);
+        self
+    }
+
+    /// Only return results of this type. Wiki data sources can contain data
+    /// sources as well as pages, which `send()` can't deserialize as pages.
+    pub fn result_type(mut self, result_type: QueryDataSourceResultType) -> Self {
+        self.result_type = Some(result_type);
+        self
+    }
+
+    /// Fill in relation and people properties that Notion cut off at 25
+    /// references, using the property item endpoint. Off by default.
+    ///
+    /// A relation is hydrated when Notion reports `has_more`. A people property
+    /// has no such flag, so one with 25 or more entries is assumed to be cut
+    /// off and is hydrated even when it is complete. Every hydrated property
+    /// costs at least one extra request, for each page of the results that has one.
+    pub fn hydrate_truncated_properties(mut self, hydrate_truncated_properties: bool) -> Self {
+        self.hydrate_truncated_properties = hydrate_truncated_properties;
+        self
+    }
 }
 
 impl<T> crate::r#trait::Paginate<notionrs_types::object::page::PageResponse<T>>
@@ -116,6 +171,307 @@ where
     }
 }
 
+/// The type of results returned by a data source query.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryDataSourceResultType {
+    Page,
+    DataSource,
+}
+
+/// How many pages have their truncated properties hydrated at once. Notion's
+/// average rate limit is 3 requests per second.
+const HYDRATE_CONCURRENCY: usize = 3;
+
+/// The timestamp that [`QueryDataSourceClient::into_exhaustive_stream`] splits a query on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum QueryWindowTimestamp {
+    /// Split on `created_time`, which doesn't change while the query runs.
+    #[default]
+    CreatedTime,
+
+    /// Split on `last_edited_time`. Pages edited while the query runs can move
+    /// to a window that was already read and be missed.
+    LastEditedTime,
+}
+
+impl<T> QueryDataSourceClient<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    /// Returns an async stream that yields every page matching the query, even
+    /// beyond the query result limit.
+    ///
+    /// Notion stops returning results once a query reaches its result limit and
+    /// reports `request_status: Incomplete { QueryResultLimitReached }`, which
+    /// [`into_stream`](crate::PaginateExt::into_stream) ends at. This stream instead
+    /// splits the query into `timestamp` windows, combined with the filter via
+    /// [`Filter::and`], until every window fits under the limit. Pages returned by
+    /// more than one window are yielded once. The window conditions are added to
+    /// the list of a top-level `and` filter, so they don't nest it any deeper.
+    ///
+    /// `sorts` apply within each window only, so the overall order is not kept.
+    /// The stream fails with [`Error::RequestParameter`](crate::Error::RequestParameter)
+    /// if more pages than the limit share the same timestamp, or if a window has
+    /// to be added to a filter that is two levels deep without being an `and`.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use notionrs::client::data_source::query_data_source::QueryWindowTimestamp;
+    ///
+    /// # async fn run(client: notionrs::Client) -> Result<(), notionrs::Error> {
+    /// let pages = client
+    ///     .query_data_source()
+    ///     .data_source_id("data-source-id")
+    ///     .into_exhaustive_stream(QueryWindowTimestamp::CreatedTime)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_exhaustive_stream(
+        self,
+        timestamp: QueryWindowTimestamp,
+    ) -> crate::r#trait::PaginateStream<notionrs_types::object::page::PageResponse<T>> {
+        let state = ExhaustiveQuery {
+            query: self,
+            timestamp,
+            windows: vec![QueryWindow::default()],
+            current: None,
+            buffer: Vec::new().into_iter(),
+            seen: std::collections::HashSet::new(),
+        };
+
+        Box::pin(futures::stream::try_unfold(state, |mut state| async move {
+            loop {
+                if let Some(page) = state.buffer.next() {
+                    return Ok(Some((page, state)));
+                }
+
+                let current = match state.current.take() {
+                    Some(current) => current,
+                    None => match state.windows.pop() {
+                        Some(window) => WindowProgress::new(window),
+                        None => return Ok(None),
+                    },
+                };
+
+                let response = state.query_window(&current).await?;
+                state.advance(current, response)?;
+            }
+        }))
+    }
+}
+
+/// A `[start, end)` range of the split timestamp; `None` is unbounded.
+#[derive(Debug, Default, Clone, Copy)]
+struct QueryWindow {
+    start: Option<time::OffsetDateTime>,
+    end: Option<time::OffsetDateTime>,
+}
+
+/// How far a window has been read.
+struct WindowProgress {
+    window: QueryWindow,
+    cursor: Option<String>,
+
+    /// The earliest and latest timestamps returned for the window so far.
+    range: Option<(time::OffsetDateTime, time::OffsetDateTime)>,
+}
+
+impl WindowProgress {
+    fn new(window: QueryWindow) -> Self {
+        Self {
+            window,
+            cursor: None,
+            range: None,
+        }
+    }
+
+    /// Halves of the window, or `None` when it is too narrow to split.
+    fn split(&self) -> Option<[QueryWindow; 2]> {
+        let (earliest, latest) = self.range?;
+        let start = self.window.start.unwrap_or(earliest);
+        let end = self.window.end.unwrap_or(latest);
+
+        if end - start <= time::Duration::MILLISECOND {
+            return None;
+        }
+
+        let middle = start + (end - start) / 2;
+
+        Some([
+            QueryWindow {
+                start: self.window.start,
+                end: Some(middle),
+            },
+            QueryWindow {
+                start: Some(middle),
+                end: self.window.end,
+            },
+        ])
+    }
+}
+
+/// Notion accepts compound filters nested at most this deep.
+const MAX_FILTER_DEPTH: usize = 2;
+
+/// How deep compound filters are nested in `filter`; `0` for a single condition.
+fn filter_depth(filter: &Filter) -> usize {
+    if filter.and.is_none() && filter.or.is_none() {
+        return 0;
+    }
+
+    let nested = filter.and.iter().chain(&filter.or).flatten();
+    1 + nested.map(|filter| filter_depth(filter)).max().unwrap_or(0)
+}
+
+/// `filter` restricted by `conditions` as well. They are added to the list of
+/// an `and` filter, and any other filter is wrapped in a new `and`, as long as
+/// that stays within [`MAX_FILTER_DEPTH`].
+fn and_within_depth(
+    filter: Option<Filter>,
+    mut conditions: Vec<Filter>,
+) -> Result<Option<Filter>, crate::error::Error> {
+    match filter {
+        None if conditions.len() <= 1 => Ok(conditions.pop()),
+        None => Ok(Some(Filter::and(conditions))),
+        Some(filter) if conditions.is_empty() => Ok(Some(filter)),
+        Some(Filter {
+            and: Some(mut and),
+            or: None,
+            property: None,
+            condition: None,
+            timestamp: None,
+        }) => {
+            and.extend(conditions.into_iter().map(Box::new));
+            Ok(Some(Filter {
+                and: Some(and),
+                ..Default::default()
+            }))
+        }
+        Some(filter) if filter_depth(&filter) < MAX_FILTER_DEPTH => {
+            conditions.insert(0, filter);
+            Ok(Some(Filter::and(conditions)))
+        }
+        Some(_) => Err(crate::error::Error::RequestParameter(format!(
+            "The filter is nested {MAX_FILTER_DEPTH} levels deep, so the timestamp window \
+             can't be added to it; use an `and` filter at the top level."
+        ))),
+    }
+}
+
+/// State of [`QueryDataSourceClient::into_exhaustive_stream`].
+struct ExhaustiveQuery<T>
+where
+    T: Clone + Send + 'static,
+{
+    query: QueryDataSourceClient<T>,
+    timestamp: QueryWindowTimestamp,
+
+    /// Windows still to read, the next one last.
+    windows: Vec<QueryWindow>,
+    current: Option<WindowProgress>,
+    buffer: std::vec::IntoIter<notionrs_types::object::page::PageResponse<T>>,
+
+    /// IDs of the pages yielded so far.
+    seen: std::collections::HashSet<String>,
+}
+
+impl<T> ExhaustiveQuery<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    async fn query_window(
+        &self,
+        progress: &WindowProgress,
+    ) -> Result<ListResponse<notionrs_types::object::page::PageResponse<T>>, crate::error::Error>
+    {
+        let format = |timestamp: time::OffsetDateTime| {
+            timestamp
+                .format(&time::format_description::well_known::Rfc3339)
+                .map_err(|e| crate::error::Error::RequestParameter(e.to_string()))
+        };
+
+        let mut filters = Vec::new();
+
+        if let Some(start) = progress.window.start {
+            filters.push(match self.timestamp {
+                QueryWindowTimestamp::CreatedTime => Filter::timestamp_on_or_after(format(start)?),
+                QueryWindowTimestamp::LastEditedTime => {
+                    Filter::last_edited_time_on_or_after(format(start)?)
+                }
+            });
+        }
+
+        if let Some(end) = progress.window.end {
+            filters.push(match self.timestamp {
+                QueryWindowTimestamp::CreatedTime => Filter::timestamp_before(format(end)?),
+                QueryWindowTimestamp::LastEditedTime => {
+                    Filter::last_edited_time_before(format(end)?)
+                }
+            });
+        }
+
+        let mut query = self.query.clone();
+        query.filter = and_within_depth(query.filter.take(), filters)?;
+        query.start_cursor = progress.cursor.clone();
+
+        query.send().await
+    }
+
+    /// Buffers the unseen pages of `response` and decides what to read next.
+    fn advance(
+        &mut self,
+        mut progress: WindowProgress,
+        response: ListResponse<notionrs_types::object::page::PageResponse<T>>,
+    ) -> Result<(), crate::error::Error> {
+        let mut pages = Vec::new();
+
+        for page in response.results {
+            let timestamp = match self.timestamp {
+                QueryWindowTimestamp::CreatedTime => page.created_time,
+                QueryWindowTimestamp::LastEditedTime => page.last_edited_time,
+            };
+
+            progress.range = Some(match progress.range {
+                Some((earliest, latest)) => (earliest.min(timestamp), latest.max(timestamp)),
+                None => (timestamp, timestamp),
+            });
+
+            if self.seen.insert(page.id.clone()) {
+                pages.push(page);
+            }
+        }
+
+        self.buffer = pages.into_iter();
+
+        let limit_reached = matches!(
+            response.request_status,
+            Some(RequestStatus::Incomplete {
+                incomplete_reason: Some(IncompleteReason::QueryResultLimitReached),
+            })
+        );
+
+        if limit_reached {
+            let [earlier, later] = progress.split().ok_or_else(|| {
+                crate::error::Error::RequestParameter(
+                    "More pages than the query result limit share the same timestamp; \
+                     narrow the filter to read them all."
+                        .to_string(),
+                )
+            })?;
+            self.windows.push(later);
+            self.windows.push(earlier);
+        } else if let (Some(true), Some(cursor)) = (response.has_more, response.next_cursor) {
+            progress.cursor = Some(cursor);
+            self.current = Some(progress);
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct QueryDataSourceRequestBody {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,25 +485,92 @@ pub struct QueryDataSourceRequestBody {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) page_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) in_trash: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) result_type: Option<QueryDataSourceResultType>,
 }
 
+#[notionrs_macro::blocking]
 impl<T> QueryDataSourceClient<T>
 where
     T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
 {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<ListResponse<notionrs_types::object::page::PageResponse<T>>, crate::error::Error>
     {
+        let reqwest_client = self.reqwest_client.clone();
+        let hydrate_truncated_properties = self.hydrate_truncated_properties;
+
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        if !hydrate_truncated_properties {
+            let pages = serde_json::from_slice::<
+                ListResponse<notionrs_types::object::page::PageResponse<T>>,
+            >(&body)?;
+            return Ok(pages);
+        }
+
+        let mut pages = serde_json::from_slice::<serde_json::Value>(&body)?;
+
+        if let Some(results) = pages["results"].as_array_mut() {
+            let (reqwest_client, config) = (&reqwest_client, &config);
+
+            *results = futures::stream::iter(std::mem::take(results))
+                .map(|mut page| async move {
+                    crate::client::page::hydrate_truncated_properties(
+                        reqwest_client,
+                        config,
+                        &mut page,
+                    )
+                    .await?;
+                    Ok::<_, crate::error::Error>(page)
+                })
+                .buffered(HYDRATE_CONCURRENCY)
+                .try_collect()
+                .await?;
+        }
+
+        Ok(serde_json::from_value(pages)?)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         match self.data_source_id {
             Some(id) => {
-                let url = format!("https://api.notion.com/v1/data_sources/{}/query", id);
+                let url = format!("{}/v1/data_sources/{}/query", self.config.base_url, id);
 
                 let request_body = serde_json::to_string(&QueryDataSourceRequestBody {
                     filter: self.filter.clone(),
                     sorts: self.sorts.clone(),
                     start_cursor: self.start_cursor.clone(),
                     page_size: self.page_size,
+                    in_trash: self.in_trash,
+                    result_type: self.result_type,
                 })?;
 
                 let request = self
@@ -155,30 +578,351 @@ where
                     .post(url)
                     .header("Content-Type", "application/json")
                     .body(request_body);
+                let request =
+                    crate::client::page::with_filter_properties(request, self.filter_properties);
 
-                let response = request
-                    .send()
-                    .await
-                    .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+                Ok((self.config, request))
+            }
+            None => Err(crate::error::Error::RequestParameter(
+                "`database_id` is not set".to_string(),
+            )),
+        }
+    }
+}
 
-                if !response.status().is_success() {
-                    return Err(crate::error::Error::try_from_response_async(response).await);
-                }
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use futures::TryStreamExt;
 
-                let body = response
-                    .bytes()
-                    .await
-                    .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+    use super::*;
+    use crate::util::test_server::{TestResponse, TestServer, list_json, page_json};
 
-                let pages = serde_json::from_slice::<
-                    ListResponse<notionrs_types::object::page::PageResponse<T>>,
-                >(&body)?;
+    fn page(id: &str, created_time: &str) -> serde_json::Value {
+        let mut page = page_json(
+            id,
+            serde_json::json!({ "type": "data_source_id", "data_source_id": "data-source-id" }),
+            serde_json::json!({}),
+        );
+        page["created_time"] = created_time.into();
+        page["last_edited_time"] = created_time.into();
+        page
+    }
 
-                Ok(pages)
+    #[test]
+    fn build_request_with_result_options() {
+        let request = crate::Client::new("secret")
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .sorts(vec![Sort::created_time_desc(), Sort::asc("Name")])
+            .filter_properties(vec!["title".to_string(), "%3AUj%3B".to_string()])
+            .in_trash(true)
+            .result_type(QueryDataSourceResultType::Page)
+            .build_request()
+            .unwrap();
+
+        assert_eq!(
+            request.url.query(),
+            Some("filter_properties=title&filter_properties=%3AUj%3B")
+        );
+        assert_eq!(
+            request.json::<serde_json::Value>().unwrap(),
+            serde_json::json!({
+                "sorts": [
+                    { "timestamp": "created_time", "direction": "descending" },
+                    { "property": "Name", "direction": "ascending" }
+                ],
+                "in_trash": true,
+                "result_type": "page"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn hydrate_truncated_relations() {
+        let mut truncated = page("page-1", "2026-01-01T00:00:00.000Z");
+        truncated["properties"]["Tasks"] = serde_json::json!({
+            "id": "vYdV",
+            "type": "relation",
+            "relation": [{ "id": "task-0" }],
+            "has_more": true
+        });
+        truncated["properties"]["Owner"] = serde_json::json!({
+            "id": "pAoV",
+            "type": "people",
+            "people": []
+        });
+
+        let relation = serde_json::json!({
+            "object": "list",
+            "results": (0..30)
+                .map(|i| serde_json::json!({
+                    "object": "property_item",
+                    "id": "vYdV",
+                    "type": "relation",
+                    "relation": { "id": format!("task-{i}") }
+                }))
+                .collect::<Vec<_>>(),
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": { "id": "vYdV", "next_url": null, "type": "relation", "relation": {} }
+        });
+
+        // Pages are hydrated concurrently, so both get the same property item.
+        let mut second = truncated.clone();
+        second["id"] = serde_json::json!("page-2");
+
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &page_list(vec![truncated, second], false)),
+            TestResponse::json(200, &relation.to_string()),
+            TestResponse::json(200, &relation.to_string()),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let pages = client
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .hydrate_truncated_properties(true)
+            .send()
+            .await
+            .unwrap();
+
+        for page in &pages.results {
+            match &page.properties["Tasks"] {
+                PageProperty::Relation(relation) => {
+                    assert_eq!(relation.relation.len(), 30);
+                    assert!(!relation.has_more);
+                }
+                other => panic!("Expected a relation, got {other:?}"),
             }
-            None => Err(crate::error::Error::RequestParameter(
-                "`database_id` is not set".to_string(),
-            )),
         }
+
+        let mut paths = server
+            .requests()
+            .await
+            .into_iter()
+            .skip(1)
+            .map(|request| request.path)
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "/v1/pages/page-1/properties/vYdV",
+                "/v1/pages/page-2/properties/vYdV",
+            ]
+        );
+    }
+
+    fn page_list(pages: Vec<serde_json::Value>, limit_reached: bool) -> String {
+        let mut list = list_json(pages);
+
+        if limit_reached {
+            list["request_status"] = serde_json::json!({
+                "type": "incomplete",
+                "incomplete_reason": "query_result_limit_reached"
+            });
+        }
+
+        list.to_string()
+    }
+
+    #[tokio::test]
+    async fn exhaustive_stream_splits_truncated_queries() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &page_list(
+                    vec![
+                        page("page-1", "2024-01-01T00:00:00.000Z"),
+                        page("page-2", "2024-01-03T00:00:00.000Z"),
+                    ],
+                    true,
+                ),
+            ),
+            TestResponse::json(
+                200,
+                &page_list(
+                    vec![
+                        page("page-0", "2023-12-31T00:00:00.000Z"),
+                        page("page-1", "2024-01-01T00:00:00.000Z"),
+                    ],
+                    false,
+                ),
+            ),
+            TestResponse::json(
+                200,
+                &page_list(
+                    vec![
+                        page("page-2", "2024-01-03T00:00:00.000Z"),
+                        page("page-3", "2024-01-04T00:00:00.000Z"),
+                    ],
+                    false,
+                ),
+            ),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let pages = client
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .filter(Filter::checkbox_is_checked("Done"))
+            .into_exhaustive_stream(QueryWindowTimestamp::CreatedTime)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| page.id.as_str())
+                .collect::<Vec<_>>(),
+            ["page-1", "page-2", "page-0", "page-3"]
+        );
+
+        let bodies = server
+            .requests()
+            .await
+            .iter()
+            .map(|request| request.json())
+            .collect::<Vec<_>>();
+
+        let done = serde_json::json!({ "property": "Done", "checkbox": { "equals": true } });
+        assert_eq!(bodies[0]["filter"], done);
+        assert_eq!(
+            bodies[1]["filter"],
+            serde_json::json!({ "and": [
+                done,
+                { "timestamp": "created_time", "created_time": { "before": "2024-01-02T00:00:00Z" } }
+            ]})
+        );
+        assert_eq!(
+            bodies[2]["filter"],
+            serde_json::json!({ "and": [
+                done,
+                { "timestamp": "created_time", "created_time": { "on_or_after": "2024-01-02T00:00:00Z" } }
+            ]})
+        );
+    }
+
+    /// How deep `and` and `or` lists are nested in a serialized filter.
+    fn json_filter_depth(filter: &serde_json::Value) -> usize {
+        ["and", "or"]
+            .iter()
+            .filter_map(|key| filter[key].as_array())
+            .map(|filters| 1 + filters.iter().map(json_filter_depth).max().unwrap_or(0))
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[tokio::test]
+    async fn exhaustive_stream_keeps_nested_filters_within_two_levels() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &page_list(
+                    vec![
+                        page("page-1", "2024-01-01T00:00:00.000Z"),
+                        page("page-2", "2024-01-03T00:00:00.000Z"),
+                    ],
+                    true,
+                ),
+            ),
+            TestResponse::json(200, &page_list(vec![], false)),
+            TestResponse::json(200, &page_list(vec![], false)),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        client
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .filter(Filter::and(vec![
+                Filter::or(vec![
+                    Filter::checkbox_is_checked("Done"),
+                    Filter::checkbox_is_checked("Archived"),
+                ]),
+                Filter::checkbox_is_checked("Public"),
+            ]))
+            .into_exhaustive_stream(QueryWindowTimestamp::CreatedTime)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+        assert_eq!(requests.len(), 3);
+
+        for request in &requests[1..] {
+            let filter = &request.json()["filter"];
+            assert_eq!(json_filter_depth(filter), 2);
+            assert_eq!(filter["and"].as_array().unwrap().len(), 3);
+            assert_eq!(filter["and"][2]["timestamp"], "created_time");
+        }
+    }
+
+    #[test]
+    fn and_within_depth_wraps_shallow_filters_only() {
+        let done = Filter::checkbox_is_checked("Done");
+        let window = Filter::timestamp_before("2024-01-02T00:00:00Z");
+
+        let wrapped = and_within_depth(
+            Some(Filter::or(vec![done.clone(), done.clone()])),
+            vec![window.clone()],
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(filter_depth(&wrapped), 2);
+        assert_eq!(wrapped.and.unwrap().len(), 2);
+
+        let too_deep = Filter::or(vec![Filter::and(vec![done.clone(), done.clone()]), done]);
+        assert!(matches!(
+            and_within_depth(Some(too_deep), vec![window]),
+            Err(crate::Error::RequestParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn exhaustive_stream_fails_when_a_window_cannot_be_split() {
+        let server = TestServer::start(vec![TestResponse::json(
+            200,
+            &page_list(vec![page("page-1", "2024-01-01T00:00:00.000Z")], true),
+        )])
+        .await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let error = client
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .into_exhaustive_stream(QueryWindowTimestamp::LastEditedTime)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, crate::Error::RequestParameter(_)));
     }
 }
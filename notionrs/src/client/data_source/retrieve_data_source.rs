@@ -1,33 +1,30 @@
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct RetrieveDataSourceClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) data_source_id: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl RetrieveDataSourceClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::data_source::DataSourceResponse, crate::error::Error> {
-        let data_source_id = self
-            .data_source_id
-            .ok_or(crate::error::Error::RequestParameter(
-                "`data_source_id` is not set.".to_string(),
-            ))?;
-
-        let url = format!("https://api.notion.com/v1/data_sources/{}", data_source_id);
-
-        let request = self.reqwest_client.get(url);
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
         let body = response
             .bytes()
@@ -40,4 +37,29 @@ impl RetrieveDataSourceClient {
 
         Ok(database)
     }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let data_source_id = self
+            .data_source_id
+            .ok_or(crate::error::Error::RequestParameter(
+                "`data_source_id` is not set.".to_string(),
+            ))?;
+
+        let url = format!(
+            "{}/v1/data_sources/{}",
+            self.config.base_url, data_source_id
+        );
+
+        let request = self.reqwest_client.get(url);
+
+        Ok((self.config, request))
+    }
 }
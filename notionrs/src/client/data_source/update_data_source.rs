@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 
 /// @see <https://developers.notion.com/reference/update-a-data-source>
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct UpdateDataSourceClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// ID of a Notion data source. This is a UUIDv4, with or without dashes.
     pub(crate) data_source_id: Option<String>,
 
@@ -37,13 +41,47 @@ pub struct UpdateDataSourceRequestBody {
     pub(crate) in_trash: Option<bool>,
 }
 
+#[notionrs_macro::blocking]
 impl UpdateDataSourceClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::data_source::DataSourceResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let data_source = serde_json::from_slice::<
+            notionrs_types::object::data_source::DataSourceResponse,
+        >(&body)?;
+
+        Ok(data_source)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let url = if let Some(data_source_id) = self.data_source_id {
             Ok(format!(
-                "https://api.notion.com/v1/data_sources/{data_source_id}"
+                "{}/v1/data_sources/{data_source_id}",
+                self.config.base_url
             ))
         } else {
             Err(crate::error::Error::RequestParameter(
@@ -66,24 +104,6 @@ impl UpdateDataSourceClient {
             .header("Content-Type", "application/json")
             .body(request_body_string);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let data_source = serde_json::from_slice::<
-            notionrs_types::object::data_source::DataSourceResponse,
-        >(&body)?;
-
-        Ok(data_source)
+        Ok((self.config, request))
     }
 }
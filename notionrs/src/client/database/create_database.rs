@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 use notionrs_types::object::rich_text::RichText;
 
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct CreateDatabaseClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) page_id: Option<String>,
 
     pub(crate) title: Vec<RichText>,
@@ -54,10 +58,42 @@ pub struct CreateDatabaseRequestBody {
     pub(crate) cover: Option<notionrs_types::object::file::File>,
 }
 
+#[notionrs_macro::blocking]
 impl CreateDatabaseClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::database::DatabaseResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let database: notionrs_types::object::database::DatabaseResponse =
+            serde_json::from_slice::<notionrs_types::object::database::DatabaseResponse>(&body)?;
+
+        Ok(database)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let page_id = self.page_id.unwrap();
 
         let request_body_struct = CreateDatabaseRequestBody {
@@ -73,7 +109,7 @@ impl CreateDatabaseClient {
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = "https://api.notion.com/v1/databases".to_string();
+        let url = format!("{}/v1/databases", self.config.base_url);
 
         let request = self
             .reqwest_client
@@ -81,23 +117,6 @@ impl CreateDatabaseClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let database: notionrs_types::object::database::DatabaseResponse =
-            serde_json::from_slice::<notionrs_types::object::database::DatabaseResponse>(&body)?;
-
-        Ok(database)
+        Ok((self.config, request))
     }
 }
@@ -1,33 +1,30 @@
 use notionrs_types::prelude::*;
 
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct RetrieveDatabaseClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) database_id: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl RetrieveDatabaseClient {
-    pub async fn send(self) -> Result<DatabaseResponse, crate::error::Error> {
-        let url = if let Some(database_id) = self.database_id {
-            Ok(format!("https://api.notion.com/v1/databases/{database_id}"))
-        } else {
-            Err(crate::error::Error::RequestParameter(
-                "`database_id` is not set.".to_owned(),
-            ))
-        }?;
-
-        let request = self.reqwest_client.get(url);
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
 
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+    pub async fn send(self) -> Result<DatabaseResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
         let body = response
             .bytes()
@@ -38,4 +35,29 @@ impl RetrieveDatabaseClient {
 
         Ok(database)
     }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let url = if let Some(database_id) = self.database_id {
+            Ok(format!(
+                "{}/v1/databases/{database_id}",
+                self.config.base_url
+            ))
+        } else {
+            Err(crate::error::Error::RequestParameter(
+                "`database_id` is not set.".to_owned(),
+            ))
+        }?;
+
+        let request = self.reqwest_client.get(url);
+
+        Ok((self.config, request))
+    }
 }
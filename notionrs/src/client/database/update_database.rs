@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 use notionrs_types::object::rich_text::RichText;
 
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct UpdateDatabaseClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) database_id: Option<String>,
 
     pub(crate) title: Vec<RichText>,
@@ -51,10 +55,42 @@ pub struct UpdateDatabaseRequestBody {
     pub(crate) cover: Option<notionrs_types::object::file::File>,
 }
 
+#[notionrs_macro::blocking]
 impl UpdateDatabaseClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::database::DatabaseResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let database: notionrs_types::object::database::DatabaseResponse =
+            serde_json::from_slice::<notionrs_types::object::database::DatabaseResponse>(&body)?;
+
+        Ok(database)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let database_id = self
             .database_id
             .ok_or(crate::error::Error::RequestParameter(
@@ -72,7 +108,7 @@ impl UpdateDatabaseClient {
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = format!("https://api.notion.com/v1/databases/{}", database_id);
+        let url = format!("{}/v1/databases/{}", self.config.base_url, database_id);
 
         let request = self
             .reqwest_client
@@ -80,23 +116,6 @@ impl UpdateDatabaseClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let database: notionrs_types::object::database::DatabaseResponse =
-            serde_json::from_slice::<notionrs_types::object::database::DatabaseResponse>(&body)?;
-
-        Ok(database)
+        Ok((self.config, request))
     }
 }
@@ -1,7 +1,11 @@
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct CreateFileUploadClient {
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) mode: FileUploadMode,
 
     pub(crate) filename: Option<String>,
@@ -39,6 +43,7 @@ pub enum FileUploadMode {
     ExternalUrl,
 }
 
+#[notionrs_macro::blocking]
 impl CreateFileUploadClient {
     fn validate_request(&self) -> Result<(), crate::error::Error> {
         if matches!(
@@ -66,7 +71,33 @@ impl CreateFileUploadClient {
         Ok(())
     }
 
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(self) -> Result<notionrs_types::prelude::FileUpload, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+
+        let response =
+            crate::util::send_and_convert::<notionrs_types::prelude::FileUpload>(&config, request)
+                .await?;
+
+        Ok(response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         self.validate_request()?;
 
         let request_body = serde_json::to_string(&CreateFileUploadRequestBody {
@@ -79,13 +110,10 @@ impl CreateFileUploadClient {
 
         let request = self
             .reqwest_client
-            .post("https://api.notion.com/v1/file_uploads")
+            .post(format!("{}/v1/file_uploads", self.config.base_url))
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response =
-            crate::util::send_and_convert::<notionrs_types::prelude::FileUpload>(request).await?;
-
-        Ok(response)
+        Ok((self.config, request))
     }
 }
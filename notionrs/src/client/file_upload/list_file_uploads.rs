@@ -1,9 +1,13 @@
 use notionrs_types::prelude::*;
 
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct ListFileUploadClient {
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub status: Option<FileUploadStatus>,
 
     pub start_cursor: Option<String>,
@@ -25,8 +29,34 @@ pub struct ListFileUploadQueryParams {
 
 crate::impl_paginate!(ListFileUploadClient, FileUpload);
 
+#[notionrs_macro::blocking]
 impl ListFileUploadClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(self) -> Result<ListResponse<FileUpload>, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+
+        let response =
+            crate::util::send_and_convert::<ListResponse<FileUpload>>(&config, request).await?;
+
+        Ok(response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let params = ListFileUploadQueryParams {
             status: self.status,
             start_cursor: self.start_cursor,
@@ -35,12 +65,10 @@ impl ListFileUploadClient {
 
         let request = self
             .reqwest_client
-            .get("https://api.notion.com/v1/file_uploads")
+            .get(format!("{}/v1/file_uploads", self.config.base_url))
             .query(&params)
             .header("Content-Type", "application/json");
 
-        let response = crate::util::send_and_convert::<ListResponse<FileUpload>>(request).await?;
-
-        Ok(response)
+        Ok((self.config, request))
     }
 }
@@ -1,12 +1,43 @@
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct RetrieveFileUploadClient {
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) file_upload_id: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl RetrieveFileUploadClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(self) -> Result<notionrs_types::prelude::FileUpload, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+
+        let response =
+            crate::util::send_and_convert::<notionrs_types::prelude::FileUpload>(&config, request)
+                .await?;
+
+        Ok(response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let file_upload_id = self
             .file_upload_id
             .ok_or(crate::error::Error::RequestParameter(
@@ -16,13 +47,11 @@ impl RetrieveFileUploadClient {
         let request = self
             .reqwest_client
             .get(format!(
-                "https://api.notion.com/v1/file_uploads/{file_upload_id}"
+                "{}/v1/file_uploads/{file_upload_id}",
+                self.config.base_url
             ))
             .header("Content-Type", "application/json");
 
-        let response =
-            crate::util::send_and_convert::<notionrs_types::prelude::FileUpload>(request).await?;
-
-        Ok(response)
+        Ok((self.config, request))
     }
 }
@@ -1,7 +1,11 @@
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct SendFileUploadClient {
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) file_upload_id: Option<String>,
 
     pub(crate) file: Option<Vec<u8>>,
@@ -32,8 +36,35 @@ mod unit_tests {
     }
 }
 
+#[notionrs_macro::blocking]
 impl SendFileUploadClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(self) -> Result<notionrs_types::prelude::FileUpload, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+
+        let response =
+            crate::util::send_and_convert::<notionrs_types::prelude::FileUpload>(&config, request)
+                .await?;
+
+        Ok(response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let file_upload_id = self
             .file_upload_id
             .ok_or(crate::error::Error::RequestParameter(
@@ -64,13 +95,11 @@ impl SendFileUploadClient {
         let request = self
             .reqwest_client
             .post(format!(
-                "https://api.notion.com/v1/file_uploads/{file_upload_id}/send"
+                "{}/v1/file_uploads/{file_upload_id}/send",
+                self.config.base_url
             ))
             .multipart(form);
 
-        let response =
-            crate::util::send_and_convert::<notionrs_types::prelude::FileUpload>(request).await?;
-
-        Ok(response)
+        Ok((self.config, request))
     }
 }
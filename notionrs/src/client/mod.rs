@@ -1,48 +1,69 @@
-use futures::TryStreamExt;
 use notionrs_types::prelude::*;
 
-use crate::PaginateExt;
-
 pub mod async_task;
 pub mod block;
+pub mod builder;
 pub mod comment;
 pub mod custom_emoji;
 pub mod data_source;
 pub mod database;
 pub mod file_upload;
+pub mod oauth;
 pub mod page;
 pub mod search;
 pub mod user;
 pub mod view;
 
-#[derive(Default, Debug)]
+pub(crate) use builder::ClientConfig;
+
+#[derive(Default, Debug, Clone)]
 pub struct Client {
     reqwest_client: reqwest::Client,
+    config: std::sync::Arc<ClientConfig>,
 }
 
 impl Client {
-    // TODO: docs: new method
+    /// Create a client for `https://api.notion.com` that authenticates with `notion_api_key`.
+    ///
+    /// Use [`Client::builder`] to configure the base URL, `Notion-Version`,
+    /// timeouts or additional headers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `notion_api_key` is not a valid header value.
     pub fn new(notion_api_key: impl AsRef<str>) -> Self {
-        let mut headers = reqwest::header::HeaderMap::new();
-        let secret = notion_api_key.as_ref().to_string();
-
-        headers.insert(
-            "Notion-Version",
-            reqwest::header::HeaderValue::from_static("2026-03-11"),
-        );
-        headers.insert(
-            "Authorization",
-            reqwest::header::HeaderValue::from_str(&format!("Bearer {}", secret))
-                .expect("Invalid header value"),
-        );
-
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
+        Self::builder()
+            .notion_api_key(notion_api_key)
             .build()
-            .unwrap();
+            .expect("Invalid header value")
+    }
+
+    /// Start configuring a [`Client`].
+    pub fn builder() -> crate::client::builder::ClientBuilder {
+        crate::client::builder::ClientBuilder::default()
+    }
 
-        Client {
-            reqwest_client: client,
+    /// A client that authenticates with `token` instead of the configured key,
+    /// such as the OAuth access token of another workspace.
+    ///
+    /// It shares the connection pool, rate limiter, retry policy and middlewares
+    /// of `self`, so creating one per call is cheap. An invalid token is reported
+    /// as [`Error::RequestParameter`](crate::Error::RequestParameter) when a
+    /// request is sent.
+    ///
+    /// ```no_run
+    /// # async fn run(client: notionrs::Client, access_token: String) -> Result<(), notionrs::Error> {
+    /// let bot = client.with_token(access_token).get_self().send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_token(&self, token: impl AsRef<str>) -> Self {
+        let mut config = ClientConfig::clone(&self.config);
+        config.access_token = Some(token.as_ref().to_string());
+
+        Self {
+            reqwest_client: self.reqwest_client.clone(),
+            config: std::sync::Arc::new(config),
         }
     }
 
@@ -58,6 +79,39 @@ impl Client {
     pub fn get_async_task(&self) -> crate::client::async_task::get_async_task::GetAsyncTaskClient {
         crate::client::async_task::get_async_task::GetAsyncTaskClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Poll the async task `task_id` until it succeeds or fails, e.g. one
+    /// returned by `create_page` with `allow_async(true)`.
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::Error> {
+    /// let client = notionrs::Client::new("secret");
+    ///
+    /// let task = client
+    ///     .wait_for_async_task("task-id")
+    ///     .max_wait(std::time::Duration::from_secs(60))
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!("{:?}", task.typed_result());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_async_task<T>(
+        &self,
+        task_id: T,
+    ) -> crate::client::async_task::wait_for_async_task::WaitForAsyncTaskClient
+    where
+        T: AsRef<str>,
+    {
+        crate::client::async_task::wait_for_async_task::WaitForAsyncTaskClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            task_id: Some(task_id.as_ref().to_string()),
             ..Default::default()
         }
     }
@@ -72,6 +126,7 @@ impl Client {
     pub fn list_users(&self) -> crate::client::user::list_users::ListUsersClient {
         crate::client::user::list_users::ListUsersClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -80,6 +135,7 @@ impl Client {
     pub fn get_user(&self) -> crate::client::user::get_user::GetUserClient {
         crate::client::user::get_user::GetUserClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -88,6 +144,7 @@ impl Client {
     pub fn get_self(&self) -> crate::client::user::get_self::GetSelfClient {
         crate::client::user::get_self::GetSelfClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
         }
     }
 
@@ -102,6 +159,7 @@ impl Client {
     ) -> crate::client::custom_emoji::list_custom_emojis::ListCustomEmojisClient {
         crate::client::custom_emoji::list_custom_emojis::ListCustomEmojisClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -116,6 +174,7 @@ impl Client {
     pub fn get_page<T>(&self) -> crate::client::page::get_page::GetPageClient<T> {
         crate::client::page::get_page::GetPageClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -126,6 +185,7 @@ impl Client {
     ) -> crate::client::page::get_page_property_item::GetPagePropertyItemClient {
         crate::client::page::get_page_property_item::GetPagePropertyItemClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -133,6 +193,7 @@ impl Client {
     pub fn create_page<T>(&self) -> crate::client::page::create_page::CreatePageClient<T> {
         crate::client::page::create_page::CreatePageClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -153,6 +214,7 @@ impl Client {
     {
         crate::client::page::update_page::UpdatePageClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -160,6 +222,7 @@ impl Client {
     pub fn move_page<T>(&self) -> crate::client::page::move_page::MovePageClient<T> {
         crate::client::page::move_page::MovePageClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -172,6 +235,70 @@ impl Client {
     ) -> crate::client::page::get_page_markdown::GetPageMarkdownClient {
         crate::client::page::get_page_markdown::GetPageMarkdownClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Duplicate a page, with its content, under another page or in a data source.
+    ///
+    /// See [`DuplicatePageClient`](crate::client::page::duplicate_page::DuplicatePageClient)
+    /// for what can't be copied.
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::Error> {
+    /// let client = notionrs::Client::new("secret");
+    ///
+    /// let response = client
+    ///     .duplicate_page("template-page-id")
+    ///     .parent_page_id("projects-page-id")
+    ///     .recursive(true)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!("{}", response.page.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn duplicate_page<T>(
+        &self,
+        page_id: T,
+    ) -> crate::client::page::duplicate_page::DuplicatePageClient
+    where
+        T: AsRef<str>,
+    {
+        crate::client::page::duplicate_page::DuplicatePageClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            page_id: Some(page_id.as_ref().to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Update the page of a data source that has a key property value, or
+    /// create it when there is none.
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use notionrs_types::prelude::*;
+    /// # async fn run(properties: HashMap<String, PageProperty>) -> Result<(), notionrs::Error> {
+    /// let client = notionrs::Client::new("secret");
+    ///
+    /// let page = client
+    ///     .upsert_page()
+    ///     .data_source_id("data-source-id")
+    ///     .key("SKU")
+    ///     .properties(properties)
+    ///     .send()
+    ///     .await?
+    ///     .into_page();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upsert_page(&self) -> crate::client::page::upsert_page::UpsertPageClient {
+        crate::client::page::upsert_page::UpsertPageClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -190,6 +317,7 @@ impl Client {
     ) -> crate::client::page::update_page_markdown::UpdatePageMarkdownClient {
         crate::client::page::update_page_markdown::UpdatePageMarkdownClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -205,6 +333,7 @@ impl Client {
     ) -> crate::client::database::create_database::CreateDatabaseClient {
         crate::client::database::create_database::CreateDatabaseClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -214,6 +343,7 @@ impl Client {
     ) -> crate::client::database::update_database::UpdateDatabaseClient {
         crate::client::database::update_database::UpdateDatabaseClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -223,6 +353,7 @@ impl Client {
     ) -> crate::client::database::retrieve_database::RetrieveDatabaseClient {
         crate::client::database::retrieve_database::RetrieveDatabaseClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -238,6 +369,7 @@ impl Client {
     ) -> crate::client::data_source::query_data_source::QueryDataSourceClient {
         crate::client::data_source::query_data_source::QueryDataSourceClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -247,6 +379,7 @@ impl Client {
     ) -> crate::client::data_source::create_data_source::CreateDataSourceClient {
         crate::client::data_source::create_data_source::CreateDataSourceClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -256,6 +389,7 @@ impl Client {
     ) -> crate::client::data_source::retrieve_data_source::RetrieveDataSourceClient {
         crate::client::data_source::retrieve_data_source::RetrieveDataSourceClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -265,6 +399,7 @@ impl Client {
     ) -> crate::client::data_source::update_data_source::UpdateDataSourceClient {
         crate::client::data_source::update_data_source::UpdateDataSourceClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -274,6 +409,7 @@ impl Client {
     ) -> crate::client::data_source::list_data_source_templates::ListDataSourceTemplatesClient {
         crate::client::data_source::list_data_source_templates::ListDataSourceTemplatesClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -288,6 +424,7 @@ impl Client {
     pub fn get_block(&self) -> crate::client::block::get_block::GetBlockClient {
         crate::client::block::get_block::GetBlockClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -298,6 +435,42 @@ impl Client {
     ) -> crate::client::block::get_block_children::GetBlockChildrenClient {
         crate::client::block::get_block_children::GetBlockChildrenClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Fetch the blocks under a page or block with all their descendants,
+    /// following synced blocks and, optionally, child pages.
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::Error> {
+    /// let client = notionrs::Client::new("secret");
+    ///
+    /// let tree = client
+    ///     .get_block_tree("page-id")
+    ///     .max_depth(3)
+    ///     .concurrency(4)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for (depth, node) in tree.iter() {
+    ///     println!("{}{}", "  ".repeat(depth), node.block.block);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_block_tree<T>(
+        &self,
+        block_id: T,
+    ) -> crate::client::block::get_block_tree::GetBlockTreeClient
+    where
+        T: AsRef<str>,
+    {
+        crate::client::block::get_block_tree::GetBlockTreeClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            block_id: Some(block_id.as_ref().to_string()),
             ..Default::default()
         }
     }
@@ -306,6 +479,7 @@ impl Client {
     pub fn delete_block(&self) -> crate::client::block::delete_block::DeleteBlockClient {
         crate::client::block::delete_block::DeleteBlockClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -316,6 +490,40 @@ impl Client {
     ) -> crate::client::block::append_block_children::AppendBlockChildrenClient {
         crate::client::block::append_block_children::AppendBlockChildrenClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Append blocks nested to any depth and in any number, splitting them
+    /// into as many `append_block_children` requests as the API limits need.
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::Error> {
+    /// # use notionrs_types::prelude::*;
+    /// let client = notionrs::Client::new("secret");
+    ///
+    /// let blocks = (0..250)
+    ///     .map(|i| Block::Paragraph {
+    ///         paragraph: ParagraphBlock::from(format!("Line {i}")),
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let created = client
+    ///     .append_block_tree()
+    ///     .block_id("PAGE_ID")
+    ///     .children(blocks)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn append_block_tree(
+        &self,
+    ) -> crate::client::block::append_block_tree::AppendBlockTreeClient {
+        crate::client::block::append_block_tree::AppendBlockTreeClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -324,6 +532,7 @@ impl Client {
     pub fn update_block(&self) -> crate::client::block::update_block::UpdateBlockClient {
         crate::client::block::update_block::UpdateBlockClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -336,6 +545,7 @@ impl Client {
     ) -> crate::client::block::create_meeting_note::CreateMeetingNoteClient {
         crate::client::block::create_meeting_note::CreateMeetingNoteClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -348,6 +558,7 @@ impl Client {
     ) -> crate::client::block::query_meeting_notes::QueryMeetingNotesClient {
         crate::client::block::query_meeting_notes::QueryMeetingNotesClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -362,6 +573,7 @@ impl Client {
     pub fn search(&self) -> crate::client::search::SearchClient {
         crate::client::search::SearchClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -370,6 +582,7 @@ impl Client {
     pub fn search_database(&self) -> crate::client::search::SearchDatabaseClient {
         crate::client::search::SearchDatabaseClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -378,6 +591,7 @@ impl Client {
     pub fn search_page(&self) -> crate::client::search::SearchPageClient {
         crate::client::search::SearchPageClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -392,6 +606,7 @@ impl Client {
     pub fn create_comment(&self) -> crate::client::comment::create_comment::CreateCommentClient {
         crate::client::comment::create_comment::CreateCommentClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -402,6 +617,7 @@ impl Client {
     ) -> crate::client::comment::retrieve_comments::RetrieveCommentsClient {
         crate::client::comment::retrieve_comments::RetrieveCommentsClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -410,6 +626,7 @@ impl Client {
     pub fn update_comment(&self) -> crate::client::comment::update_comment::UpdateCommentClient {
         crate::client::comment::update_comment::UpdateCommentClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -418,6 +635,7 @@ impl Client {
     pub fn delete_comment(&self) -> crate::client::comment::delete_comment::DeleteCommentClient {
         crate::client::comment::delete_comment::DeleteCommentClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -433,6 +651,7 @@ impl Client {
     ) -> crate::client::file_upload::create_file_upload::CreateFileUploadClient {
         crate::client::file_upload::create_file_upload::CreateFileUploadClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -442,6 +661,7 @@ impl Client {
     ) -> crate::client::file_upload::send_file_upload::SendFileUploadClient {
         crate::client::file_upload::send_file_upload::SendFileUploadClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -451,6 +671,7 @@ impl Client {
     ) -> crate::client::file_upload::complete_file_upload::CompleteFileUploadClient {
         crate::client::file_upload::complete_file_upload::CompleteFileUploadClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -460,6 +681,7 @@ impl Client {
     ) -> crate::client::file_upload::retrieve_file_upload::RetrieveFileUploadClient {
         crate::client::file_upload::retrieve_file_upload::RetrieveFileUploadClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -469,6 +691,7 @@ impl Client {
     ) -> crate::client::file_upload::list_file_uploads::ListFileUploadClient {
         crate::client::file_upload::list_file_uploads::ListFileUploadClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -485,6 +708,7 @@ impl Client {
     pub fn create_view(&self) -> crate::client::view::create_view::CreateViewClient {
         crate::client::view::create_view::CreateViewClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -495,6 +719,7 @@ impl Client {
     pub fn retrieve_view(&self) -> crate::client::view::retrieve_view::RetrieveViewClient {
         crate::client::view::retrieve_view::RetrieveViewClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -505,6 +730,7 @@ impl Client {
     pub fn update_view(&self) -> crate::client::view::update_view::UpdateViewClient {
         crate::client::view::update_view::UpdateViewClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -515,6 +741,7 @@ impl Client {
     pub fn delete_view(&self) -> crate::client::view::delete_view::DeleteViewClient {
         crate::client::view::delete_view::DeleteViewClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -525,6 +752,7 @@ impl Client {
     pub fn list_views(&self) -> crate::client::view::list_views::ListViewsClient {
         crate::client::view::list_views::ListViewsClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -537,6 +765,7 @@ impl Client {
     ) -> crate::client::view::create_view_query::CreateViewQueryClient {
         crate::client::view::create_view_query::CreateViewQueryClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -549,6 +778,7 @@ impl Client {
     ) -> crate::client::view::get_view_query_results::GetViewQueryResultsClient {
         crate::client::view::get_view_query_results::GetViewQueryResultsClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -561,6 +791,90 @@ impl Client {
     ) -> crate::client::view::delete_view_query::DeleteViewQueryClient {
         crate::client::view::delete_view_query::DeleteViewQueryClient {
             reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // OAuth
+    //
+    // # --------------------------------------------------------------------------------
+
+    /// Build the URL that sends a user to Notion to authorize a public integration.
+    ///
+    /// ```
+    /// let client = notionrs::Client::builder().build().unwrap();
+    ///
+    /// let url = client
+    ///     .oauth_authorization_url()
+    ///     .client_id("client-id")
+    ///     .redirect_uri("https://example.com/callback")
+    ///     .state("csrf-token")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     url.as_str(),
+    ///     "https://api.notion.com/v1/oauth/authorize?client_id=client-id&response_type=code&owner=user&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&state=csrf-token"
+    /// );
+    /// ```
+    pub fn oauth_authorization_url(
+        &self,
+    ) -> crate::client::oauth::authorization_url::AuthorizationUrlBuilder {
+        crate::client::oauth::authorization_url::AuthorizationUrlBuilder {
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Exchange the authorization code of the OAuth redirect for an access token.
+    ///
+    /// The request authenticates with the integration's client ID and secret
+    /// (HTTP Basic auth) instead of the client's token. Use the returned
+    /// `access_token` with [`Client::with_token`].
+    ///
+    /// <https://developers.notion.com/reference/create-a-token>
+    pub fn create_oauth_token(&self) -> crate::client::oauth::create_token::CreateTokenClient {
+        crate::client::oauth::create_token::CreateTokenClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Exchange a refresh token for a new access token.
+    ///
+    /// <https://developers.notion.com/reference/refresh-a-token>
+    pub fn refresh_oauth_token(&self) -> crate::client::oauth::refresh_token::RefreshTokenClient {
+        crate::client::oauth::refresh_token::RefreshTokenClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Check whether an access token is active.
+    ///
+    /// <https://developers.notion.com/reference/introspect-token>
+    pub fn introspect_oauth_token(
+        &self,
+    ) -> crate::client::oauth::introspect_token::IntrospectTokenClient {
+        crate::client::oauth::introspect_token::IntrospectTokenClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Revoke an access token.
+    ///
+    /// <https://developers.notion.com/reference/revoke-token>
+    pub fn revoke_oauth_token(&self) -> crate::client::oauth::revoke_token::RevokeTokenClient {
+        crate::client::oauth::revoke_token::RevokeTokenClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
             ..Default::default()
         }
     }
@@ -572,22 +886,18 @@ impl Client {
     {
         let mut markdown_list: Vec<String> = Vec::new();
 
-        // Retrieve first-level children for the provided block id.
-        let response = self
-            .get_block_children()
-            .block_id(block_id.as_ref())
-            .into_stream()
-            .try_collect::<Vec<BlockResponse>>()
-            .await?;
+        let tree = self.get_block_tree(block_id).send().await?;
 
-        // Stack holds (BlockResponse, indent_level); changed to stack for traversal
-        let mut stack: Vec<(notionrs_types::prelude::BlockResponse, usize)> = Vec::new();
+        // Stack holds (BlockNode, indent_level); changed to stack for traversal
+        let mut stack: Vec<(notionrs_types::prelude::BlockNode, usize)> = Vec::new();
 
-        for block in response.into_iter().rev() {
-            stack.push((block, 0));
+        for node in tree.children.into_iter().rev() {
+            stack.push((node, 0));
         }
 
-        while let Some((block, indent)) = stack.pop() {
+        while let Some((node, indent)) = stack.pop() {
+            let notionrs_types::prelude::BlockNode { block, children } = node;
+
             match block.block {
                 notionrs_types::prelude::Block::Audio { .. } => continue,
                 notionrs_types::prelude::Block::Bookmark { bookmark } => {
@@ -606,12 +916,8 @@ impl Client {
                     let prefix = "  ".repeat(indent);
                     markdown_list.push(format!("{}- {}", prefix, text));
 
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent + 1));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent + 1));
                     }
                 }
                 notionrs_types::prelude::Block::Callout { callout } => {
@@ -624,12 +930,8 @@ impl Client {
                     let prefix = "  ".repeat(indent);
                     markdown_list.push(format!("{}> {}", prefix, text));
 
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent));
                     }
                 }
                 notionrs_types::prelude::Block::ChildDatabase { .. } => continue,
@@ -649,21 +951,13 @@ impl Client {
                     markdown_list.push(String::new()); // New Line
                 }
                 notionrs_types::prelude::Block::ColumnList { .. } => {
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent));
                     }
                 }
                 notionrs_types::prelude::Block::Column { .. } => {
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent));
                     }
                 }
                 notionrs_types::prelude::Block::Divider { .. } => {
@@ -772,12 +1066,8 @@ impl Client {
                     let prefix = "  ".repeat(indent);
                     markdown_list.push(format!("{}1. {}", prefix, text));
 
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent + 1));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent + 1));
                     }
                 }
                 notionrs_types::prelude::Block::Paragraph { paragraph } => {
@@ -791,12 +1081,8 @@ impl Client {
                     markdown_list.push(format!("{}{}", prefix, text));
                     markdown_list.push(String::new()); // New Line
 
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent));
                     }
                 }
                 notionrs_types::prelude::Block::Pdf { pdf } => {
@@ -815,34 +1101,15 @@ impl Client {
                     let prefix = "  ".repeat(indent);
                     markdown_list.push(format!("{}> {}", prefix, text));
 
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent));
                     }
 
                     markdown_list.push(String::new()); // New Line
                 }
-                notionrs_types::prelude::Block::SyncedBlock { synced_block } => {
-                    let block_id_to_fetch = synced_block
-                        .clone()
-                        .synced_from
-                        .map(|s| s.block_id)
-                        .unwrap_or_else(|| block.id.clone());
-
-                    if block.has_children || synced_block.synced_from.is_some() {
-                        if let Ok(children_resp) = self
-                            .get_block_children()
-                            .block_id(&block_id_to_fetch)
-                            .send()
-                            .await
-                        {
-                            for child in children_resp.results.into_iter().rev() {
-                                stack.push((child, indent));
-                            }
-                        }
+                notionrs_types::prelude::Block::SyncedBlock { .. } => {
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent));
                     }
                 }
                 notionrs_types::prelude::Block::TableOfContents { .. } => continue,
@@ -850,18 +1117,16 @@ impl Client {
                     let prefix = "  ".repeat(indent);
 
                     if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        let rows: Vec<_> = children_resp.results;
+                        let rows = children;
 
                         if rows.is_empty() {
                             continue;
                         }
 
                         let mut table_rows = Vec::new();
-                        for row_block in rows {
+                        for row_node in rows {
                             if let notionrs_types::prelude::Block::TableRow { table_row } =
-                                row_block.block
+                                row_node.block.block
                             {
                                 let cells: Vec<String> = table_row
                                     .cells
@@ -909,12 +1174,8 @@ impl Client {
                     let checkbox = if to_do.checked { "[x]" } else { "[ ]" };
                     markdown_list.push(format!("{}- {} {}", prefix, checkbox, text));
 
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent + 1));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent + 1));
                     }
                 }
                 notionrs_types::prelude::Block::Toggle { toggle } => {
@@ -927,12 +1188,8 @@ impl Client {
                     let prefix = "  ".repeat(indent);
                     markdown_list.push(format!("{}> **{}**", prefix, text));
 
-                    if block.has_children {
-                        let children_resp =
-                            self.get_block_children().block_id(&block.id).send().await?;
-                        for child in children_resp.results.into_iter().rev() {
-                            stack.push((child, indent));
-                        }
+                    for child in children.into_iter().rev() {
+                        stack.push((child, indent));
                     }
 
                     markdown_list.push(String::new()); // New Line
@@ -0,0 +1,51 @@
+/// Build the URL that sends a user to Notion to authorize a public integration.
+///
+/// After the user approves, Notion redirects to `redirect_uri` with a `code`
+/// query parameter, which [`CreateTokenClient`](super::create_token::CreateTokenClient)
+/// exchanges for an access token. `state` is passed back unchanged.
+///
+/// <https://developers.notion.com/docs/authorization#step-1-navigate-the-user-to-the-integrations-authorization-url>
+#[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+pub struct AuthorizationUrlBuilder {
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// The OAuth client ID of the integration.
+    pub(crate) client_id: Option<String>,
+
+    /// Where Notion redirects the user after they authorize the integration.
+    pub(crate) redirect_uri: Option<String>,
+
+    /// An opaque value returned in the redirect, e.g. to prevent CSRF.
+    pub(crate) state: Option<String>,
+}
+
+impl AuthorizationUrlBuilder {
+    /// Build the authorization URL.
+    pub fn build(self) -> Result<reqwest::Url, crate::error::Error> {
+        let client_id = self.client_id.ok_or(crate::error::Error::RequestParameter(
+            "`client_id` is not set.".to_string(),
+        ))?;
+
+        let mut url = reqwest::Url::parse(&format!("{}/v1/oauth/authorize", self.config.base_url))
+            .map_err(|e| crate::error::Error::RequestParameter(e.to_string()))?;
+
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("client_id", &client_id)
+                .append_pair("response_type", "code")
+                .append_pair("owner", "user");
+
+            if let Some(redirect_uri) = &self.redirect_uri {
+                query.append_pair("redirect_uri", redirect_uri);
+            }
+
+            if let Some(state) = &self.state {
+                query.append_pair("state", state);
+            }
+        }
+
+        Ok(url)
+    }
+}
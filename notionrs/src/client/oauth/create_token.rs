@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// Exchange the temporary authorization code for an access token.
+///
+/// <https://developers.notion.com/reference/create-a-token>
+#[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
+pub struct CreateTokenClient {
+    /// The reqwest http client
+    pub(crate) reqwest_client: reqwest::Client,
+
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// The OAuth client ID of the integration.
+    pub(crate) client_id: Option<String>,
+
+    /// The OAuth client secret of the integration.
+    pub(crate) client_secret: Option<String>,
+
+    /// The `code` query parameter of the redirect.
+    pub(crate) code: Option<String>,
+
+    /// The redirect URI used in the authorization URL. Required when the
+    /// integration has more than one redirect URI.
+    pub(crate) redirect_uri: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateTokenRequestBody {
+    grant_type: String,
+
+    code: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_uri: Option<String>,
+}
+
+#[notionrs_macro::blocking]
+impl CreateTokenClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
+    pub async fn send(
+        self,
+    ) -> Result<notionrs_types::object::oauth::OAuthTokenResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+
+        let response = crate::util::send_and_convert::<
+            notionrs_types::object::oauth::OAuthTokenResponse,
+        >(&config, request)
+        .await?;
+
+        Ok(response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let (client_id, client_secret) =
+            super::client_credentials(self.client_id, self.client_secret)?;
+
+        let code = self.code.ok_or(crate::error::Error::RequestParameter(
+            "`code` is not set.".to_string(),
+        ))?;
+
+        let request_body = serde_json::to_string(&CreateTokenRequestBody {
+            grant_type: "authorization_code".to_string(),
+            code,
+            redirect_uri: self.redirect_uri,
+        })?;
+
+        let request = self
+            .reqwest_client
+            .post(format!("{}/v1/oauth/token", self.config.base_url))
+            .basic_auth(client_id, Some(client_secret))
+            .header("Content-Type", "application/json")
+            .body(request_body);
+
+        Ok((self.config, request))
+    }
+}
@@ -0,0 +1,202 @@
+pub mod authorization_url;
+pub mod create_token;
+pub mod introspect_token;
+pub mod refresh_token;
+pub mod revoke_token;
+
+/// Request body of the introspect and revoke endpoints.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct TokenRequestBody {
+    token: String,
+}
+
+/// The client ID and secret that authenticate OAuth requests with HTTP Basic auth.
+fn client_credentials(
+    client_id: Option<String>,
+    client_secret: Option<String>,
+) -> Result<(String, String), crate::error::Error> {
+    let client_id = client_id.ok_or(crate::error::Error::RequestParameter(
+        "`client_id` is not set.".to_string(),
+    ))?;
+
+    let client_secret = client_secret.ok_or(crate::error::Error::RequestParameter(
+        "`client_secret` is not set.".to_string(),
+    ))?;
+
+    Ok((client_id, client_secret))
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    const TOKEN_BODY: &str = r#"{
+        "access_token": "ntn_access",
+        "token_type": "bearer",
+        "refresh_token": "nrt_refresh",
+        "bot_id": "bot-id",
+        "workspace_name": "Acme",
+        "workspace_icon": null,
+        "workspace_id": "workspace-id",
+        "owner": { "type": "workspace", "workspace": true },
+        "duplicated_template_id": null
+    }"#;
+
+    const BASIC_AUTH: &str = "Basic Y2xpZW50LWlkOmNsaWVudC1zZWNyZXQ=";
+
+    fn client(base_url: &str) -> crate::Client {
+        crate::Client::builder()
+            .notion_api_key("integration-secret")
+            .base_url(base_url)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn create_and_refresh_token() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, TOKEN_BODY),
+            TestResponse::json(200, TOKEN_BODY),
+        ])
+        .await;
+
+        // The client credentials win over both the API key and `with_token`.
+        let client = client(&server.base_url()).with_token("workspace-token");
+
+        let token = client
+            .create_oauth_token()
+            .client_id("client-id")
+            .client_secret("client-secret")
+            .code("authorization-code")
+            .redirect_uri("https://example.com/callback")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "ntn_access");
+        assert_eq!(token.bot_id, "bot-id");
+
+        client
+            .refresh_oauth_token()
+            .client_id("client-id")
+            .client_secret("client-secret")
+            .refresh_token("nrt_refresh")
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/v1/oauth/token");
+        assert_eq!(requests[0].header("authorization"), Some(BASIC_AUTH));
+        assert_eq!(
+            requests[0].json(),
+            serde_json::json!({
+                "grant_type": "authorization_code",
+                "code": "authorization-code",
+                "redirect_uri": "https://example.com/callback"
+            })
+        );
+
+        assert_eq!(requests[1].path, "/v1/oauth/token");
+        assert_eq!(requests[1].header("authorization"), Some(BASIC_AUTH));
+        assert_eq!(
+            requests[1].json(),
+            serde_json::json!({ "grant_type": "refresh_token", "refresh_token": "nrt_refresh" })
+        );
+    }
+
+    #[tokio::test]
+    async fn introspect_and_revoke_token() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, r#"{"active":true,"scope":"read_content","iat":1}"#),
+            TestResponse::json(200, r#"{"request_id":"request-id"}"#),
+        ])
+        .await;
+
+        let client = client(&server.base_url());
+
+        let introspection = client
+            .introspect_oauth_token()
+            .client_id("client-id")
+            .client_secret("client-secret")
+            .token("ntn_access")
+            .send()
+            .await
+            .unwrap();
+        assert!(introspection.active);
+
+        client
+            .revoke_oauth_token()
+            .client_id("client-id")
+            .client_secret("client-secret")
+            .token("ntn_access")
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+
+        assert_eq!(requests[0].path, "/v1/oauth/introspect");
+        assert_eq!(requests[0].header("authorization"), Some(BASIC_AUTH));
+        assert_eq!(
+            requests[0].json(),
+            serde_json::json!({ "token": "ntn_access" })
+        );
+
+        assert_eq!(requests[1].path, "/v1/oauth/revoke");
+        assert_eq!(requests[1].header("authorization"), Some(BASIC_AUTH));
+        assert_eq!(
+            requests[1].json(),
+            serde_json::json!({ "token": "ntn_access" })
+        );
+    }
+
+    #[test]
+    fn client_credentials_are_required() {
+        let client = client("http://127.0.0.1:8080");
+
+        let error = client
+            .create_oauth_token()
+            .client_id("client-id")
+            .code("authorization-code")
+            .build_request()
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::RequestParameter(_)));
+
+        let error = client
+            .revoke_oauth_token()
+            .client_id("client-id")
+            .client_secret("client-secret")
+            .build_request()
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::RequestParameter(_)));
+    }
+
+    #[test]
+    fn authorization_url() {
+        let url = client("http://127.0.0.1:8080/")
+            .oauth_authorization_url()
+            .client_id("client-id")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "http://127.0.0.1:8080/v1/oauth/authorize?client_id=client-id&response_type=code&owner=user"
+        );
+
+        let error = client("http://127.0.0.1:8080")
+            .oauth_authorization_url()
+            .state("state")
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::RequestParameter(_)));
+    }
+}
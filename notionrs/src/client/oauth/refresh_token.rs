@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+/// Exchange a refresh token for a new access token.
+///
+/// <https://developers.notion.com/reference/refresh-a-token>
+#[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
+pub struct RefreshTokenClient {
+    /// The reqwest http client
+    pub(crate) reqwest_client: reqwest::Client,
+
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// The OAuth client ID of the integration.
+    pub(crate) client_id: Option<String>,
+
+    /// The OAuth client secret of the integration.
+    pub(crate) client_secret: Option<String>,
+
+    /// The `refresh_token` of an earlier token response.
+    pub(crate) refresh_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RefreshTokenRequestBody {
+    grant_type: String,
+
+    refresh_token: String,
+}
+
+#[notionrs_macro::blocking]
+impl RefreshTokenClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
+    pub async fn send(
+        self,
+    ) -> Result<notionrs_types::object::oauth::OAuthTokenResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+
+        let response = crate::util::send_and_convert::<
+            notionrs_types::object::oauth::OAuthTokenResponse,
+        >(&config, request)
+        .await?;
+
+        Ok(response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let (client_id, client_secret) =
+            super::client_credentials(self.client_id, self.client_secret)?;
+
+        let refresh_token = self
+            .refresh_token
+            .ok_or(crate::error::Error::RequestParameter(
+                "`refresh_token` is not set.".to_string(),
+            ))?;
+
+        let request_body = serde_json::to_string(&RefreshTokenRequestBody {
+            grant_type: "refresh_token".to_string(),
+            refresh_token,
+        })?;
+
+        let request = self
+            .reqwest_client
+            .post(format!("{}/v1/oauth/token", self.config.base_url))
+            .basic_auth(client_id, Some(client_secret))
+            .header("Content-Type", "application/json")
+            .body(request_body);
+
+        Ok((self.config, request))
+    }
+}
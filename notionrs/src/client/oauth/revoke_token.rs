@@ -0,0 +1,68 @@
+/// Revoke an access token. Requests made with it afterwards fail with `401`.
+///
+/// <https://developers.notion.com/reference/revoke-token>
+#[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
+pub struct RevokeTokenClient {
+    /// The reqwest http client
+    pub(crate) reqwest_client: reqwest::Client,
+
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// The OAuth client ID of the integration.
+    pub(crate) client_id: Option<String>,
+
+    /// The OAuth client secret of the integration.
+    pub(crate) client_secret: Option<String>,
+
+    /// The access token to revoke.
+    pub(crate) token: Option<String>,
+}
+
+#[notionrs_macro::blocking]
+impl RevokeTokenClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
+    pub async fn send(self) -> Result<(), crate::error::Error> {
+        let (config, request) = self.into_request()?;
+
+        crate::util::send_request(&config, request).await?;
+
+        Ok(())
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let (client_id, client_secret) =
+            super::client_credentials(self.client_id, self.client_secret)?;
+
+        let token = self.token.ok_or(crate::error::Error::RequestParameter(
+            "`token` is not set.".to_string(),
+        ))?;
+
+        let request_body = serde_json::to_string(&super::TokenRequestBody { token })?;
+
+        let request = self
+            .reqwest_client
+            .post(format!("{}/v1/oauth/revoke", self.config.base_url))
+            .basic_auth(client_id, Some(client_secret))
+            .header("Content-Type", "application/json")
+            .body(request_body);
+
+        Ok((self.config, request))
+    }
+}
@@ -4,12 +4,16 @@ use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 /// @see <https://developers.notion.com/reference/post-page>
 #[derive(Debug, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct CreatePageClient<
     T = std::collections::HashMap<String, notionrs_types::object::page::PageProperty>,
 > {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// Cannot specify the same page ID as the parent page's data_source_id  
     pub(crate) page_id: Option<String>,
 
@@ -60,6 +64,7 @@ impl<T> Default for CreatePageClient<T> {
     fn default() -> Self {
         Self {
             reqwest_client: reqwest::Client::default(),
+            config: Default::default(),
             page_id: None,
             data_source_id: None,
             filter_properties: None,
@@ -111,7 +116,7 @@ pub struct CreatePageRequestBody {
 /// Normally this is the created page (`send()` receives an HTTP `200`). When
 /// `allow_async(true)` is set and Notion accepts the request for asynchronous
 /// processing instead, an HTTP `202` is returned with an async task reference.
-/// Poll it with `Client::get_async_task`.
+/// Poll it with `Client::get_async_task`, or use `into_page_eventually`.
 #[derive(Debug, Clone)]
 pub enum CreatePageResponse<T>
 where
@@ -142,6 +147,42 @@ where
     }
 }
 
+impl<T> CreatePageResponse<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    /// Returns the created page, waiting for the async task to finish if the
+    /// request was accepted for asynchronous processing.
+    ///
+    /// The page is taken from the task result, or retrieved by its ID when the
+    /// result only references it.
+    pub async fn into_page_eventually(
+        self,
+        client: &crate::Client,
+    ) -> Result<notionrs_types::object::page::PageResponse<T>, crate::error::Error> {
+        let task = match self {
+            CreatePageResponse::Page(page) => return Ok(page),
+            CreatePageResponse::AsyncTask(task) => task,
+        };
+
+        let succeeded = client.wait_for_async_task(task.id()).send().await?;
+
+        if let Ok(page) = succeeded.result_as::<notionrs_types::object::page::PageResponse<T>>() {
+            return Ok(page);
+        }
+
+        let page_id = succeeded
+            .result
+            .get("id")
+            .and_then(|id| id.as_str())
+            .ok_or_else(|| crate::error::Error::UnexpectedAsyncTask {
+                task_id: succeeded.id.clone(),
+            })?;
+
+        client.get_page::<T>().page_id(page_id).send().await
+    }
+}
+
 fn parse_create_page_response<T>(
     status: reqwest::StatusCode,
     body: &[u8],
@@ -198,6 +239,7 @@ pub struct CreatePageTemplatePositionAfterBlockPayload {
     id: String,
 }
 
+#[notionrs_macro::blocking]
 impl<T> CreatePageClient<T> {
     /// Change the page-property type used to deserialize the response.
     /// Call this when you want to map properties into a custom struct instead
@@ -205,6 +247,7 @@ impl<T> CreatePageClient<T> {
     pub fn typed<U>(self) -> CreatePageClient<U> {
         CreatePageClient {
             reqwest_client: self.reqwest_client,
+            config: self.config,
             page_id: self.page_id,
             data_source_id: self.data_source_id,
             filter_properties: self.filter_properties,
@@ -290,6 +333,14 @@ impl<T> CreatePageClient<T> {
         self
     }
 
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     /// Send the request and deserialize the response properties into type `T`.
     ///
     /// Use `typed::<MyResponse>()` before calling `send()` to specify a custom struct.
@@ -303,6 +354,28 @@ impl<T> CreatePageClient<T> {
     where
         T: DeserializeOwned + Clone + Send + Sync + 'static,
     {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let status = response.status();
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        parse_create_page_response(status, &body)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let mut parent: Option<notionrs_types::object::parent::Parent> = None;
 
         if let Some(page_id) = self.page_id {
@@ -344,7 +417,7 @@ impl<T> CreatePageClient<T> {
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = "https://api.notion.com/v1/pages".to_string();
+        let url = format!("{}/v1/pages", self.config.base_url);
 
         let request = self
             .reqwest_client
@@ -353,23 +426,7 @@ impl<T> CreatePageClient<T> {
             .body(request_body);
         let request = crate::client::page::with_filter_properties(request, self.filter_properties);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let status = response.status();
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        parse_create_page_response(status, &body)
+        Ok((self.config, request))
     }
 }
 
@@ -551,6 +608,51 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn into_page_eventually_retrieves_the_page_of_the_task() {
+        use crate::util::test_server::{TestResponse, TestServer, page_json};
+
+        let task = |status: &str, extra: &str| {
+            format!(
+                r#"{{"object":"async_task","id":"task-id-123","status":"{status}","status_url":"https://api.notion.com/v1/async_tasks/task-id-123","created_time":"2026-01-01T00:00:00.000Z","operation":{{"surface":"rest","name":"create_page"}}{extra}}}"#
+            )
+        };
+        let page = page_json(
+            "page-id-456",
+            serde_json::json!({ "type": "page_id", "page_id": "parent-id" }),
+            serde_json::json!({}),
+        );
+
+        let server = TestServer::start(vec![
+            TestResponse::json(202, &task("queued", r#","poll_after_seconds":0"#)),
+            TestResponse::json(200, &task("succeeded", r#","result":{"id":"page-id-456"}"#)),
+            TestResponse::json(200, &page.to_string()),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let response = client
+            .create_page::<std::collections::HashMap<String, notionrs_types::object::page::PageProperty>>()
+            .page_id("parent-id")
+            .allow_async(true)
+            .send()
+            .await
+            .unwrap();
+        assert!(matches!(response, CreatePageResponse::AsyncTask(_)));
+
+        let page = response.into_page_eventually(&client).await.unwrap();
+        assert_eq!(page.id, "page-id-456");
+
+        let requests = server.requests().await;
+        assert_eq!(requests[1].path, "/v1/async_tasks/task-id-123");
+        assert_eq!(requests[2].path, "/v1/pages/page-id-456");
+    }
+
     #[tokio::test]
     async fn create_page_client_rejects_children_and_markdown() {
         let client = CreatePageClient::<
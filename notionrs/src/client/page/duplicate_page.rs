@@ -0,0 +1,1203 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use futures::TryStreamExt;
+use notionrs_types::object::block::{Block, BlockResponse};
+use notionrs_types::object::data_source::DataSourceProperty;
+use notionrs_types::object::file::File;
+use notionrs_types::object::page::{PageProperty, PageResponse};
+
+use crate::PaginateExt;
+
+type BoxFuture<'a, T> = std::pin::Pin<
+    Box<dyn std::future::Future<Output = Result<T, crate::error::Error>> + Send + 'a>,
+>;
+
+/// Recreate a page under another parent: its properties, icon, cover and
+/// block tree, and with `recursive(true)` its child pages and databases.
+///
+/// Notion has no endpoint to duplicate a page, so the copy is assembled with
+/// `get_page`, `get_block_children`, `create_page` and `append_block_tree`.
+/// The block tree of each page is read before its copy is created, and pages
+/// and databases created by the duplication are never duplicated themselves,
+/// so the target may be inside the duplicated page. Some content can't be
+/// recreated through the API:
+///
+/// - Files hosted by Notion (in blocks, covers, icons and `files` properties),
+///   link previews, meeting notes and unsupported blocks are left out. The
+///   IDs of the left-out blocks are returned in
+///   [`DuplicatePageResponse::skipped_block_ids`].
+/// - Computed properties such as formulas, rollups and timestamps are left
+///   out, and so are properties the target data source doesn't have with the
+///   same type.
+/// - Child pages and databases are created at the end of the duplicated page,
+///   even if they were nested in another block. Duplicated databases get no
+///   `button`, `formula`, `relation`, `rollup`, `status` or `verification`
+///   properties.
+#[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
+pub struct DuplicatePageClient {
+    /// The reqwest http client
+    pub(crate) reqwest_client: reqwest::Client,
+
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// The ID of the page to duplicate.
+    pub(crate) page_id: Option<String>,
+
+    /// The ID of the page to create the duplicate under.
+    pub(crate) parent_page_id: Option<String>,
+
+    /// The ID of the data source to create the duplicate in.
+    pub(crate) parent_data_source_id: Option<String>,
+
+    /// Whether to also duplicate child pages and child databases, with their
+    /// pages. Off by default.
+    pub(crate) recursive: bool,
+
+    /// Property names of the target data source, keyed by the property names
+    /// of the duplicated page. Properties that aren't listed keep their name,
+    /// except the title, which is matched to the target's title property.
+    pub(crate) property_map: HashMap<String, String>,
+}
+
+/// Result of [`DuplicatePageClient::send`].
+#[derive(Debug, Clone)]
+pub struct DuplicatePageResponse {
+    /// The duplicate of the page.
+    pub page: PageResponse,
+
+    /// IDs of the source blocks that couldn't be recreated, e.g. images
+    /// hosted by Notion.
+    pub skipped_block_ids: Vec<String>,
+}
+
+#[notionrs_macro::blocking]
+impl DuplicatePageClient {
+    pub async fn send(self) -> Result<DuplicatePageResponse, crate::error::Error> {
+        let page_id = self.page_id.ok_or(crate::error::Error::RequestParameter(
+            "`page_id` is not set.".to_string(),
+        ))?;
+
+        let target = match (self.parent_page_id, self.parent_data_source_id) {
+            (Some(page_id), None) => Target::Page(page_id),
+            (None, Some(data_source_id)) => Target::DataSource(data_source_id),
+            _ => {
+                return Err(crate::error::Error::RequestParameter(
+                    "Set exactly one of `parent_page_id` and `parent_data_source_id`.".to_string(),
+                ));
+            }
+        };
+
+        let duplicator = Duplicator {
+            client: crate::Client {
+                reqwest_client: self.reqwest_client,
+                config: self.config,
+            },
+            recursive: self.recursive,
+            deferred: Default::default(),
+            skipped_block_ids: Default::default(),
+            created_ids: Default::default(),
+        };
+
+        let page = duplicator
+            .duplicate_page(&page_id, &target, &self.property_map)
+            .await?;
+
+        loop {
+            let next = duplicator.deferred.lock().unwrap().pop_front();
+            match next {
+                Some(Deferred::Page { source_id, target }) => {
+                    duplicator
+                        .duplicate_page(&source_id, &target, &HashMap::new())
+                        .await?;
+                }
+                Some(Deferred::Database {
+                    source_id,
+                    parent_page_id,
+                }) => {
+                    duplicator
+                        .duplicate_database(&source_id, &parent_page_id)
+                        .await?;
+                }
+                None => break,
+            }
+        }
+
+        Ok(DuplicatePageResponse {
+            page,
+            skipped_block_ids: duplicator.skipped_block_ids.into_inner().unwrap(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Page(String),
+    DataSource(String),
+}
+
+/// Child pages and databases, duplicated once the page they were found on is done.
+#[derive(Debug)]
+enum Deferred {
+    Page {
+        source_id: String,
+        target: Target,
+    },
+    Database {
+        source_id: String,
+        parent_page_id: String,
+    },
+}
+
+/// A child page or child database found in the content of a page.
+#[derive(Debug)]
+enum ChildObject {
+    Page(String),
+    Database(String),
+}
+
+#[derive(Debug)]
+struct Duplicator {
+    client: crate::Client,
+    recursive: bool,
+    deferred: std::sync::Mutex<VecDeque<Deferred>>,
+    skipped_block_ids: std::sync::Mutex<Vec<String>>,
+
+    /// IDs of the pages and databases created so far. They are never
+    /// duplicated, even when the target is inside the duplicated page.
+    created_ids: std::sync::Mutex<HashSet<String>>,
+}
+
+impl Duplicator {
+    async fn duplicate_page(
+        &self,
+        source_id: &str,
+        target: &Target,
+        property_map: &HashMap<String, String>,
+    ) -> Result<PageResponse, crate::error::Error> {
+        let source = self
+            .client
+            .get_page::<HashMap<String, PageProperty>>()
+            .page_id(source_id)
+            .hydrate_truncated_properties(true)
+            .send()
+            .await?;
+
+        // The content is read before the copy exists, which may be created inside it.
+        let mut child_objects = Vec::new();
+        let children = self.get_children(source_id).await?;
+        let blocks = self.copy_blocks(children, &mut child_objects).await?;
+
+        let request = self.client.create_page::<HashMap<String, PageProperty>>();
+
+        let request = match target {
+            Target::Page(page_id) => request
+                .page_id(page_id)
+                .properties(title_only(source.properties)),
+            Target::DataSource(data_source_id) => {
+                let schema = self
+                    .client
+                    .retrieve_data_source()
+                    .data_source_id(data_source_id)
+                    .send()
+                    .await?
+                    .properties;
+
+                request
+                    .data_source_id(data_source_id)
+                    .properties(remap_properties(source.properties, &schema, property_map))
+            }
+        };
+
+        let request = match source.icon {
+            Some(notionrs_types::object::emoji_and_icon::EmojiAndIcon::File(
+                File::NotionHosted(_),
+            ))
+            | None => request,
+            Some(icon) => request.icon(icon),
+        };
+
+        let request = match source.cover {
+            Some(File::NotionHosted(_)) | None => request,
+            Some(cover) => request.cover(cover),
+        };
+
+        let page = request.send().await?.into_page()?;
+        self.created_ids.lock().unwrap().insert(page.id.clone());
+
+        if !blocks.is_empty() {
+            self.client
+                .append_block_tree()
+                .block_id(&page.id)
+                .children(blocks)
+                .send()
+                .await?;
+        }
+
+        self.deferred
+            .lock()
+            .unwrap()
+            .extend(child_objects.into_iter().map(|child| match child {
+                ChildObject::Page(source_id) => Deferred::Page {
+                    source_id,
+                    target: Target::Page(page.id.clone()),
+                },
+                ChildObject::Database(source_id) => Deferred::Database {
+                    source_id,
+                    parent_page_id: page.id.clone(),
+                },
+            }));
+
+        Ok(page)
+    }
+
+    async fn duplicate_database(
+        &self,
+        source_id: &str,
+        parent_page_id: &str,
+    ) -> Result<(), crate::error::Error> {
+        let source = self
+            .client
+            .retrieve_database()
+            .database_id(source_id)
+            .send()
+            .await?;
+
+        let mut database_id = None;
+
+        for data_source in source.data_sources {
+            let schema = self
+                .client
+                .retrieve_data_source()
+                .data_source_id(&data_source.id)
+                .send()
+                .await?;
+
+            let properties = schema
+                .properties
+                .into_iter()
+                .filter(|(_, property)| is_copyable_schema(property))
+                .collect::<HashMap<_, _>>();
+
+            let data_source_id = match &database_id {
+                None => {
+                    let mut request = self
+                        .client
+                        .create_database()
+                        .page_id(parent_page_id)
+                        .title(source.title.clone())
+                        .description(source.description.clone())
+                        .properties(properties);
+
+                    if let Some(icon) = source.icon.clone() {
+                        request = request.icon(icon);
+                    }
+
+                    let database = request.send().await?;
+                    self.created_ids.lock().unwrap().insert(database.id.clone());
+                    database_id = Some(database.id);
+
+                    database
+                        .data_sources
+                        .into_iter()
+                        .next()
+                        .map(|data_source| data_source.id)
+                        .ok_or_else(|| {
+                            crate::error::Error::BodyParse(
+                                "The created database has no data source.".to_string(),
+                            )
+                        })?
+                }
+                Some(database_id) => {
+                    self.client
+                        .create_data_source()
+                        .database_id(database_id)
+                        .title(schema.title)
+                        .properties(properties)
+                        .send()
+                        .await?
+                        .id
+                }
+            };
+
+            let pages = self
+                .client
+                .query_data_source()
+                .data_source_id(&data_source.id)
+                .into_stream()
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            let created_ids = self.created_ids.lock().unwrap();
+            self.deferred.lock().unwrap().extend(
+                pages
+                    .into_iter()
+                    .filter(|page| !created_ids.contains(&page.id))
+                    .map(|page| Deferred::Page {
+                        source_id: page.id,
+                        target: Target::DataSource(data_source_id.clone()),
+                    }),
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn get_children(
+        &self,
+        block_id: &str,
+    ) -> Result<Vec<BlockResponse>, crate::error::Error> {
+        self.client
+            .get_block_children()
+            .block_id(block_id)
+            .into_stream()
+            .try_collect()
+            .await
+    }
+
+    /// Copy the source blocks with their nested blocks, skipping the blocks
+    /// that can't be recreated. Child pages and databases are collected in
+    /// `child_objects` instead, when duplicated recursively.
+    fn copy_blocks<'a>(
+        &'a self,
+        sources: Vec<BlockResponse>,
+        child_objects: &'a mut Vec<ChildObject>,
+    ) -> BoxFuture<'a, Vec<Block>> {
+        Box::pin(async move {
+            let mut blocks = Vec::new();
+
+            for source in sources {
+                match &source.block {
+                    Block::ChildPage { .. } | Block::ChildDatabase { .. }
+                        if self.created_ids.lock().unwrap().contains(&source.id) => {}
+                    Block::ChildPage { .. } => {
+                        if self.recursive {
+                            child_objects.push(ChildObject::Page(source.id));
+                        }
+                    }
+                    Block::ChildDatabase { .. } => {
+                        if self.recursive {
+                            child_objects.push(ChildObject::Database(source.id));
+                        }
+                    }
+                    block if !is_recreatable(block) => {
+                        self.skipped_block_ids.lock().unwrap().push(source.id);
+                    }
+                    _ => {
+                        // The children of a synced copy belong to the original block.
+                        let synced_copy = matches!(
+                            &source.block,
+                            Block::SyncedBlock { synced_block } if synced_block.synced_from.is_some()
+                        );
+
+                        let mut block = source.block;
+
+                        if let Some(slot) = block
+                            .children_mut()
+                            .filter(|_| source.has_children && !synced_copy)
+                        {
+                            let children = self.get_children(&source.id).await?;
+                            *slot = Some(self.copy_blocks(children, child_objects).await?);
+                        }
+
+                        blocks.push(block);
+                    }
+                }
+            }
+
+            Ok(blocks)
+        })
+    }
+}
+
+/// Whether the API can create a copy of the block.
+#[allow(deprecated)]
+fn is_recreatable(block: &Block) -> bool {
+    match block {
+        Block::Audio { audio: file }
+        | Block::File { file }
+        | Block::Image { image: file }
+        | Block::Pdf { pdf: file }
+        | Block::Video { video: file } => !matches!(file, File::NotionHosted(_)),
+        Block::LinkPreview { .. }
+        | Block::Template { .. }
+        | Block::MeetingNotes { .. }
+        | Block::Transcription { .. }
+        | Block::Unsupported { .. } => false,
+        _ => true,
+    }
+}
+
+/// Whether a property can be added to a duplicated database as it is.
+fn is_copyable_schema(property: &DataSourceProperty) -> bool {
+    !matches!(
+        property,
+        DataSourceProperty::Button(_)
+            | DataSourceProperty::Formula(_)
+            | DataSourceProperty::Relation(_)
+            | DataSourceProperty::Rollup(_)
+            | DataSourceProperty::Status(_)
+            | DataSourceProperty::Verification(_)
+    )
+}
+
+/// The `type` a property value or schema is tagged with.
+fn type_name<T: serde::Serialize>(property: &T) -> Option<String> {
+    serde_json::to_value(property).ok()?["type"]
+        .as_str()
+        .map(str::to_string)
+}
+
+/// A page under another page only has a title.
+fn title_only(properties: HashMap<String, PageProperty>) -> HashMap<String, PageProperty> {
+    properties
+        .into_values()
+        .find(|property| matches!(property, PageProperty::Title(_)))
+        .map(|title| HashMap::from([("title".to_string(), title)]))
+        .unwrap_or_default()
+}
+
+/// Rename the properties for the target data source, keeping those it has
+/// with the same type.
+fn remap_properties(
+    properties: HashMap<String, PageProperty>,
+    schema: &HashMap<String, DataSourceProperty>,
+    property_map: &HashMap<String, String>,
+) -> HashMap<String, PageProperty> {
+    let title_name = schema
+        .iter()
+        .find(|(_, property)| matches!(property, DataSourceProperty::Title(_)))
+        .map(|(name, _)| name.clone());
+
+    properties
+        .into_iter()
+        .filter(|(_, property)| !property.is_read_only())
+        .filter_map(|(name, mut property)| {
+            let name = match property_map.get(&name) {
+                Some(name) => name.clone(),
+                None if matches!(property, PageProperty::Title(_)) => title_name.clone()?,
+                None => name,
+            };
+
+            if type_name(schema.get(&name)?) != type_name(&property) {
+                return None;
+            }
+
+            if let PageProperty::Files(files) = &mut property {
+                files
+                    .files
+                    .retain(|file| !matches!(file, File::NotionHosted(_)));
+            }
+
+            Some((name, property))
+        })
+        .collect()
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::util::test_server::{TestResponse, TestServer, block_json, list_json, page_json};
+
+    fn page(id: &str, parent: serde_json::Value, properties: serde_json::Value) -> String {
+        let mut page = page_json(id, parent, properties);
+        page["icon"] = serde_json::json!({ "type": "emoji", "emoji": "📋" });
+        page.to_string()
+    }
+
+    fn paragraph(id: &str, has_children: bool) -> serde_json::Value {
+        block_json(
+            id,
+            has_children,
+            serde_json::json!({
+                "type": "paragraph",
+                "paragraph": { "rich_text": [], "color": "default" }
+            }),
+        )
+    }
+
+    fn title(text: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "title",
+            "type": "title",
+            "title": [{
+                "type": "text",
+                "text": { "content": text, "link": null },
+                "annotations": {
+                    "bold": false, "italic": false, "strikethrough": false,
+                    "underline": false, "code": false, "color": "default"
+                },
+                "plain_text": text,
+                "href": null
+            }]
+        })
+    }
+
+    #[tokio::test]
+    async fn duplicate_page_with_block_tree_and_child_page() {
+        let source_parent = serde_json::json!({ "type": "data_source_id", "data_source_id": "ds" });
+        let target_parent = serde_json::json!({ "type": "page_id", "page_id": "target-id" });
+
+        let server = TestServer::start(vec![
+            // The source page and its copy.
+            TestResponse::json(
+                200,
+                &page(
+                    "source-id",
+                    source_parent,
+                    serde_json::json!({
+                        "Name": title("Template"),
+                        "Created": {
+                            "id": "abc",
+                            "type": "created_time",
+                            "created_time": "2026-01-01T00:00:00.000Z"
+                        }
+                    }),
+                ),
+            ),
+            // The content of the source page.
+            TestResponse::json(
+                200,
+                &list_json(vec![
+                    paragraph("paragraph-id", true),
+                    block_json(
+                        "image-id",
+                        false,
+                        serde_json::json!({
+                            "type": "image",
+                            "image": {
+                                "type": "file",
+                                "file": {
+                                    "url": "https://prod-files-secure.s3.amazonaws.com/image.png",
+                                    "expiry_time": "2026-01-01T01:00:00.000Z"
+                                }
+                            }
+                        }),
+                    ),
+                    block_json(
+                        "child-id",
+                        false,
+                        serde_json::json!({ "type": "child_page", "child_page": { "title": "Child" } }),
+                    ),
+                ]).to_string(),
+            ),
+            // The children of the paragraph.
+            TestResponse::json(200, &list_json(vec![paragraph("nested-id", false)]).to_string()),
+            // The copy and its content.
+            TestResponse::json(
+                200,
+                &page(
+                    "copy-id",
+                    target_parent.clone(),
+                    serde_json::json!({ "title": title("Template") }),
+                ),
+            ),
+            TestResponse::json(200, &list_json(vec![paragraph("paragraph-copy-id", false)]).to_string()),
+            TestResponse::json(200, &list_json(vec![paragraph("nested-copy-id", false)]).to_string()),
+            // The child page, duplicated under the copy.
+            TestResponse::json(
+                200,
+                &page(
+                    "child-id",
+                    serde_json::json!({ "type": "page_id", "page_id": "source-id" }),
+                    serde_json::json!({ "title": title("Child") }),
+                ),
+            ),
+            TestResponse::json(200, &list_json(vec![]).to_string()),
+            TestResponse::json(
+                200,
+                &page(
+                    "child-copy-id",
+                    serde_json::json!({ "type": "page_id", "page_id": "copy-id" }),
+                    serde_json::json!({ "title": title("Child") }),
+                ),
+            ),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let response = client
+            .duplicate_page("source-id")
+            .parent_page_id("target-id")
+            .recursive(true)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.page.id, "copy-id");
+        assert_eq!(response.skipped_block_ids, vec!["image-id".to_string()]);
+
+        let requests = server.requests().await;
+        let paths = requests
+            .iter()
+            .map(|request| format!("{} {}", request.method, request.path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "GET /v1/pages/source-id",
+                "GET /v1/blocks/source-id/children?page_size=100",
+                "GET /v1/blocks/paragraph-id/children?page_size=100",
+                "POST /v1/pages",
+                "PATCH /v1/blocks/copy-id/children",
+                "PATCH /v1/blocks/paragraph-copy-id/children",
+                "GET /v1/pages/child-id",
+                "GET /v1/blocks/child-id/children?page_size=100",
+                "POST /v1/pages",
+            ]
+        );
+
+        let created = requests[3].json();
+        assert_eq!(created["parent"]["page_id"], "target-id");
+        assert_eq!(
+            created["properties"]["title"]["title"][0]["plain_text"],
+            "Template"
+        );
+        assert!(created["properties"].get("Created").is_none());
+        assert_eq!(created["icon"]["emoji"], "📋");
+
+        let appended = requests[4].json();
+        assert_eq!(appended["children"].as_array().unwrap().len(), 1);
+        assert_eq!(appended["children"][0]["type"], "paragraph");
+
+        assert_eq!(requests[8].json()["parent"]["page_id"], "copy-id");
+    }
+
+    #[tokio::test]
+    async fn duplicate_table_in_column() {
+        let parent = serde_json::json!({ "type": "page_id", "page_id": "target-id" });
+
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &page(
+                    "source-id",
+                    parent.clone(),
+                    serde_json::json!({ "title": title("Columns") }),
+                ),
+            ),
+            // The source column list, its column and the table in it.
+            TestResponse::json(
+                200,
+                &list_json(vec![block_json(
+                    "column-list-id",
+                    true,
+                    serde_json::json!({ "type": "column_list", "column_list": {} }),
+                )]).to_string(),
+            ),
+            TestResponse::json(
+                200,
+                &list_json(vec![block_json(
+                    "column-id",
+                    true,
+                    serde_json::json!({ "type": "column", "column": { "width_ratio": 0.5 } }),
+                )]).to_string(),
+            ),
+            TestResponse::json(
+                200,
+                &list_json(vec![block_json(
+                    "table-id",
+                    true,
+                    serde_json::json!({
+                        "type": "table",
+                        "table": { "table_width": 1, "has_column_header": false, "has_row_header": false }
+                    }),
+                )]).to_string(),
+            ),
+            TestResponse::json(
+                200,
+                &list_json(vec![block_json(
+                    "row-id",
+                    false,
+                    serde_json::json!({ "type": "table_row", "table_row": { "cells": [[]] } }),
+                )]).to_string(),
+            ),
+            TestResponse::json(
+                200,
+                &page(
+                    "copy-id",
+                    parent,
+                    serde_json::json!({ "title": title("Columns") }),
+                ),
+            ),
+            // The copy of the column list, created with a placeholder.
+            TestResponse::json(200, &list_json(vec![paragraph("column-list-copy-id", true)]).to_string()),
+            TestResponse::json(200, &list_json(vec![paragraph("column-copy-id", true)]).to_string()),
+            TestResponse::json(200, &list_json(vec![paragraph("placeholder-id", false)]).to_string()),
+            // The copy of the table, then the placeholder is deleted.
+            TestResponse::json(200, &list_json(vec![paragraph("table-copy-id", true)]).to_string()),
+            TestResponse::json(200, &paragraph("placeholder-id", false).to_string()),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        client
+            .duplicate_page("source-id")
+            .parent_page_id("target-id")
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+        let paths = requests
+            .iter()
+            .map(|request| format!("{} {}", request.method, request.path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "GET /v1/pages/source-id",
+                "GET /v1/blocks/source-id/children?page_size=100",
+                "GET /v1/blocks/column-list-id/children?page_size=100",
+                "GET /v1/blocks/column-id/children?page_size=100",
+                "GET /v1/blocks/table-id/children?page_size=100",
+                "POST /v1/pages",
+                "PATCH /v1/blocks/copy-id/children",
+                "GET /v1/blocks/column-list-copy-id/children?page_size=100",
+                "GET /v1/blocks/column-copy-id/children?page_size=100",
+                "PATCH /v1/blocks/column-copy-id/children",
+                "DELETE /v1/blocks/placeholder-id",
+            ]
+        );
+
+        let column = &requests[6].json()["children"][0]["column_list"]["children"][0];
+        let children = column["column"]["children"].as_array().unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0]["type"], "paragraph");
+
+        let table = requests[9].json();
+        assert_eq!(table["position"]["type"], "start");
+        assert_eq!(table["children"][0]["type"], "table");
+        assert_eq!(
+            table["children"][0]["table"]["children"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_tree_over_the_request_block_limit() {
+        let parent = serde_json::json!({ "type": "page_id", "page_id": "target-id" });
+
+        // 11 tables of 100 rows: 1111 blocks, more than a request may create.
+        let tables = (0..11)
+            .map(|i| {
+                block_json(
+                    &format!("table-{i}"),
+                    true,
+                    serde_json::json!({
+                        "type": "table",
+                        "table": { "table_width": 1, "has_column_header": false, "has_row_header": false }
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+        let rows = (0..100)
+            .map(|i| {
+                block_json(
+                    &format!("row-{i}"),
+                    false,
+                    serde_json::json!({ "type": "table_row", "table_row": { "cells": [[]] } }),
+                )
+            })
+            .collect::<Vec<_>>();
+        let created = |count: usize| {
+            list_json(
+                (0..count)
+                    .map(|i| paragraph(&format!("table-copy-{i}"), true))
+                    .collect(),
+            )
+            .to_string()
+        };
+
+        let mut responses = vec![
+            TestResponse::json(
+                200,
+                &page(
+                    "source-id",
+                    parent.clone(),
+                    serde_json::json!({ "title": title("Tables") }),
+                ),
+            ),
+            TestResponse::json(200, &list_json(tables).to_string()),
+        ];
+        responses
+            .extend((0..11).map(|_| TestResponse::json(200, &list_json(rows.clone()).to_string())));
+        responses.extend([
+            TestResponse::json(
+                200,
+                &page(
+                    "copy-id",
+                    parent,
+                    serde_json::json!({ "title": title("Tables") }),
+                ),
+            ),
+            TestResponse::json(200, &created(9)),
+            TestResponse::json(200, &created(2)),
+        ]);
+
+        let server = TestServer::start(responses).await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        client
+            .duplicate_page("source-id")
+            .parent_page_id("target-id")
+            .send()
+            .await
+            .unwrap();
+
+        let appends = server
+            .requests()
+            .await
+            .into_iter()
+            .filter(|request| request.method == "PATCH")
+            .map(|request| request.json())
+            .collect::<Vec<_>>();
+        assert_eq!(appends.len(), 2);
+
+        let blocks = appends
+            .iter()
+            .map(|append| {
+                append["children"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|table| 1 + table["table"]["children"].as_array().unwrap().len())
+                    .sum::<usize>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(blocks, vec![909, 202]);
+
+        assert_eq!(
+            appends[1]["position"],
+            serde_json::json!({ "type": "after_block", "after_block": { "id": "table-copy-8" } })
+        );
+    }
+
+    #[tokio::test]
+    async fn skip_the_copy_when_the_target_is_inside_the_source() {
+        let source_parent = serde_json::json!({ "type": "page_id", "page_id": "root-id" });
+        let child_parent = serde_json::json!({ "type": "page_id", "page_id": "source-id" });
+
+        let child_page = |id: &str| {
+            block_json(
+                id,
+                false,
+                serde_json::json!({ "type": "child_page", "child_page": { "title": "Child" } }),
+            )
+        };
+
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &page(
+                    "source-id",
+                    source_parent,
+                    serde_json::json!({ "title": title("Source") }),
+                ),
+            ),
+            TestResponse::json(200, &list_json(vec![child_page("child-id")]).to_string()),
+            // The copy, created inside the child page.
+            TestResponse::json(
+                200,
+                &page(
+                    "copy-id",
+                    serde_json::json!({ "type": "page_id", "page_id": "child-id" }),
+                    serde_json::json!({ "title": title("Source") }),
+                ),
+            ),
+            // The child page now contains the copy, which is left alone.
+            TestResponse::json(
+                200,
+                &page(
+                    "child-id",
+                    child_parent,
+                    serde_json::json!({ "title": title("Child") }),
+                ),
+            ),
+            TestResponse::json(200, &list_json(vec![child_page("copy-id")]).to_string()),
+            TestResponse::json(
+                200,
+                &page(
+                    "child-copy-id",
+                    serde_json::json!({ "type": "page_id", "page_id": "copy-id" }),
+                    serde_json::json!({ "title": title("Child") }),
+                ),
+            ),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        client
+            .duplicate_page("source-id")
+            .parent_page_id("child-id")
+            .recursive(true)
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+        let paths = requests
+            .iter()
+            .map(|request| format!("{} {}", request.method, request.path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "GET /v1/pages/source-id",
+                "GET /v1/blocks/source-id/children?page_size=100",
+                "POST /v1/pages",
+                "GET /v1/pages/child-id",
+                "GET /v1/blocks/child-id/children?page_size=100",
+                "POST /v1/pages",
+            ]
+        );
+        assert_eq!(requests[5].json()["parent"]["page_id"], "copy-id");
+    }
+
+    #[tokio::test]
+    async fn duplicate_child_database_with_schema_and_rows() {
+        let target_parent = serde_json::json!({ "type": "page_id", "page_id": "target-id" });
+        let row_parent = serde_json::json!({ "type": "data_source_id", "data_source_id": "ds-id" });
+
+        let database = |id: &str, data_source_id: &str| {
+            serde_json::json!({
+                "object": "database",
+                "id": id,
+                "title": [],
+                "description": [],
+                "parent": { "type": "page_id", "page_id": "page-id" },
+                "is_inline": true,
+                "in_trash": false,
+                "is_locked": false,
+                "created_time": "2026-01-01T00:00:00.000Z",
+                "last_edited_time": "2026-01-01T00:00:00.000Z",
+                "data_sources": [{ "id": data_source_id, "name": "Tasks" }],
+                "icon": null,
+                "cover": null,
+                "url": format!("https://www.notion.so/{id}"),
+                "public_url": null
+            })
+            .to_string()
+        };
+        let data_source = |id: &str| {
+            serde_json::json!({
+                "object": "data_source",
+                "id": id,
+                "created_time": "2026-01-01T00:00:00.000Z",
+                "last_edited_time": "2026-01-01T00:00:00.000Z",
+                "parent": { "type": "database_id", "database_id": "database-id" },
+                "properties": {
+                    "Name": { "id": "title", "name": "Name", "type": "title", "title": {} },
+                    "Points": { "id": "a", "name": "Points", "type": "number", "number": { "format": "number" } },
+                    "Double": {
+                        "id": "b", "name": "Double", "type": "formula",
+                        "formula": { "expression": "prop(\"Points\") * 2" }
+                    }
+                },
+                "icon": null,
+                "cover": null,
+                "url": format!("https://www.notion.so/{id}"),
+                "title": [],
+                "in_trash": false,
+                "description": [],
+                "public_url": null
+            })
+            .to_string()
+        };
+        let row = |id: &str, parent: serde_json::Value| {
+            page(
+                id,
+                parent,
+                serde_json::json!({
+                    "Name": title("Task"),
+                    "Points": { "id": "a", "type": "number", "number": 3 },
+                    "Double": { "id": "b", "type": "formula", "formula": { "type": "number", "number": 6 } }
+                }),
+            )
+        };
+
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &page(
+                    "source-id",
+                    target_parent.clone(),
+                    serde_json::json!({ "title": title("Project") }),
+                ),
+            ),
+            TestResponse::json(
+                200,
+                &list_json(vec![block_json(
+                    "database-id",
+                    true,
+                    serde_json::json!({ "type": "child_database", "child_database": { "title": "Tasks" } }),
+                )])
+                .to_string(),
+            ),
+            TestResponse::json(
+                200,
+                &page(
+                    "copy-id",
+                    target_parent,
+                    serde_json::json!({ "title": title("Project") }),
+                ),
+            ),
+            // The database, its schema, its copy and its rows.
+            TestResponse::json(200, &database("database-id", "ds-id")),
+            TestResponse::json(200, &data_source("ds-id")),
+            TestResponse::json(200, &database("database-copy-id", "ds-copy-id")),
+            TestResponse::json(
+                200,
+                &list_json(vec![serde_json::from_str(&row("row-id", row_parent)).unwrap()])
+                    .to_string(),
+            ),
+            // The row, duplicated into the copy of the data source.
+            TestResponse::json(
+                200,
+                &row(
+                    "row-id",
+                    serde_json::json!({ "type": "data_source_id", "data_source_id": "ds-id" }),
+                ),
+            ),
+            TestResponse::json(200, &list_json(vec![]).to_string()),
+            TestResponse::json(200, &data_source("ds-copy-id")),
+            TestResponse::json(
+                200,
+                &row(
+                    "row-copy-id",
+                    serde_json::json!({ "type": "data_source_id", "data_source_id": "ds-copy-id" }),
+                ),
+            ),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        client
+            .duplicate_page("source-id")
+            .parent_page_id("target-id")
+            .recursive(true)
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+        let paths = requests
+            .iter()
+            .map(|request| format!("{} {}", request.method, request.path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "GET /v1/pages/source-id",
+                "GET /v1/blocks/source-id/children?page_size=100",
+                "POST /v1/pages",
+                "GET /v1/databases/database-id",
+                "GET /v1/data_sources/ds-id",
+                "POST /v1/databases",
+                "POST /v1/data_sources/ds-id/query",
+                "GET /v1/pages/row-id",
+                "GET /v1/blocks/row-id/children?page_size=100",
+                "GET /v1/data_sources/ds-copy-id",
+                "POST /v1/pages",
+            ]
+        );
+
+        let created_database = requests[5].json();
+        assert_eq!(created_database["parent"]["page_id"], "copy-id");
+        let mut schema = created_database["properties"]["initial_data_source"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        schema.sort();
+        assert_eq!(schema, vec!["Name", "Points"]);
+
+        let created_row = requests[10].json();
+        assert_eq!(created_row["parent"]["data_source_id"], "ds-copy-id");
+        assert_eq!(created_row["properties"]["Points"]["number"], 3.0);
+        assert_eq!(
+            created_row["properties"]["Name"]["title"][0]["plain_text"],
+            "Task"
+        );
+        assert!(created_row["properties"].get("Double").is_none());
+    }
+
+    #[test]
+    fn remap_properties_for_target_schema() {
+        let schema = serde_json::from_value::<HashMap<String, DataSourceProperty>>(
+            serde_json::json!({
+                "Task": { "id": "title", "name": "Task", "type": "title", "title": {} },
+                "Estimate": { "id": "a", "name": "Estimate", "type": "number", "number": { "format": "number" } },
+                "Notes": { "id": "b", "name": "Notes", "type": "url", "url": {} }
+            }),
+        )
+        .unwrap();
+
+        let properties =
+            serde_json::from_value::<HashMap<String, PageProperty>>(serde_json::json!({
+                "Name": title("Write docs"),
+                "Points": { "id": "c", "type": "number", "number": 3 },
+                "Notes": { "id": "d", "type": "rich_text", "rich_text": [] }
+            }))
+            .unwrap();
+
+        let remapped = remap_properties(
+            properties,
+            &schema,
+            &HashMap::from([("Points".to_string(), "Estimate".to_string())]),
+        );
+
+        let mut names = remapped.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, vec!["Estimate", "Task"]);
+    }
+
+    #[tokio::test]
+    async fn rejects_ambiguous_target() {
+        let error = crate::Client::new("secret")
+            .duplicate_page("source-id")
+            .parent_page_id("page-id")
+            .parent_data_source_id("data-source-id")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, crate::Error::RequestParameter(_)));
+    }
+}
@@ -3,15 +3,32 @@ use std::marker::PhantomData;
 use serde::{Serialize, de::DeserializeOwned};
 
 #[derive(Debug, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct GetPageClient<
     T = std::collections::HashMap<String, notionrs_types::prelude::PageProperty>,
 > {
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// Specify the ID of the page.
     /// The ID is also included in the Notion page URL.
     pub(crate) page_id: Option<String>,
 
+    /// Property IDs or names to include in the returned page's `properties`.
+    /// IDs may be passed in the URL-encoded form returned by Notion.
+    pub(crate) filter_properties: Option<Vec<String>>,
+
+    /// Fill in relation and people properties that Notion cut off at 25
+    /// references, using the property item endpoint. Off by default.
+    ///
+    /// A relation is hydrated when Notion reports `has_more`. A people property
+    /// has no such flag, so one with 25 or more entries is assumed to be cut
+    /// off and is hydrated even when it is complete. Every hydrated property
+    /// costs at least one extra request.
+    pub(crate) hydrate_truncated_properties: bool,
+
     #[setter(skip)]
     pub(crate) _phantom: PhantomData<T>,
 }
@@ -20,43 +37,71 @@ impl<T> Default for GetPageClient<T> {
     fn default() -> Self {
         Self {
             reqwest_client: reqwest::Client::default(),
+            config: Default::default(),
             page_id: None,
+            filter_properties: None,
+            hydrate_truncated_properties: false,
             _phantom: PhantomData,
         }
     }
 }
 
+#[notionrs_macro::blocking]
 impl<T> GetPageClient<T>
 where
     T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
 {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::page::PageResponse<T>, crate::error::Error> {
-        match self.page_id {
-            Some(id) => {
-                let url = format!("https://api.notion.com/v1/pages/{}", id);
+        let reqwest_client = self.reqwest_client.clone();
+        let hydrate_truncated_properties = self.hydrate_truncated_properties;
 
-                let request = self.reqwest_client.get(url);
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
-                let response = request
-                    .send()
-                    .await
-                    .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
 
-                if !response.status().is_success() {
-                    return Err(crate::error::Error::try_from_response_async(response).await);
-                }
+        if !hydrate_truncated_properties {
+            let page =
+                serde_json::from_slice::<notionrs_types::object::page::PageResponse<T>>(&body)?;
+            return Ok(page);
+        }
 
-                let body = response
-                    .bytes()
-                    .await
-                    .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+        let mut page = serde_json::from_slice::<serde_json::Value>(&body)?;
+        super::hydrate_truncated_properties(&reqwest_client, &config, &mut page).await?;
 
-                let page =
-                    serde_json::from_slice::<notionrs_types::object::page::PageResponse<T>>(&body)?;
+        Ok(serde_json::from_value(page)?)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        match self.page_id {
+            Some(id) => {
+                let url = format!("{}/v1/pages/{}", self.config.base_url, id);
+
+                let request = self.reqwest_client.get(url);
+                let request = super::with_filter_properties(request, self.filter_properties);
 
-                Ok(page)
+                Ok((self.config, request))
             }
             None => Err(crate::error::Error::RequestParameter(
                 "`user_id` is not set.".to_string(),
@@ -64,3 +109,92 @@ where
         }
     }
 }
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use notionrs_types::object::page::PageProperty;
+
+    use crate::util::test_server::{TestResponse, TestServer, page_json};
+
+    #[test]
+    fn build_request_with_filter_properties() {
+        let request = crate::Client::new("secret")
+            .get_page::<std::collections::HashMap<String, PageProperty>>()
+            .page_id("page-id")
+            .filter_properties(vec!["title".to_string()])
+            .build_request()
+            .unwrap();
+
+        assert_eq!(
+            request.url.as_str(),
+            "https://api.notion.com/v1/pages/page-id?filter_properties=title"
+        );
+    }
+
+    #[tokio::test]
+    async fn hydrate_truncated_people() {
+        let user = |i: usize| serde_json::json!({ "object": "user", "id": format!("user-{i}") });
+
+        let page = page_json(
+            "page-id",
+            serde_json::json!({ "type": "page_id", "page_id": "parent-id" }),
+            serde_json::json!({
+                "Reviewers": {
+                    "id": "pAoV",
+                    "type": "people",
+                    "people": (0..25).map(user).collect::<Vec<_>>()
+                }
+            }),
+        );
+
+        let people = serde_json::json!({
+            "object": "list",
+            "results": (0..40)
+                .map(|i| serde_json::json!({
+                    "object": "property_item",
+                    "id": "pAoV",
+                    "type": "people",
+                    "people": user(i)
+                }))
+                .collect::<Vec<_>>(),
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": { "id": "pAoV", "next_url": null, "type": "people", "people": {} }
+        });
+
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &page.to_string()),
+            TestResponse::json(200, &people.to_string()),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let page = client
+            .get_page::<std::collections::HashMap<String, PageProperty>>()
+            .page_id("page-id")
+            .hydrate_truncated_properties(true)
+            .send()
+            .await
+            .unwrap();
+
+        match &page.properties["Reviewers"] {
+            PageProperty::People(people) => assert_eq!(people.people.len(), 40),
+            other => panic!("Expected people, got {other:?}"),
+        }
+
+        let requests = server.requests().await;
+        assert_eq!(requests[1].path, "/v1/pages/page-id/properties/pAoV");
+    }
+}
@@ -1,9 +1,13 @@
 /// <https://developers.notion.com/reference/retrieve-page-markdown>
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct GetPageMarkdownClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the page (or block) to retrieve as markdown.
     /// Non-navigable block IDs from truncated responses can be passed here
     /// to fetch their subtrees.
@@ -15,16 +19,49 @@ pub struct GetPageMarkdownClient {
     pub(crate) include_transcript: Option<bool>,
 }
 
+#[notionrs_macro::blocking]
 impl GetPageMarkdownClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::page_markdown::PageMarkdownResponse, crate::error::Error>
     {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let page_markdown = serde_json::from_slice::<
+            notionrs_types::object::page_markdown::PageMarkdownResponse,
+        >(&body)?;
+
+        Ok(page_markdown)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let page_id = self.page_id.ok_or(crate::error::Error::RequestParameter(
             "`page_id` is not set.".to_string(),
         ))?;
 
-        let url = format!("https://api.notion.com/v1/pages/{}/markdown", page_id);
+        let url = format!("{}/v1/pages/{}/markdown", self.config.base_url, page_id);
 
         let request = self.reqwest_client.get(url);
         let request = if let Some(include_transcript) = self.include_transcript {
@@ -33,24 +70,6 @@ impl GetPageMarkdownClient {
             request
         };
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let page_markdown = serde_json::from_slice::<
-            notionrs_types::object::page_markdown::PageMarkdownResponse,
-        >(&body)?;
-
-        Ok(page_markdown)
+        Ok((self.config, request))
     }
 }
@@ -1,18 +1,97 @@
-#[derive(Debug, Default, notionrs_macro::Setter)]
+use notionrs_types::object::page::property_item::PropertyItemResponse;
+
+#[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct GetPagePropertyItemClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) page_id: Option<String>,
 
     pub(crate) property_id: Option<String>,
+
+    #[setter(skip)]
+    pub(crate) start_cursor: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl GetPagePropertyItemClient {
-    // TODO: docs for send
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
+    /// Retrieve the value of the property.
+    ///
+    /// `title`, `rich_text`, `relation`, `people` and `rollup` values are
+    /// paginated by Notion, 25 items at a time. Every page is fetched and
+    /// assembled into one property, so e.g. a relation holds all of its links.
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::page::PageProperty, crate::error::Error> {
+        let mut list = match self.clone().send_page().await? {
+            PropertyItemResponse::PropertyItem(property) => return Ok(property),
+            PropertyItemResponse::List(list) => list,
+        };
+
+        while list.has_more {
+            let Some(start_cursor) = list.next_cursor.clone() else {
+                break;
+            };
+
+            let next = Self {
+                start_cursor: Some(start_cursor),
+                ..self.clone()
+            };
+
+            match next.send_page().await? {
+                PropertyItemResponse::List(next) => list.extend(next),
+                PropertyItemResponse::PropertyItem(_) => {
+                    return Err(crate::error::Error::BodyParse(
+                        "Expected the next page of the property item list.".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let r#type = list.property_item.r#type.clone();
+
+        list.into_property().ok_or_else(|| {
+            crate::error::Error::BodyParse(format!(
+                "Unexpected property item list of type `{type}`."
+            ))
+        })
+    }
+
+    async fn send_page(self) -> Result<PropertyItemResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let property_item = serde_json::from_slice::<PropertyItemResponse>(&body)?;
+
+        Ok(property_item)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let page_id = self.page_id.ok_or(crate::error::Error::RequestParameter(
             "`page_id` is not set.".to_string(),
         ))?;
@@ -24,28 +103,116 @@ impl GetPagePropertyItemClient {
             ))?;
 
         let url = format!(
-            "https://api.notion.com/v1/pages/{}/properties/{}",
-            page_id, property_id
+            "{}/v1/pages/{}/properties/{}",
+            self.config.base_url, page_id, property_id
         );
 
-        let request = self.reqwest_client.get(url);
+        let mut query_params: Vec<(String, String)> = Vec::new();
 
-        let response = request
+        if let Some(start_cursor) = self.start_cursor {
+            query_params.push(("start_cursor".to_string(), start_cursor));
+        }
+
+        let request = self.reqwest_client.get(url).query(&query_params);
+
+        Ok((self.config, request))
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use notionrs_types::object::page::PageProperty;
+
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    fn client(server: &TestServer) -> crate::Client {
+        crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap()
+    }
+
+    fn relation_page(range: std::ops::Range<usize>, next_cursor: Option<&str>) -> String {
+        serde_json::json!({
+            "object": "list",
+            "results": range
+                .map(|i| serde_json::json!({
+                    "object": "property_item",
+                    "id": "vYdV",
+                    "type": "relation",
+                    "relation": { "id": format!("page-{i}") }
+                }))
+                .collect::<Vec<_>>(),
+            "next_cursor": next_cursor,
+            "has_more": next_cursor.is_some(),
+            "type": "property_item",
+            "property_item": { "id": "vYdV", "next_url": null, "type": "relation", "relation": {} }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn follows_next_cursor() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &relation_page(0..25, Some("cursor-1"))),
+            TestResponse::json(200, &relation_page(25..50, Some("cursor-2"))),
+            TestResponse::json(200, &relation_page(50..60, None)),
+        ])
+        .await;
+
+        let property = client(&server)
+            .get_page_property_item()
+            .page_id("page-id")
+            .property_id("vYdV")
             .send()
             .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+            .unwrap();
 
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
+        match property {
+            PageProperty::Relation(relation) => {
+                assert_eq!(relation.relation.len(), 60);
+                assert_eq!(relation.relation[59].id, "page-59");
+                assert!(!relation.has_more);
+            }
+            other => panic!("Expected a relation, got {other:?}"),
         }
 
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+        let requests = server.requests().await;
+        assert_eq!(requests[0].path, "/v1/pages/page-id/properties/vYdV");
+        assert_eq!(
+            requests[1].path,
+            "/v1/pages/page-id/properties/vYdV?start_cursor=cursor-1"
+        );
+        assert_eq!(
+            requests[2].path,
+            "/v1/pages/page-id/properties/vYdV?start_cursor=cursor-2"
+        );
+    }
+
+    #[tokio::test]
+    async fn single_value() {
+        let server = TestServer::start(vec![TestResponse::json(
+            200,
+            r#"{"object":"property_item","id":"kjPO","type":"checkbox","checkbox":true}"#,
+        )])
+        .await;
 
-        let user = serde_json::from_slice::<notionrs_types::object::page::PageProperty>(&body)?;
+        let property = client(&server)
+            .get_page_property_item()
+            .page_id("page-id")
+            .property_id("kjPO")
+            .send()
+            .await
+            .unwrap();
 
-        Ok(user)
+        assert!(matches!(property, PageProperty::Checkbox(checkbox) if checkbox.checkbox));
+        assert_eq!(server.requests().await.len(), 1);
     }
 }
@@ -1,10 +1,12 @@
 pub mod create_page;
+pub mod duplicate_page;
 pub mod get_page;
 pub mod get_page_markdown;
 pub mod get_page_property_item;
 pub mod move_page;
 pub mod update_page;
 pub mod update_page_markdown;
+pub mod upsert_page;
 
 pub(super) fn with_filter_properties(
     request: reqwest::RequestBuilder,
@@ -27,8 +29,67 @@ pub(super) fn with_filter_properties(
     request.query(&query)
 }
 
+/// Whether a property of a page object is cut off at Notion's limit of 25 references.
+///
+/// Relations say so with `has_more`; people properties are assumed to be cut
+/// off when they reach the limit.
+fn is_truncated(property: &serde_json::Value) -> bool {
+    match property["type"].as_str() {
+        Some("relation") => property["has_more"].as_bool().unwrap_or(false),
+        Some("people") => property["people"]
+            .as_array()
+            .is_some_and(|people| people.len() >= 25),
+        _ => false,
+    }
+}
+
+/// Replace the truncated relation and people properties in the JSON of `page`
+/// with their complete values from the property item endpoint.
+pub(super) async fn hydrate_truncated_properties(
+    reqwest_client: &reqwest::Client,
+    config: &std::sync::Arc<crate::client::ClientConfig>,
+    page: &mut serde_json::Value,
+) -> Result<(), crate::error::Error> {
+    let Some(page_id) = page["id"].as_str().map(str::to_string) else {
+        return Ok(());
+    };
+
+    let Some(properties) = page["properties"].as_object_mut() else {
+        return Ok(());
+    };
+
+    let requests = properties
+        .iter()
+        .filter(|(_, property)| is_truncated(property))
+        .filter_map(|(name, property)| {
+            let property_id = property["id"].as_str()?.to_string();
+            let client = get_page_property_item::GetPagePropertyItemClient {
+                reqwest_client: reqwest_client.clone(),
+                config: config.clone(),
+                page_id: Some(page_id.clone()),
+                property_id: Some(property_id),
+                ..Default::default()
+            };
+            let name = name.clone();
+            Some(async move { Ok::<_, crate::error::Error>((name, client.send().await?)) })
+        })
+        .collect::<Vec<_>>();
+
+    for (name, property) in futures::future::try_join_all(requests).await? {
+        properties.insert(name, serde_json::to_value(property)?);
+    }
+
+    Ok(())
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
 #[cfg(test)]
-mod tests {
+mod unit_tests {
     use super::*;
 
     #[test]
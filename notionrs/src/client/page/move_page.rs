@@ -7,12 +7,16 @@ use serde::{Deserialize, Serialize, de::DeserializeOwned};
 /// This API was added on 2025-12-16.
 /// [@notionhq/client Release Notes](https://github.com/makenotion/notion-sdk-js/releases/tag/v5.6.0)
 #[derive(Debug, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct MovePageClient<
     T = std::collections::HashMap<String, notionrs_types::object::page::PageProperty>,
 > {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the page to move
     pub(crate) source_page_id: Option<String>,
 
@@ -30,6 +34,7 @@ impl<T> Default for MovePageClient<T> {
     fn default() -> Self {
         Self {
             reqwest_client: reqwest::Client::default(),
+            config: Default::default(),
             source_page_id: None,
             destination_page_id: None,
             destination_data_source_id: None,
@@ -53,6 +58,7 @@ pub enum MovePageDestination {
     DataSourceId { data_source_id: String },
 }
 
+#[notionrs_macro::blocking]
 impl<T> MovePageClient<T> {
     /// Change the page-property type used to deserialize the response.
     /// Call this when you want to map properties into a custom struct instead
@@ -60,6 +66,7 @@ impl<T> MovePageClient<T> {
     pub fn typed<U>(self) -> MovePageClient<U> {
         MovePageClient {
             reqwest_client: self.reqwest_client,
+            config: self.config,
             source_page_id: self.source_page_id,
             destination_page_id: self.destination_page_id,
             destination_data_source_id: self.destination_data_source_id,
@@ -67,12 +74,42 @@ impl<T> MovePageClient<T> {
         }
     }
 
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::page::PageResponse<T>, crate::error::Error>
     where
         T: DeserializeOwned + Clone + Send + Sync + 'static,
     {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let page = serde_json::from_slice::<notionrs_types::object::page::PageResponse<T>>(&body)?;
+
+        Ok(page)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let source_page_id = self
             .source_page_id
             .ok_or(crate::error::Error::RequestParameter(
@@ -105,7 +142,8 @@ impl<T> MovePageClient<T> {
             }?;
 
         let request_url = format!(
-            "https://api.notion.com/v1/pages/{page_id}/move",
+            "{}/v1/pages/{page_id}/move",
+            self.config.base_url,
             page_id = source_page_id
         );
 
@@ -119,24 +157,7 @@ impl<T> MovePageClient<T> {
             .header("Content-Type", "application/json")
             .body(request_body_string);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let page =
-            serde_json::from_slice::<notionrs_types::object::page::PageResponse<T>>(&body)?;
-
-        Ok(page)
+        Ok((self.config, request))
     }
 }
 
@@ -2,12 +2,16 @@ use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 /// @see <https://developers.notion.com/reference/patch-page>
 #[derive(Debug, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct UpdatePageClient<
     T = std::collections::HashMap<String, notionrs_types::object::page::PageProperty>,
 > {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) page_id: Option<String>,
 
     /// Property IDs or names to include in the returned page's `properties`.
@@ -32,6 +36,7 @@ where
     fn default() -> Self {
         Self {
             reqwest_client: reqwest::Client::default(),
+            config: Default::default(),
             page_id: None,
             filter_properties: None,
             properties: Default::default(),
@@ -77,6 +82,23 @@ pub struct UpdatePageTemplate {
     pub(crate) timezone: Option<String>,
 }
 
+#[notionrs_macro::blocking]
+impl UpdatePageClient {
+    /// Send only the properties that a [`PropertyDiff`](notionrs_types::object::page::diff::PropertyDiff)
+    /// added or changed, so that unchanged values aren't written again.
+    ///
+    /// Check [`PropertyDiff::is_empty`](notionrs_types::object::page::diff::PropertyDiff::is_empty)
+    /// first to skip the request when there is nothing to update.
+    pub fn properties_from_diff(
+        mut self,
+        diff: &notionrs_types::object::page::diff::PropertyDiff,
+    ) -> Self {
+        self.properties = diff.update_properties();
+        self
+    }
+}
+
+#[notionrs_macro::blocking]
 impl<T> UpdatePageClient<T>
 where
     T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
@@ -127,9 +149,39 @@ where
         self
     }
 
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::page::PageResponse<T>, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let page = serde_json::from_slice::<notionrs_types::object::page::PageResponse<T>>(&body)?;
+
+        Ok(page)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let page_id = self.page_id.ok_or(crate::error::Error::RequestParameter(
             "`page_id` is not set.".to_string(),
         ))?;
@@ -144,7 +196,7 @@ where
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = format!("https://api.notion.com/v1/pages/{}", page_id);
+        let url = format!("{}/v1/pages/{}", self.config.base_url, page_id);
 
         let request = self
             .reqwest_client
@@ -153,22 +205,65 @@ where
             .body(request_body);
         let request = crate::client::page::with_filter_properties(request, self.filter_properties);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+        Ok((self.config, request))
+    }
+}
 
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
 
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::HashMap;
 
-        let page = serde_json::from_slice::<notionrs_types::object::page::PageResponse<T>>(&body)?;
+    use notionrs_types::object::page::{PageProperty, diff::PropertyDiff};
 
-        Ok(page)
+    #[test]
+    fn properties_from_diff_sends_changed_properties_only() {
+        let property =
+            |value: serde_json::Value| serde_json::from_value::<PageProperty>(value).unwrap();
+
+        let current = HashMap::from([
+            (
+                "Status".to_string(),
+                property(serde_json::json!({
+                    "id": "s%3Ax",
+                    "type": "select",
+                    "select": { "id": "opt-1", "name": "Todo", "color": "red" }
+                })),
+            ),
+            (
+                "Points".to_string(),
+                property(serde_json::json!({ "id": "p%3Ax", "type": "number", "number": 3 })),
+            ),
+        ]);
+
+        let desired = HashMap::from([
+            (
+                "Status".to_string(),
+                property(serde_json::json!({ "type": "select", "select": { "name": "Done" } })),
+            ),
+            (
+                "Points".to_string(),
+                property(serde_json::json!({ "type": "number", "number": 3 })),
+            ),
+        ]);
+
+        let request = crate::Client::new("secret")
+            .update_page()
+            .page_id("page-id")
+            .properties_from_diff(&PropertyDiff::between(&current, &desired))
+            .build_request()
+            .unwrap();
+
+        let body = request.json::<serde_json::Value>().unwrap();
+
+        assert_eq!(
+            body["properties"],
+            serde_json::json!({ "Status": { "type": "select", "select": { "name": "Done" } } })
+        );
     }
 }
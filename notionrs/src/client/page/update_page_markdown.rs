@@ -8,10 +8,14 @@ use serde::{Deserialize, Serialize};
 /// - `update_content`: Update specific content using search-and-replace operations (added in v5.13.0)
 /// - `replace_content`: Replace the entire page content with new markdown (added in v5.13.0)
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct UpdatePageMarkdownClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the page to update.
     pub(crate) page_id: Option<String>,
 
@@ -42,7 +46,8 @@ pub struct UpdatePageMarkdownRequestBody {
 /// Normally this is the updated page's markdown (`send()` receives an HTTP
 /// `200`). When `allow_async(true)` is set and Notion accepts the request for
 /// asynchronous processing instead, an HTTP `202` is returned with an async
-/// task reference. Poll it with `Client::get_async_task`.
+/// task reference. Poll it with `Client::get_async_task`, or use
+/// `into_markdown_eventually`.
 #[derive(Debug, Clone)]
 pub enum UpdatePageMarkdownResponse {
     /// The page's markdown was updated synchronously.
@@ -70,6 +75,42 @@ impl UpdatePageMarkdownResponse {
     }
 }
 
+impl UpdatePageMarkdownResponse {
+    /// Returns the updated page's markdown, waiting for the async task to
+    /// finish if the request was accepted for asynchronous processing.
+    ///
+    /// The markdown is taken from the task result, or retrieved by the page ID
+    /// when the result only references the page.
+    pub async fn into_markdown_eventually(
+        self,
+        client: &crate::Client,
+    ) -> Result<notionrs_types::object::page_markdown::PageMarkdownResponse, crate::error::Error>
+    {
+        let task = match self {
+            UpdatePageMarkdownResponse::Markdown(markdown) => return Ok(markdown),
+            UpdatePageMarkdownResponse::AsyncTask(task) => task,
+        };
+
+        let succeeded = client.wait_for_async_task(task.id()).send().await?;
+
+        if let Ok(markdown) =
+            succeeded.result_as::<notionrs_types::object::page_markdown::PageMarkdownResponse>()
+        {
+            return Ok(markdown);
+        }
+
+        let page_id = succeeded
+            .result
+            .get("id")
+            .and_then(|id| id.as_str())
+            .ok_or_else(|| crate::error::Error::UnexpectedAsyncTask {
+                task_id: succeeded.id.clone(),
+            })?;
+
+        client.get_page_markdown().page_id(page_id).send().await
+    }
+}
+
 fn parse_update_page_markdown_response(
     status: reqwest::StatusCode,
     body: &[u8],
@@ -207,6 +248,7 @@ pub struct ReplaceContentPayload {
     pub allow_deleting_content: Option<bool>,
 }
 
+#[notionrs_macro::blocking]
 impl UpdatePageMarkdownClient {
     /// Set the body to an `insert_content` operation.
     ///
@@ -348,6 +390,14 @@ impl UpdatePageMarkdownClient {
         self
     }
 
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     /// Send the request.
     ///
     /// Returns `UpdatePageMarkdownResponse::AsyncTask` instead of
@@ -356,6 +406,28 @@ impl UpdatePageMarkdownClient {
     /// `202`). Call `.into_markdown()` on the result if you only want to
     /// handle the synchronous case.
     pub async fn send(self) -> Result<UpdatePageMarkdownResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let status = response.status();
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        parse_update_page_markdown_response(status, &body)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let page_id = self.page_id.ok_or(crate::error::Error::RequestParameter(
             "`page_id` is not set.".to_string(),
         ))?;
@@ -369,7 +441,7 @@ impl UpdatePageMarkdownClient {
             operation,
         })?;
 
-        let url = format!("https://api.notion.com/v1/pages/{}/markdown", page_id);
+        let url = format!("{}/v1/pages/{}/markdown", self.config.base_url, page_id);
 
         let request = self
             .reqwest_client
@@ -377,23 +449,7 @@ impl UpdatePageMarkdownClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let status = response.status();
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        parse_update_page_markdown_response(status, &body)
+        Ok((self.config, request))
     }
 }
 
@@ -821,6 +877,41 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn into_markdown_eventually_takes_the_markdown_from_the_task() {
+        use crate::util::test_server::{TestResponse, TestServer};
+
+        let task = |status: &str, extra: &str| {
+            format!(
+                r#"{{"object":"async_task","id":"task-id-123","status":"{status}","status_url":"https://api.notion.com/v1/async_tasks/task-id-123","created_time":"2026-01-01T00:00:00.000Z","operation":{{"surface":"rest","name":"update_page_markdown"}}{extra}}}"#
+            )
+        };
+
+        let server = TestServer::start(vec![TestResponse::json(
+            200,
+            &task(
+                "succeeded",
+                r##","result":{"object":"page_markdown","id":"page-id-456","markdown":"# Hello","truncated":false,"unknown_block_ids":[]}"##,
+            ),
+        )])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let response = UpdatePageMarkdownResponse::AsyncTask(
+            serde_json::from_str(&task("running", "")).unwrap(),
+        );
+
+        let markdown = response.into_markdown_eventually(&client).await.unwrap();
+        assert_eq!(markdown.id, "page-id-456");
+        assert_eq!(markdown.markdown, "# Hello");
+        assert_eq!(server.requests().await.len(), 1);
+    }
+
     #[test]
     fn into_markdown_returns_error_for_async_task() {
         let task = notionrs_types::object::async_task::AsyncTaskResponse::Running(
@@ -0,0 +1,481 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use notionrs_types::object::page::{PageProperty, PageResponse};
+use notionrs_types::object::request::filter::Filter;
+
+/// Update the page of a data source that has the given value in a key
+/// property, or create it when there is none.
+///
+/// The key property can be a `title`, `rich_text`, `number`, `unique_id` or
+/// `url` property, and its value is taken from `properties`. An existing page
+/// is only updated when its properties differ, and then only with the changed
+/// ones. When more than one page has the key value, nothing is written and
+/// `Error::UpsertMultipleMatches` is returned.
+///
+/// Upserts of the same key value through one client (and its clones) run one
+/// at a time, so concurrent tasks don't create the page twice. Notion's query
+/// index lags behind writes, so the client also remembers the pages it
+/// created for a few minutes and reads them directly when the query doesn't
+/// find them yet. Notion has no way to make the lookup and the creation
+/// atomic, so writers in other processes can still race.
+#[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
+pub struct UpsertPageClient {
+    /// The reqwest http client
+    pub(crate) reqwest_client: reqwest::Client,
+
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
+    /// The ID of the data source to look up and create the page in.
+    pub(crate) data_source_id: Option<String>,
+
+    /// The name of the property that identifies the page.
+    pub(crate) key: Option<String>,
+
+    /// The properties of the page, including the key property.
+    pub(crate) properties: HashMap<String, PageProperty>,
+}
+
+/// What [`UpsertPageClient::send`] did.
+#[derive(Debug, Clone)]
+pub enum UpsertPageResponse {
+    /// No page had the key value, so one was created.
+    Created(PageResponse),
+
+    /// The page with the key value was updated.
+    Updated(PageResponse),
+
+    /// The page with the key value already had the properties, so no request
+    /// was sent to update it.
+    Unchanged(PageResponse),
+}
+
+impl UpsertPageResponse {
+    /// The created, updated or unchanged page.
+    pub fn into_page(self) -> PageResponse {
+        match self {
+            Self::Created(page) | Self::Updated(page) | Self::Unchanged(page) => page,
+        }
+    }
+}
+
+#[notionrs_macro::blocking]
+impl UpsertPageClient {
+    pub async fn send(self) -> Result<UpsertPageResponse, crate::error::Error> {
+        let data_source_id = self
+            .data_source_id
+            .ok_or(crate::error::Error::RequestParameter(
+                "`data_source_id` is not set.".to_string(),
+            ))?;
+
+        let key = self.key.ok_or(crate::error::Error::RequestParameter(
+            "`key` is not set.".to_string(),
+        ))?;
+
+        let filter = key_filter(&key, self.properties.get(&key))?;
+
+        let client = crate::Client {
+            reqwest_client: self.reqwest_client,
+            config: self.config,
+        };
+
+        let lock = client.config.upsert_locks.get(format!(
+            "{data_source_id}/{}",
+            serde_json::to_string(&filter)?
+        ));
+        let mut created = lock.lock().await;
+
+        let mut matches = client
+            .query_data_source()
+            .data_source_id(&data_source_id)
+            .filter(filter)
+            .send()
+            .await?
+            .results;
+
+        // A page created moments ago may not be in the query results yet.
+        if let Some(recent) = created.as_ref().filter(|_| matches.is_empty()) {
+            match client.get_page().page_id(&recent.id).send().await {
+                Ok(page) if !page.in_trash => matches.push(page),
+                Ok(_) => {}
+                Err(error) if error.is_not_found() => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        if matches.len() > 1 {
+            return Err(crate::error::Error::UpsertMultipleMatches {
+                key,
+                page_ids: matches.into_iter().map(|page| page.id).collect(),
+            });
+        }
+
+        match matches.pop() {
+            None => {
+                let properties = self
+                    .properties
+                    .into_iter()
+                    .filter(|(_, property)| !property.is_read_only())
+                    .collect::<HashMap<_, _>>();
+
+                let page = client
+                    .create_page()
+                    .data_source_id(&data_source_id)
+                    .properties(properties)
+                    .send()
+                    .await?
+                    .into_page()?;
+
+                *created = Some(CreatedPage {
+                    id: page.id.clone(),
+                    expires_at: Instant::now() + CREATED_PAGE_TTL,
+                });
+
+                Ok(UpsertPageResponse::Created(page))
+            }
+            Some(page) => {
+                let diff = page.diff_properties(&self.properties);
+
+                if diff.is_empty() {
+                    return Ok(UpsertPageResponse::Unchanged(page));
+                }
+
+                let page = client
+                    .update_page()
+                    .page_id(&page.id)
+                    .properties_from_diff(&diff)
+                    .send()
+                    .await?;
+
+                Ok(UpsertPageResponse::Updated(page))
+            }
+        }
+    }
+}
+
+/// The filter that finds the pages with the value of the key property.
+fn key_filter(key: &str, value: Option<&PageProperty>) -> Result<Filter, crate::error::Error> {
+    let missing = || {
+        crate::error::Error::RequestParameter(format!(
+            "`properties` has no value for the key property `{key}`."
+        ))
+    };
+
+    // Notion applies `rich_text` filters to `title` and `url` properties too.
+    let filter = match value.ok_or_else(missing)? {
+        PageProperty::Title(title) => Filter::rich_text_equals(key, title.to_string()),
+        PageProperty::RichText(rich_text) => Filter::rich_text_equals(key, rich_text.to_string()),
+        PageProperty::Number(number) => {
+            Filter::number_equals(key, number.number.ok_or_else(missing)?)
+        }
+        PageProperty::UniqueId(unique_id) => {
+            Filter::unique_id_equals(key, unique_id.unique_id.number.ok_or_else(missing)?)
+        }
+        PageProperty::Url(url) => {
+            Filter::rich_text_equals(key, url.url.as_ref().ok_or_else(missing)?)
+        }
+        _ => {
+            return Err(crate::error::Error::RequestParameter(format!(
+                "The key property `{key}` must be a title, rich_text, number, unique_id or url property."
+            )));
+        }
+    };
+
+    Ok(filter)
+}
+
+/// How long a created page is looked up by ID when the query doesn't find it.
+const CREATED_PAGE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// A page created by an upsert, until the query index has caught up with it.
+#[derive(Debug, Clone)]
+struct CreatedPage {
+    id: String,
+    expires_at: Instant,
+}
+
+type UpsertLock = Arc<futures::lock::Mutex<Option<CreatedPage>>>;
+
+/// One lock per upserted key value, shared by a client and its clones. The
+/// lock holds the page the last upsert of the key created, if any.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct UpsertLocks(Arc<std::sync::Mutex<HashMap<String, UpsertLock>>>);
+
+impl UpsertLocks {
+    fn get(&self, key: String) -> UpsertLock {
+        let mut locks = self.0.lock().unwrap();
+
+        // Keep the entries of running upserts and of recently created pages.
+        locks.retain(|_, lock| {
+            Arc::strong_count(lock) > 1
+                || lock.try_lock().is_some_and(|created| {
+                    created
+                        .as_ref()
+                        .is_some_and(|created| Instant::now() < created.expires_at)
+                })
+        });
+
+        locks.entry(key).or_default().clone()
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::util::test_server::{TestResponse, TestServer, list_json, page_json};
+
+    fn page(id: &str, status: &str) -> serde_json::Value {
+        page_json(
+            id,
+            serde_json::json!({ "type": "data_source_id", "data_source_id": "data-source-id" }),
+            serde_json::json!({
+                "SKU": {
+                    "id": "a%3Ab",
+                    "type": "rich_text",
+                    "rich_text": [{
+                        "type": "text",
+                        "text": { "content": "A-1", "link": null },
+                        "annotations": {
+                            "bold": false, "italic": false, "strikethrough": false,
+                            "underline": false, "code": false, "color": "default"
+                        },
+                        "plain_text": "A-1",
+                        "href": null
+                    }]
+                },
+                "Status": {
+                    "id": "c%3Ad",
+                    "type": "select",
+                    "select": { "id": "opt", "name": status, "color": "blue" }
+                }
+            }),
+        )
+    }
+
+    fn properties(status: &str) -> HashMap<String, PageProperty> {
+        serde_json::from_value(serde_json::json!({
+            "SKU": { "type": "rich_text", "rich_text": [{ "type": "text", "text": { "content": "A-1", "link": null }, "annotations": { "bold": false, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default" }, "plain_text": "A-1", "href": null }] },
+            "Status": { "type": "select", "select": { "name": status } }
+        }))
+        .unwrap()
+    }
+
+    async fn upsert(
+        responses: Vec<TestResponse>,
+        status: &str,
+    ) -> (
+        Result<UpsertPageResponse, crate::Error>,
+        Vec<crate::util::test_server::RecordedRequest>,
+    ) {
+        let server = TestServer::start(responses).await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let response = client
+            .upsert_page()
+            .data_source_id("data-source-id")
+            .key("SKU")
+            .properties(properties(status))
+            .send()
+            .await;
+
+        (response, server.requests().await)
+    }
+
+    #[tokio::test]
+    async fn creates_missing_page() {
+        let (response, requests) = upsert(
+            vec![
+                TestResponse::json(200, &list_json(vec![]).to_string()),
+                TestResponse::json(200, &page("new-id", "Todo").to_string()),
+            ],
+            "Todo",
+        )
+        .await;
+
+        assert!(
+            matches!(response.unwrap(), UpsertPageResponse::Created(page) if page.id == "new-id")
+        );
+
+        assert_eq!(requests[0].path, "/v1/data_sources/data-source-id/query");
+        assert_eq!(
+            requests[0].json()["filter"],
+            serde_json::json!({ "property": "SKU", "rich_text": { "equals": "A-1" } })
+        );
+        assert_eq!(requests[1].path, "/v1/pages");
+        assert_eq!(
+            requests[1].json()["properties"]["Status"]["select"]["name"],
+            "Todo"
+        );
+    }
+
+    #[tokio::test]
+    async fn updates_changed_properties_only() {
+        let (response, requests) = upsert(
+            vec![
+                TestResponse::json(200, &list_json(vec![page("page-id", "Todo")]).to_string()),
+                TestResponse::json(200, &page("page-id", "Done").to_string()),
+            ],
+            "Done",
+        )
+        .await;
+
+        assert!(matches!(response.unwrap(), UpsertPageResponse::Updated(_)));
+
+        assert_eq!(requests[1].method, "PATCH");
+        assert_eq!(requests[1].path, "/v1/pages/page-id");
+        assert_eq!(
+            requests[1].json()["properties"],
+            serde_json::json!({ "Status": { "type": "select", "select": { "name": "Done" } } })
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_unchanged_page() {
+        let (response, requests) = upsert(
+            vec![TestResponse::json(
+                200,
+                &list_json(vec![page("page-id", "Todo")]).to_string(),
+            )],
+            "Todo",
+        )
+        .await;
+
+        assert!(matches!(
+            response.unwrap(),
+            UpsertPageResponse::Unchanged(_)
+        ));
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn multiple_matches_are_an_error() {
+        let (response, requests) = upsert(
+            vec![TestResponse::json(
+                200,
+                &list_json(vec![page("page-1", "Todo"), page("page-2", "Todo")]).to_string(),
+            )],
+            "Done",
+        )
+        .await;
+
+        match response.unwrap_err() {
+            crate::Error::UpsertMultipleMatches { key, page_ids } => {
+                assert_eq!(key, "SKU");
+                assert_eq!(page_ids, vec!["page-1", "page-2"]);
+            }
+            other => panic!("Expected UpsertMultipleMatches, got {other:?}"),
+        }
+
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[test]
+    fn unsupported_key_property() {
+        let properties = properties("Todo");
+
+        assert!(matches!(
+            key_filter("Status", properties.get("Status")),
+            Err(crate::Error::RequestParameter(_))
+        ));
+        assert!(matches!(
+            key_filter("Missing", properties.get("Missing")),
+            Err(crate::Error::RequestParameter(_))
+        ));
+    }
+
+    #[test]
+    fn same_key_shares_a_lock() {
+        let locks = UpsertLocks::default();
+
+        let first = locks.get("data-source-id/A-1".to_string());
+        let second = locks.get("data-source-id/A-1".to_string());
+        let other = locks.get("data-source-id/A-2".to_string());
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+    }
+
+    #[test]
+    fn finished_upserts_keep_only_recently_created_pages() {
+        let locks = UpsertLocks::default();
+
+        *locks.get("created".to_string()).try_lock().unwrap() = Some(CreatedPage {
+            id: "page-id".to_string(),
+            expires_at: Instant::now() + CREATED_PAGE_TTL,
+        });
+        *locks.get("expired".to_string()).try_lock().unwrap() = Some(CreatedPage {
+            id: "page-id".to_string(),
+            expires_at: Instant::now(),
+        });
+        drop(locks.get("updated".to_string()));
+
+        let lock = locks.get("other".to_string());
+
+        let mut keys = locks.0.lock().unwrap().keys().cloned().collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, vec!["created", "other"]);
+        assert!(lock.try_lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn finds_created_page_before_the_query_index_does() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &list_json(vec![]).to_string()),
+            TestResponse::json(200, &page("new-id", "Todo").to_string()),
+            // The query doesn't list the new page yet.
+            TestResponse::json(200, &list_json(vec![]).to_string()),
+            TestResponse::json(200, &page("new-id", "Todo").to_string()),
+            TestResponse::json(200, &page("new-id", "Done").to_string()),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        for status in ["Todo", "Done"] {
+            client
+                .clone()
+                .upsert_page()
+                .data_source_id("data-source-id")
+                .key("SKU")
+                .properties(properties(status))
+                .send()
+                .await
+                .unwrap();
+        }
+
+        let paths = server
+            .requests()
+            .await
+            .iter()
+            .map(|request| format!("{} {}", request.method, request.path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "POST /v1/data_sources/data-source-id/query",
+                "POST /v1/pages",
+                "POST /v1/data_sources/data-source-id/query",
+                "GET /v1/pages/new-id",
+                "PATCH /v1/pages/new-id",
+            ]
+        );
+    }
+}
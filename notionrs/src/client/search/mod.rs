@@ -13,6 +13,8 @@ pub struct SearchClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) body: SearchRequestBody,
 }
 
@@ -34,29 +36,22 @@ pub struct SearchRequestBody {
     pub(crate) page_size: Option<u32>,
 }
 
+#[notionrs_macro::blocking]
 impl SearchClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<ListResponse<notionrs_types::object::response::SearchResultItem>, crate::error::Error>
     {
-        let url = String::from("https://api.notion.com/v1/search");
-
-        let request_body = serde_json::to_string(&self.body)?;
-
-        let request = self
-            .reqwest_client
-            .post(url)
-            .header("Content-Type", "application/json")
-            .body(request_body);
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
         let body = response
             .bytes()
@@ -70,6 +65,28 @@ impl SearchClient {
         Ok(pages)
     }
 
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let url = format!("{}/v1/search", self.config.base_url);
+
+        let request_body = serde_json::to_string(&self.body)?;
+
+        let request = self
+            .reqwest_client
+            .post(url)
+            .header("Content-Type", "application/json")
+            .body(request_body);
+
+        Ok((self.config, request))
+    }
+
     pub fn query<T: AsRef<str>>(mut self, query: T) -> Self {
         self.body.query = Some(query.as_ref().to_string());
         self
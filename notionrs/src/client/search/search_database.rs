@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct SearchDatabaseClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) query: Option<String>,
 
     pub(crate) sort: Option<notionrs_types::object::request::search::SearchSort>,
@@ -45,7 +49,16 @@ pub struct SearchDatabaseRequestBody {
     pub(crate) page_size: Option<u32>,
 }
 
+#[notionrs_macro::blocking]
 impl SearchDatabaseClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<
@@ -54,7 +67,33 @@ impl SearchDatabaseClient {
         >,
         crate::error::Error,
     > {
-        let url = String::from("https://api.notion.com/v1/search");
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let pages = serde_json::from_slice::<
+            notionrs_types::object::response::ListResponse<
+                notionrs_types::object::data_source::DataSourceResponse,
+            >,
+        >(&body)?;
+
+        Ok(pages)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let url = format!("{}/v1/search", self.config.base_url);
 
         let request_body = serde_json::to_string(&SearchDatabaseRequestBody {
             query: self.query,
@@ -76,27 +115,7 @@ impl SearchDatabaseClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let pages = serde_json::from_slice::<
-            notionrs_types::object::response::ListResponse<
-                notionrs_types::object::data_source::DataSourceResponse,
-            >,
-        >(&body)?;
-
-        Ok(pages)
+        Ok((self.config, request))
     }
 
     /// Sort by the last edited time of the page in ascending order.
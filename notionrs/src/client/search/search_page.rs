@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct SearchPageClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     pub(crate) query: Option<String>,
 
     pub(crate) sort: Option<notionrs_types::object::request::search::SearchSort>,
@@ -42,14 +46,49 @@ pub struct SearchPageRequestBody {
     pub(crate) page_size: Option<u32>,
 }
 
+#[notionrs_macro::blocking]
 impl SearchPageClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<
         notionrs_types::object::response::ListResponse<notionrs_types::object::page::PageResponse>,
         crate::error::Error,
     > {
-        let url = String::from("https://api.notion.com/v1/search");
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let pages = serde_json::from_slice::<
+            notionrs_types::object::response::ListResponse<
+                notionrs_types::object::page::PageResponse,
+            >,
+        >(&body)?;
+
+        Ok(pages)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let url = format!("{}/v1/search", self.config.base_url);
 
         let request_body = serde_json::to_string(&SearchPageRequestBody {
             query: self.query,
@@ -71,27 +110,7 @@ impl SearchPageClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let pages = serde_json::from_slice::<
-            notionrs_types::object::response::ListResponse<
-                notionrs_types::object::page::PageResponse,
-            >,
-        >(&body)?;
-
-        Ok(pages)
+        Ok((self.config, request))
     }
 
     /// Sort by the last edited time of the page in ascending order.
@@ -2,23 +2,24 @@
 pub struct GetSelfClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
+
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
 }
 
+#[notionrs_macro::blocking]
 impl GetSelfClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     /// Send a request to the API endpoint of Notion.
     pub async fn send(self) -> Result<notionrs_types::object::user::User, crate::error::Error> {
-        let url = String::from("https://api.notion.com/v1/users/me");
-
-        let request = self.reqwest_client.get(url);
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
         let body = response
             .bytes()
@@ -29,4 +30,20 @@ impl GetSelfClient {
 
         Ok(user)
     }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let url = format!("{}/v1/users/me", self.config.base_url);
+
+        let request = self.reqwest_client.get(url);
+
+        Ok((self.config, request))
+    }
 }
@@ -1,40 +1,59 @@
 use notionrs_types::object::user::User;
 
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct GetUserClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the user to retrieve.
     pub(crate) user_id: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl GetUserClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     /// Send a request to the API endpoint of Notion.
     pub async fn send(self) -> Result<User, crate::error::Error> {
-        match self.user_id {
-            Some(id) => {
-                let url = format!("https://api.notion.com/v1/users/{}", id);
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
-                let request = self.reqwest_client.get(url);
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
 
-                let response = request
-                    .send()
-                    .await
-                    .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+        let user = serde_json::from_slice::<User>(&body)?;
 
-                if !response.status().is_success() {
-                    return Err(crate::error::Error::try_from_response_async(response).await);
-                }
+        Ok(user)
+    }
 
-                let body = response
-                    .bytes()
-                    .await
-                    .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        match self.user_id {
+            Some(id) => {
+                let url = format!("{}/v1/users/{}", self.config.base_url, id);
 
-                let user = serde_json::from_slice::<User>(&body)?;
+                let request = self.reqwest_client.get(url);
 
-                Ok(user)
+                Ok((self.config, request))
             }
             None => Err(crate::error::Error::RequestParameter(
                 "`user_id` is not set.".to_string(),
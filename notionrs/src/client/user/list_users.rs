@@ -5,10 +5,14 @@ use notionrs_types::prelude::*;
 /// A request builder for performing `list_users` operations.
 
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct ListUsersClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The starting cursor position for pagination
     pub(crate) start_cursor: Option<String>,
 
@@ -32,10 +36,35 @@ struct ListUserQueryParams {
 
 crate::impl_paginate!(ListUsersClient, User);
 
+#[notionrs_macro::blocking]
 impl ListUsersClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     /// Send a request to the API endpoint of Notion.
     pub async fn send(self) -> Result<ListResponse<User>, crate::error::Error> {
-        let url = "https://api.notion.com/v1/users";
+        let (config, request) = self.into_request()?;
+
+        let response: ListResponse<User> = crate::util::send_and_convert(&config, request).await?;
+
+        Ok(response)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let url = format!("{}/v1/users", self.config.base_url);
 
         let params = ListUserQueryParams {
             start_cursor: self.start_cursor.clone(),
@@ -44,8 +73,6 @@ impl ListUsersClient {
 
         let request = self.reqwest_client.get(url).query(&params);
 
-        let response: ListResponse<User> = crate::util::send_and_convert(request).await?;
-
-        Ok(response)
+        Ok((self.config, request))
     }
 }
@@ -2,10 +2,14 @@ use serde::{Deserialize, Serialize};
 
 /// <https://developers.notion.com/reference/create-a-view>
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct CreateViewClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the data source this view should be scoped to.
     pub(crate) data_source_id: Option<String>,
 
@@ -50,10 +54,41 @@ struct CreateViewRequestBody {
     configuration: Option<serde_json::Value>,
 }
 
+#[notionrs_macro::blocking]
 impl CreateViewClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::view::ViewResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let view = serde_json::from_slice::<notionrs_types::object::view::ViewResponse>(&body)?;
+
+        Ok(view)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let data_source_id = self
             .data_source_id
             .ok_or(crate::error::Error::RequestParameter(
@@ -64,13 +99,11 @@ impl CreateViewClient {
             "`name` is not set.".to_string(),
         ))?;
 
-        let view_type = self
-            .view_type
-            .ok_or(crate::error::Error::RequestParameter(
-                "`view_type` is not set.".to_string(),
-            ))?;
+        let view_type = self.view_type.ok_or(crate::error::Error::RequestParameter(
+            "`view_type` is not set.".to_string(),
+        ))?;
 
-        let url = "https://api.notion.com/v1/views";
+        let url = format!("{}/v1/views", self.config.base_url);
 
         let req = CreateViewRequestBody {
             data_source_id,
@@ -91,22 +124,6 @@ impl CreateViewClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let view = serde_json::from_slice::<notionrs_types::object::view::ViewResponse>(&body)?;
-
-        Ok(view)
+        Ok((self.config, request))
     }
 }
@@ -2,15 +2,158 @@ use serde::{Deserialize, Serialize};
 
 /// <https://developers.notion.com/reference/create-a-view-query>
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct CreateViewQueryClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the view to query.
     pub(crate) view_id: Option<String>,
 
     /// The number of results to return per page. Maximum: 100.
     pub(crate) page_size: Option<u8>,
+
+    /// Cursor to continue the results from while paginating.
+    #[setter(skip)]
+    pub(crate) start_cursor: Option<String>,
+
+    /// The query created while paginating, shared by the clones of this builder.
+    #[setter(skip)]
+    pub(crate) active_query: std::sync::Arc<std::sync::Mutex<Option<ActiveViewQuery>>>,
+}
+
+/// A view query created while paginating, reused until it expires.
+#[derive(Debug, Clone)]
+pub(crate) struct ActiveViewQuery {
+    id: String,
+    expires_at: Option<time::OffsetDateTime>,
+}
+
+impl ActiveViewQuery {
+    fn new(query: &notionrs_types::object::view::ViewQueryResponse) -> Self {
+        Self {
+            id: query.id.clone(),
+            expires_at: time::OffsetDateTime::parse(
+                &query.expires_at,
+                &time::format_description::well_known::Rfc3339,
+            )
+            .ok(),
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= time::OffsetDateTime::now_utc())
+    }
+}
+
+/// Streams the results of a new view query. The first page comes from the
+/// create response; the following pages from `get_view_query_results`.
+///
+/// When the query has expired, it is created again and the results continue
+/// from the last cursor.
+impl crate::r#trait::Paginate<notionrs_types::object::view::ViewQueryPageReference>
+    for CreateViewQueryClient
+{
+    fn paginate_start_cursor(mut self, start_cursor: Option<String>) -> Self {
+        if start_cursor.is_some() {
+            self.start_cursor = start_cursor;
+        }
+        self
+    }
+
+    fn paginate_send(
+        self,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<
+                        notionrs_types::object::response::ListResponse<
+                            notionrs_types::object::view::ViewQueryPageReference,
+                        >,
+                        crate::error::Error,
+                    >,
+                > + Send
+                + Sync,
+        >,
+    > {
+        Box::pin(async move {
+            let active_query = self
+                .active_query
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone()
+                .filter(|query| !query.is_expired());
+
+            match (active_query, self.start_cursor.clone()) {
+                (Some(query), Some(start_cursor)) => {
+                    match self.query_results(&query.id, start_cursor).await {
+                        // The query expired before `expires_at` said so.
+                        Err(error) if error.is_not_found() => self.create_and_continue().await,
+                        result => result,
+                    }
+                }
+                _ => self.create_and_continue().await,
+            }
+        })
+    }
+}
+
+impl CreateViewQueryClient {
+    /// Creates the query and returns its results from `start_cursor` on.
+    async fn create_and_continue(
+        self,
+    ) -> Result<
+        notionrs_types::object::response::ListResponse<
+            notionrs_types::object::view::ViewQueryPageReference,
+        >,
+        crate::error::Error,
+    > {
+        let start_cursor = self.start_cursor.clone();
+        let active_query = self.active_query.clone();
+
+        let query = self.clone().send().await?;
+        let id = query.id.clone();
+        *active_query.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(ActiveViewQuery::new(&query));
+
+        match start_cursor {
+            None => Ok(notionrs_types::object::response::ListResponse {
+                object: "list".to_string(),
+                results: query.results,
+                next_cursor: query.next_cursor,
+                has_more: Some(query.has_more),
+                r#type: None,
+                request_status: query.request_status,
+            }),
+            Some(start_cursor) => self.query_results(&id, start_cursor).await,
+        }
+    }
+
+    async fn query_results(
+        &self,
+        query_id: &str,
+        start_cursor: String,
+    ) -> Result<
+        notionrs_types::object::response::ListResponse<
+            notionrs_types::object::view::ViewQueryPageReference,
+        >,
+        crate::error::Error,
+    > {
+        crate::client::view::get_view_query_results::GetViewQueryResultsClient {
+            reqwest_client: self.reqwest_client.clone(),
+            config: self.config.clone(),
+            view_id: self.view_id.clone(),
+            query_id: Some(query_id.to_string()),
+            start_cursor: Some(start_cursor),
+            page_size: self.page_size,
+        }
+        .send()
+        .await
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,15 +162,47 @@ struct CreateViewQueryRequestBody {
     page_size: Option<u8>,
 }
 
+#[notionrs_macro::blocking]
 impl CreateViewQueryClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::view::ViewQueryResponse, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let query =
+            serde_json::from_slice::<notionrs_types::object::view::ViewQueryResponse>(&body)?;
+
+        Ok(query)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
         let view_id = self.view_id.ok_or(crate::error::Error::RequestParameter(
             "`view_id` is not set.".to_string(),
         ))?;
 
-        let url = format!("https://api.notion.com/v1/views/{}/queries", view_id);
+        let url = format!("{}/v1/views/{}/queries", self.config.base_url, view_id);
 
         let req = CreateViewQueryRequestBody {
             page_size: self.page_size,
@@ -41,23 +216,151 @@ impl CreateViewQueryClient {
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+        Ok((self.config, request))
+    }
+}
 
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
 
-        let body = response
-            .bytes()
+#[cfg(test)]
+mod unit_tests {
+    use futures::TryStreamExt;
+
+    use crate::PaginateExt;
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    fn created_query(id: &str, expires_at: &str, result: &str, next_cursor: &str) -> String {
+        serde_json::json!({
+            "object": "view_query",
+            "id": id,
+            "view_id": "view-id",
+            "expires_at": expires_at,
+            "total_count": 3,
+            "results": [{ "object": "page", "id": result }],
+            "next_cursor": next_cursor,
+            "has_more": true,
+        })
+        .to_string()
+    }
+
+    fn results(ids: &[&str]) -> String {
+        serde_json::json!({
+            "object": "list",
+            "results": ids
+                .iter()
+                .map(|id| serde_json::json!({ "object": "page", "id": id }))
+                .collect::<Vec<_>>(),
+            "next_cursor": null,
+            "has_more": false,
+        })
+        .to_string()
+    }
+
+    async fn collect(server: &TestServer) -> Vec<String> {
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        client
+            .create_view_query()
+            .view_id("view-id")
+            .page_size(1)
+            .into_stream()
+            .map_ok(|page| page.id)
+            .try_collect()
             .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+            .unwrap()
+    }
 
-        let query =
-            serde_json::from_slice::<notionrs_types::object::view::ViewQueryResponse>(&body)?;
+    async fn paths(server: &TestServer) -> Vec<String> {
+        server
+            .requests()
+            .await
+            .into_iter()
+            .map(|request| format!("{} {}", request.method, request.path))
+            .collect()
+    }
 
-        Ok(query)
+    #[tokio::test]
+    async fn into_stream_continues_with_query_results() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &created_query("query-1", "2999-01-01T00:00:00.000Z", "page-1", "cursor-2"),
+            ),
+            TestResponse::json(200, &results(&["page-2", "page-3"])),
+        ])
+        .await;
+
+        assert_eq!(collect(&server).await, ["page-1", "page-2", "page-3"]);
+        assert_eq!(
+            paths(&server).await,
+            [
+                "POST /v1/views/view-id/queries",
+                "GET /v1/views/view-id/queries/query-1?start_cursor=cursor-2&page_size=1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn into_stream_recreates_an_expired_query() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &created_query("query-1", "2000-01-01T00:00:00.000Z", "page-1", "cursor-2"),
+            ),
+            TestResponse::json(
+                200,
+                &created_query("query-2", "2999-01-01T00:00:00.000Z", "page-1", "cursor-2"),
+            ),
+            TestResponse::json(200, &results(&["page-2"])),
+        ])
+        .await;
+
+        assert_eq!(collect(&server).await, ["page-1", "page-2"]);
+        assert_eq!(
+            paths(&server).await,
+            [
+                "POST /v1/views/view-id/queries",
+                "POST /v1/views/view-id/queries",
+                "GET /v1/views/view-id/queries/query-2?start_cursor=cursor-2&page_size=1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn into_stream_recreates_a_query_that_is_gone() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                &created_query("query-1", "2999-01-01T00:00:00.000Z", "page-1", "cursor-2"),
+            ),
+            TestResponse::json(
+                404,
+                r#"{"object":"error","status":404,"code":"object_not_found","message":"Not found."}"#,
+            ),
+            TestResponse::json(
+                200,
+                &created_query("query-2", "2999-01-01T00:00:00.000Z", "page-1", "cursor-2"),
+            ),
+            TestResponse::json(200, &results(&["page-2"])),
+        ])
+        .await;
+
+        assert_eq!(collect(&server).await, ["page-1", "page-2"]);
+        assert_eq!(
+            paths(&server).await,
+            [
+                "POST /v1/views/view-id/queries",
+                "GET /v1/views/view-id/queries/query-1?start_cursor=cursor-2&page_size=1",
+                "POST /v1/views/view-id/queries",
+                "GET /v1/views/view-id/queries/query-2?start_cursor=cursor-2&page_size=1",
+            ]
+        );
     }
 }
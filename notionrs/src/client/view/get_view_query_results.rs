@@ -2,10 +2,14 @@ use serde::{Deserialize, Serialize};
 
 /// <https://developers.notion.com/reference/get-view-query-results>
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct GetViewQueryResultsClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the view.
     pub(crate) view_id: Option<String>,
 
@@ -19,6 +23,11 @@ pub struct GetViewQueryResultsClient {
     pub(crate) page_size: Option<u8>,
 }
 
+crate::impl_paginate!(
+    GetViewQueryResultsClient,
+    notionrs_types::object::view::ViewQueryPageReference
+);
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 struct GetViewQueryResultsRequestParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,7 +36,16 @@ struct GetViewQueryResultsRequestParams {
     page_size: Option<u8>,
 }
 
+#[notionrs_macro::blocking]
 impl GetViewQueryResultsClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<
@@ -35,6 +53,32 @@ impl GetViewQueryResultsClient {
             notionrs_types::object::view::ViewQueryPageReference,
         >,
         crate::error::Error,
+    > {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let results = serde_json::from_slice::<
+            notionrs_types::object::response::ListResponse<
+                notionrs_types::object::view::ViewQueryPageReference,
+            >,
+        >(&body)?;
+
+        Ok(results)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
     > {
         let view_id = self.view_id.ok_or(crate::error::Error::RequestParameter(
             "`view_id` is not set.".to_string(),
@@ -50,32 +94,12 @@ impl GetViewQueryResultsClient {
         })?;
 
         let url = format!(
-            "https://api.notion.com/v1/views/{}/queries/{}?{}",
-            view_id, query_id, request_params
+            "{}/v1/views/{}/queries/{}?{}",
+            self.config.base_url, view_id, query_id, request_params
         );
 
         let request = self.reqwest_client.get(url);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let results = serde_json::from_slice::<
-            notionrs_types::object::response::ListResponse<
-                notionrs_types::object::view::ViewQueryPageReference,
-            >,
-        >(&body)?;
-
-        Ok(results)
+        Ok((self.config, request))
     }
 }
@@ -2,10 +2,14 @@ use serde::{Deserialize, Serialize};
 
 /// <https://developers.notion.com/reference/list-views>
 #[derive(Debug, Default, Clone, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct ListViewsClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// ID of a Notion database to list views for.
     /// At least one of `database_id` or `data_source_id` is required.
     pub(crate) database_id: Option<String>,
@@ -21,6 +25,8 @@ pub struct ListViewsClient {
     pub(crate) page_size: Option<u8>,
 }
 
+crate::impl_paginate!(ListViewsClient, notionrs_types::object::view::ViewReference);
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 struct ListViewsRequestParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,13 +39,46 @@ struct ListViewsRequestParams {
     page_size: Option<u8>,
 }
 
+#[notionrs_macro::blocking]
 impl ListViewsClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<
-        notionrs_types::object::response::ListResponse<
-            notionrs_types::object::view::ViewReference,
-        >,
+        notionrs_types::object::response::ListResponse<notionrs_types::object::view::ViewReference>,
+        crate::error::Error,
+    > {
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
+
+        let views = serde_json::from_slice::<
+            notionrs_types::object::response::ListResponse<
+                notionrs_types::object::view::ViewReference,
+            >,
+        >(&body)?;
+
+        Ok(views)
+    }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
         crate::error::Error,
     > {
         if self.database_id.is_none() && self.data_source_id.is_none() {
@@ -55,30 +94,10 @@ impl ListViewsClient {
             page_size: self.page_size,
         })?;
 
-        let url = format!("https://api.notion.com/v1/views?{}", request_params);
+        let url = format!("{}/v1/views?{}", self.config.base_url, request_params);
 
         let request = self.reqwest_client.get(url);
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| crate::error::Error::BodyParse(e.to_string()))?;
-
-        let views = serde_json::from_slice::<
-            notionrs_types::object::response::ListResponse<
-                notionrs_types::object::view::ViewReference,
-            >,
-        >(&body)?;
-
-        Ok(views)
+        Ok((self.config, request))
     }
 }
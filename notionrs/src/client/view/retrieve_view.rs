@@ -1,33 +1,32 @@
 /// <https://developers.notion.com/reference/retrieve-a-view>
 #[derive(Debug, Default, notionrs_macro::Setter)]
+#[setter(blocking)]
 pub struct RetrieveViewClient {
     /// The reqwest http client
     pub(crate) reqwest_client: reqwest::Client,
 
+    #[setter(skip)]
+    pub(crate) config: std::sync::Arc<crate::client::ClientConfig>,
+
     /// The ID of the view to retrieve.
     pub(crate) view_id: Option<String>,
 }
 
+#[notionrs_macro::blocking]
 impl RetrieveViewClient {
+    /// Build the request without sending it, e.g. to log or review it first.
+    ///
+    /// The `Authorization` header is left out.
+    pub fn build_request(self) -> Result<crate::PreparedRequest, crate::error::Error> {
+        let (config, request) = self.into_request()?;
+        crate::util::prepare_request(&config, request)
+    }
+
     pub async fn send(
         self,
     ) -> Result<notionrs_types::object::view::ViewResponse, crate::error::Error> {
-        let view_id = self.view_id.ok_or(crate::error::Error::RequestParameter(
-            "`view_id` is not set.".to_string(),
-        ))?;
-
-        let url = format!("https://api.notion.com/v1/views/{}", view_id);
-
-        let request = self.reqwest_client.get(url);
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::try_from_response_async(response).await);
-        }
+        let (config, request) = self.into_request()?;
+        let response = crate::util::send_request(&config, request).await?;
 
         let body = response
             .bytes()
@@ -38,4 +37,24 @@ impl RetrieveViewClient {
 
         Ok(view)
     }
+
+    fn into_request(
+        self,
+    ) -> Result<
+        (
+            std::sync::Arc<crate::client::ClientConfig>,
+            reqwest::RequestBuilder,
+        ),
+        crate::error::Error,
+    > {
+        let view_id = self.view_id.ok_or(crate::error::Error::RequestParameter(
+            "`view_id` is not set.".to_string(),
+        ))?;
+
+        let url = format!("{}/v1/views/{}", self.config.base_url, view_id);
+
+        let request = self.reqwest_client.get(url);
+
+        Ok((self.config, request))
+    }
 }
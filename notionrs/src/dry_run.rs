@@ -0,0 +1,175 @@
+//! Building requests without sending them.
+
+/// A fully-built request returned by `build_request()` on a request builder,
+/// such as [`CreatePageClient::build_request`](crate::client::page::create_page::CreatePageClient::build_request).
+///
+/// It holds exactly what `send()` would put on the wire, except that the
+/// `Authorization` header is left out so it can be logged safely.
+///
+/// ```no_run
+/// # fn run() -> Result<(), notionrs::Error> {
+/// let client = notionrs::Client::new("secret");
+///
+/// let request = client
+///     .update_data_source()
+///     .data_source_id("2b0fa2a9-3b5f-4d2b-9b0f-0f0f0f0f0f0f")
+///     .in_trash(true)
+///     .build_request()?;
+///
+/// println!("{request}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreparedRequest {
+    /// HTTP method, e.g. `PATCH`.
+    pub method: reqwest::Method,
+
+    /// Full URL, including the query string.
+    pub url: reqwest::Url,
+
+    /// Headers sent with the request, without `Authorization`.
+    pub headers: reqwest::header::HeaderMap,
+
+    /// Serialized body: JSON for most endpoints, `multipart/form-data` for
+    /// sending file uploads. `None` when the request has no body.
+    pub body: Option<bytes::Bytes>,
+}
+
+impl PreparedRequest {
+    /// Deserialize the body, e.g. into
+    /// [`CreatePageRequestBody`](crate::client::page::create_page::CreatePageRequestBody)
+    /// or a [`serde_json::Value`].
+    ///
+    /// Fails when the request has no body or the body is not valid JSON for `T`.
+    pub fn json<T>(&self) -> Result<T, crate::error::Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let body = self.body.as_deref().ok_or_else(|| {
+            crate::error::Error::RequestParameter("The request has no body.".to_string())
+        })?;
+
+        Ok(serde_json::from_slice(body)?)
+    }
+}
+
+impl std::fmt::Display for PreparedRequest {
+    /// `METHOD URL`, followed by the body on the next line when there is one.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method, self.url)?;
+
+        if let Some(body) = &self.body {
+            write!(f, "\n{}", String::from_utf8_lossy(body))?;
+        }
+
+        Ok(())
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::HashMap;
+
+    use notionrs_types::prelude::*;
+
+    fn client() -> crate::Client {
+        crate::Client::builder()
+            .notion_api_key("secret")
+            .base_url("http://127.0.0.1:8080/")
+            .user_agent("migration-script")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn build_create_page_request() {
+        let request = client()
+            .create_page::<HashMap<String, PageProperty>>()
+            .data_source_id("data-source-id")
+            .properties(HashMap::from([(
+                "Name".to_string(),
+                PageProperty::Title(PageTitleProperty::from("Release notes")),
+            )]))
+            .build_request()
+            .unwrap();
+
+        assert_eq!(request.method, reqwest::Method::POST);
+        assert_eq!(request.url.as_str(), "http://127.0.0.1:8080/v1/pages");
+        assert_eq!(
+            request.headers["Notion-Version"],
+            crate::client::builder::DEFAULT_NOTION_VERSION
+        );
+        assert_eq!(request.headers["Content-Type"], "application/json");
+        assert_eq!(request.headers["User-Agent"], "migration-script");
+        assert!(!request.headers.contains_key("Authorization"));
+
+        let body = request.json::<serde_json::Value>().unwrap();
+        assert_eq!(body["parent"]["data_source_id"], "data-source-id");
+        assert_eq!(
+            body["properties"]["Name"]["title"][0]["text"]["content"],
+            "Release notes"
+        );
+
+        request
+            .json::<crate::client::page::create_page::CreatePageRequestBody>()
+            .unwrap();
+    }
+
+    #[test]
+    fn build_request_checks_parameters() {
+        let error = client()
+            .update_data_source()
+            .in_trash(true)
+            .build_request()
+            .unwrap_err();
+
+        assert!(matches!(error, crate::Error::RequestParameter(_)));
+    }
+
+    #[test]
+    fn build_request_without_body() {
+        let request = client()
+            .get_block_children()
+            .block_id("block-id")
+            .page_size(10)
+            .build_request()
+            .unwrap();
+
+        assert_eq!(request.method, reqwest::Method::GET);
+        assert_eq!(
+            request.url.as_str(),
+            "http://127.0.0.1:8080/v1/blocks/block-id/children?page_size=10"
+        );
+        assert!(request.body.is_none());
+        assert!(request.json::<serde_json::Value>().is_err());
+        assert_eq!(
+            request.to_string(),
+            "GET http://127.0.0.1:8080/v1/blocks/block-id/children?page_size=10"
+        );
+    }
+
+    #[test]
+    fn build_multipart_request() {
+        let request = client()
+            .send_file_upload()
+            .file_upload_id("file-upload-id")
+            .file(b"hello".to_vec())
+            .filename("hello.txt")
+            .build_request()
+            .unwrap();
+
+        let content_type = request.headers["Content-Type"].to_str().unwrap();
+        assert!(content_type.starts_with("multipart/form-data; boundary="));
+
+        let body = String::from_utf8(request.body.unwrap().to_vec()).unwrap();
+        assert!(body.contains(r#"filename="hello.txt""#));
+        assert!(body.contains("hello"));
+    }
+}
@@ -5,6 +5,11 @@
 /// Errors that can happen when using notionrs
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
+    /// This error occurs when a `ClientBuilder` is given an invalid configuration,
+    /// such as a malformed base URL or a header value that cannot be sent.
+    #[error("Client build error: {0}")]
+    ClientBuild(String),
+
     /// This error occurs when the request fails due to a network issue.
     #[error("Network error: {0}")]
     Network(String),
@@ -20,6 +25,11 @@ pub enum Error {
         status: u16,
         /// Error message
         message: String,
+        /// Notion's error code, when the body is a well-formed Notion API error.
+        code: Option<ApiErrorCode>,
+        /// Value of the `Retry-After` response header, when present. Notion sends
+        /// it with `429 rate_limited` responses.
+        retry_after: Option<std::time::Duration>,
         /// Notion's request identifier, taken from the error body's `request_id`
         /// field, or from the `x-notion-request-id` response header when the body
         /// doesn't provide one. Include it when reporting an issue to Notion.
@@ -59,6 +69,53 @@ pub enum Error {
         /// The ID of the async task that was returned instead.
         task_id: String,
     },
+
+    /// This error occurs when an async task awaited with
+    /// `Client::wait_for_async_task` ends in the `failed` state.
+    #[error("Async task `{task_id}` failed: {message}")]
+    AsyncTaskFailed {
+        /// The ID of the async task.
+        task_id: String,
+        /// The error code reported by the task.
+        code: notionrs_types::object::async_task::AsyncTaskErrorCode,
+        /// A human-readable description of the error.
+        message: String,
+    },
+
+    /// This error occurs when an async task awaited with
+    /// `Client::wait_for_async_task` hasn't finished within the maximum wait.
+    #[error("Async task `{task_id}` did not finish within {waited:?}")]
+    AsyncTaskTimedOut {
+        /// The ID of the async task, which may still finish later.
+        task_id: String,
+        /// How long the task was waited for.
+        waited: std::time::Duration,
+    },
+
+    /// This error occurs when `Client::upsert_page` finds more than one page
+    /// with the value of the key property, so it can't tell which to update.
+    #[error("{} pages have the same value in the key property `{key}`", page_ids.len())]
+    UpsertMultipleMatches {
+        /// The name of the key property.
+        key: String,
+        /// The IDs of the matching pages.
+        page_ids: Vec<String>,
+    },
+
+    /// This error occurs when a request of `Client::append_block_tree` fails
+    /// after some blocks were appended. They are left in place.
+    #[error("Appended {appended} of {total} blocks: {source}")]
+    AppendBlockTreeIncomplete {
+        /// The number of blocks appended, nested ones included.
+        appended: usize,
+        /// The number of blocks to append, nested ones included.
+        total: usize,
+        /// The IDs of the first-level blocks appended, in order.
+        block_ids: Vec<String>,
+        /// The error of the failed request.
+        #[source]
+        source: Box<Error>,
+    },
 }
 
 /// Error code returned by the Notion API.
@@ -171,6 +228,85 @@ impl Error {
         }
     }
 
+    /// The HTTP status code, when this is an [`Error::Http`].
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Notion's error code, when this is an [`Error::Http`] with a well-formed
+    /// Notion API error body.
+    pub fn code(&self) -> Option<&ApiErrorCode> {
+        match self {
+            Error::Http { code, .. } => code.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The wait requested by the `Retry-After` response header, when present.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            Error::Http { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Whether the request failed with a transient error that may succeed when sent again:
+    /// a rate limit, a conflict, or an unavailable or timed out service.
+    ///
+    /// These are the responses a [`RetryPolicy`](crate::RetryPolicy) retries. A `500` is
+    /// not included because the request may already have been applied.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Http { status, code, .. } => {
+                crate::retry::RetryPolicy::is_retryable_status(*status)
+                    || matches!(
+                        code,
+                        Some(
+                            ApiErrorCode::RateLimited
+                                | ApiErrorCode::ConflictError
+                                | ApiErrorCode::ServiceUnavailable
+                                | ApiErrorCode::ServiceOverload
+                                | ApiErrorCode::GatewayTimeout
+                        )
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the resource does not exist, or isn't shared with the integration
+    /// (`404 object_not_found`).
+    pub fn is_not_found(&self) -> bool {
+        self.matches(404, ApiErrorCode::ObjectNotFound)
+    }
+
+    /// Whether the bearer token is not valid (`401 unauthorized`).
+    pub fn is_unauthorized(&self) -> bool {
+        self.matches(401, ApiErrorCode::Unauthorized)
+    }
+
+    /// Whether the request exceeded the rate limit (`429 rate_limited`).
+    ///
+    /// [`retry_after`](Error::retry_after) tells how long to wait before retrying.
+    pub fn is_rate_limited(&self) -> bool {
+        self.matches(429, ApiErrorCode::RateLimited)
+    }
+
+    /// Whether this is an [`Error::Http`] with the given error code, or with the
+    /// given status when the body carries no code.
+    fn matches(&self, expected_status: u16, expected_code: ApiErrorCode) -> bool {
+        match self {
+            Error::Http {
+                code: Some(code), ..
+            } => *code == expected_code,
+            Error::Http { status, .. } => *status == expected_status,
+            _ => false,
+        }
+    }
+
     pub(crate) async fn try_from_response_async(response: reqwest::Response) -> Self {
         let status = response.status().as_u16();
         let headers = response.headers().clone();
@@ -190,11 +326,14 @@ impl Error {
     ) -> Self {
         let ray_id = header_value(headers, "cf-ray");
         let header_request_id = header_value(headers, "x-notion-request-id");
+        let retry_after = crate::retry::parse_retry_after(headers);
 
         let Some(body) = body else {
             return Error::Http {
                 status,
                 message: "An error occurred, but failed to retrieve the error details from the response body.".to_string(),
+                code: None,
+                retry_after,
                 request_id: header_request_id,
                 ray_id,
             };
@@ -205,6 +344,8 @@ impl Error {
             Ok(error_response) => Error::Http {
                 status,
                 message: error_response.message,
+                code: Some(error_response.code),
+                retry_after,
                 request_id: error_response.request_id.or(header_request_id),
                 ray_id,
             },
@@ -224,6 +365,8 @@ impl Error {
                 Error::Http {
                     status,
                     message,
+                    code: None,
+                    retry_after,
                     request_id: header_request_id,
                     ray_id,
                 }
@@ -293,6 +436,7 @@ mod unit_tests {
                 message,
                 request_id,
                 ray_id,
+                ..
             } => (status, message, request_id, ray_id),
             other => panic!("expected Error::Http, got {other:?}"),
         }
@@ -465,6 +609,90 @@ mod unit_tests {
         assert_eq!(error.ray_id(), None);
     }
 
+    #[test]
+    fn http_from_parts_keeps_code_and_retry_after() {
+        let body = r#"
+        {
+            "object": "error",
+            "status": 429,
+            "code": "rate_limited",
+            "message": "Rate limited."
+        }
+        "#;
+
+        let error = Error::http_from_parts(429, &header_map(&[("retry-after", "12")]), Some(body));
+
+        assert_eq!(error.status(), Some(429));
+        assert_eq!(error.code(), Some(&ApiErrorCode::RateLimited));
+        assert_eq!(
+            error.retry_after(),
+            Some(std::time::Duration::from_secs(12))
+        );
+
+        let error =
+            Error::http_from_parts(502, &reqwest::header::HeaderMap::new(), Some("Bad Gateway"));
+        assert_eq!(error.code(), None);
+        assert_eq!(error.retry_after(), None);
+        assert_eq!(error.status(), Some(502));
+    }
+
+    #[test]
+    fn error_kind_helpers() {
+        let error = Error::http_from_parts(
+            404,
+            &reqwest::header::HeaderMap::new(),
+            Some(NOTION_ERROR_BODY),
+        );
+        assert!(error.is_not_found());
+        assert!(!error.is_unauthorized());
+        assert!(!error.is_rate_limited());
+        assert!(!error.is_retryable());
+
+        let error = Error::http_from_parts(
+            401,
+            &reqwest::header::HeaderMap::new(),
+            Some(
+                r#"{"object":"error","status":401,"code":"unauthorized","message":"API token is invalid."}"#,
+            ),
+        );
+        assert!(error.is_unauthorized());
+        assert!(!error.is_not_found());
+
+        let error = Error::http_from_parts(
+            429,
+            &reqwest::header::HeaderMap::new(),
+            Some(
+                r#"{"object":"error","status":429,"code":"rate_limited","message":"Rate limited."}"#,
+            ),
+        );
+        assert!(error.is_rate_limited());
+        assert!(error.is_retryable());
+
+        // A 400 `validation_error` is neither retryable nor "not found".
+        let error = Error::http_from_parts(
+            400,
+            &reqwest::header::HeaderMap::new(),
+            Some(
+                r#"{"object":"error","status":400,"code":"validation_error","message":"Invalid."}"#,
+            ),
+        );
+        assert_eq!(error.code(), Some(&ApiErrorCode::ValidationError));
+        assert!(!error.is_retryable());
+        assert!(!error.is_not_found());
+
+        // Without a Notion error body, the status decides.
+        let error = Error::http_from_parts(503, &reqwest::header::HeaderMap::new(), None);
+        assert!(error.is_retryable());
+        let error = Error::http_from_parts(404, &reqwest::header::HeaderMap::new(), None);
+        assert!(error.is_not_found());
+
+        let error = Error::Network("connection reset".to_string());
+        assert!(!error.is_retryable());
+        assert!(!error.is_not_found());
+        assert_eq!(error.status(), None);
+        assert_eq!(error.code(), None);
+    }
+
     #[test]
     fn unexpected_async_task_display() {
         let error = Error::UnexpectedAsyncTask {
@@ -1,16 +1,30 @@
 #![deny(missing_debug_implementations)]
 #![deny(missing_copy_implementations)]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+pub mod dry_run;
 pub mod error;
 pub(crate) mod r#macro;
+pub mod middleware;
+pub mod rate_limit;
+pub mod retry;
+#[cfg(feature = "tracing")]
+pub(crate) mod telemetry;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod r#trait;
 pub(crate) mod util;
 
 pub use crate::client::Client;
+pub use crate::client::builder::ClientBuilder;
+pub use crate::dry_run::PreparedRequest;
 pub use crate::error::Error;
 pub use crate::error::{ApiErrorCode, ErrorResponse};
-pub use crate::r#trait::PaginateExt;
+pub use crate::rate_limit::RateLimiter;
+pub use crate::retry::RetryPolicy;
+pub use crate::r#trait::{PaginateExt, Pagination};
 
 pub mod types {
     pub mod prelude {
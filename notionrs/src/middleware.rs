@@ -0,0 +1,268 @@
+//! Hooks that run around every request sent by a [`Client`](crate::Client).
+
+use std::sync::Arc;
+
+/// The boxed future a [`Middleware`] returns. It is `Sync` as well as `Send`, so
+/// the futures of request builders that run the middleware chain stay `Sync`.
+pub type BoxFuture<'a, T> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + Sync + 'a>>;
+
+/// A hook that wraps every HTTP request a [`Client`](crate::Client) sends.
+///
+/// A middleware receives the fully built [`reqwest::Request`] and decides what to
+/// do with it: modify it and pass it on with [`Next::run`], inspect the response
+/// that comes back, or answer on its own without calling `next` at all.
+///
+/// Middlewares run in the order they were added to the
+/// [`ClientBuilder`](crate::ClientBuilder): the first one sees the request first
+/// and the response last. They run once per attempt, after the
+/// [`RateLimiter`](crate::RateLimiter) and inside the [`RetryPolicy`](crate::RetryPolicy)
+/// loop, so a retried request passes through them again. A response returned by a
+/// middleware is handled exactly like one from the network, including retries
+/// and conversion of non-2xx statuses into [`Error::Http`](crate::Error::Http).
+///
+/// ```no_run
+/// use notionrs::middleware::{BoxFuture, Middleware, Next};
+///
+/// #[derive(Debug)]
+/// struct Latency;
+///
+/// impl Middleware for Latency {
+///     fn handle<'a>(
+///         &'a self,
+///         request: reqwest::Request,
+///         next: Next<'a>,
+///     ) -> BoxFuture<'a, Result<reqwest::Response, notionrs::Error>> {
+///         Box::pin(async move {
+///             let method = request.method().clone();
+///             let url = request.url().clone();
+///             let start = std::time::Instant::now();
+///
+///             let response = next.run(request).await;
+///
+///             println!("{method} {url} took {:?}", start.elapsed());
+///             response
+///         })
+///     }
+/// }
+///
+/// let client = notionrs::Client::builder()
+///     .notion_api_key(std::env::var("NOTION_API_KEY").unwrap())
+///     .middleware(Latency)
+///     .build()
+///     .unwrap();
+/// ```
+pub trait Middleware: std::fmt::Debug + Send + Sync + 'static {
+    /// Handle a request, usually by passing it on with `next.run(request)`.
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<reqwest::Response, crate::error::Error>>;
+}
+
+/// The rest of the middleware chain, ending with the HTTP client itself.
+#[derive(Debug, Clone, Copy)]
+pub struct Next<'a> {
+    reqwest_client: &'a reqwest::Client,
+    middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        reqwest_client: &'a reqwest::Client,
+        middlewares: &'a [Arc<dyn Middleware>],
+    ) -> Self {
+        Self {
+            reqwest_client,
+            middlewares,
+        }
+    }
+
+    /// Pass the request to the next middleware, or send it when none is left.
+    pub fn run(
+        self,
+        request: reqwest::Request,
+    ) -> BoxFuture<'a, Result<reqwest::Response, crate::error::Error>> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => middleware.handle(
+                request,
+                Next {
+                    reqwest_client: self.reqwest_client,
+                    middlewares: rest,
+                },
+            ),
+            None => Box::pin(async move {
+                self.reqwest_client
+                    .execute(request)
+                    .await
+                    .map_err(|e| crate::error::Error::Network(e.to_string()))
+            }),
+        }
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    const USER_BODY: &str = r#"{"object":"user","id":"user-id","type":"bot","bot":{}}"#;
+
+    #[derive(Debug)]
+    struct InjectHeader;
+
+    impl Middleware for InjectHeader {
+        fn handle<'a>(
+            &'a self,
+            mut request: reqwest::Request,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, Result<reqwest::Response, crate::error::Error>> {
+            request.headers_mut().insert(
+                "X-Tenant",
+                reqwest::header::HeaderValue::from_static("tenant-1"),
+            );
+            next.run(request)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware for Recorder {
+        fn handle<'a>(
+            &'a self,
+            request: reqwest::Request,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, Result<reqwest::Response, crate::error::Error>> {
+            Box::pin(async move {
+                self.events.lock().unwrap().push(format!(
+                    "{} before {} {}",
+                    self.name,
+                    request.method(),
+                    request.url().path()
+                ));
+
+                let response = next.run(request).await?;
+
+                self.events.lock().unwrap().push(format!(
+                    "{} after {}",
+                    self.name,
+                    response.status().as_u16()
+                ));
+
+                Ok(response)
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    struct Canned;
+
+    impl Middleware for Canned {
+        fn handle<'a>(
+            &'a self,
+            _request: reqwest::Request,
+            _next: Next<'a>,
+        ) -> BoxFuture<'a, Result<reqwest::Response, crate::error::Error>> {
+            Box::pin(async move {
+                let response = http::Response::builder()
+                    .status(200)
+                    .body(r#"{"object":"user","id":"canned-user","type":"bot","bot":{}}"#)
+                    .unwrap();
+                Ok(reqwest::Response::from(response))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_can_modify_requests() {
+        let server = TestServer::start(vec![TestResponse::json(200, USER_BODY)]).await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .middleware(InjectHeader)
+            .build()
+            .unwrap();
+
+        client.get_self().send().await.unwrap();
+
+        let requests = server.requests().await;
+        assert_eq!(requests[0].header("x-tenant"), Some("tenant-1"));
+    }
+
+    #[tokio::test]
+    async fn middlewares_run_in_order_around_each_attempt() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                503,
+                r#"{"object":"error","status":503,"code":"service_unavailable","message":"Unavailable."}"#,
+            ),
+            TestResponse::json(200, USER_BODY),
+        ])
+        .await;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .retry_policy(
+                crate::RetryPolicy::default()
+                    .initial_backoff(std::time::Duration::from_millis(1))
+                    .jitter(false),
+            )
+            .middleware(Recorder {
+                name: "outer",
+                events: events.clone(),
+            })
+            .middleware(Recorder {
+                name: "inner",
+                events: events.clone(),
+            })
+            .build()
+            .unwrap();
+
+        client.get_self().send().await.unwrap();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "outer before GET /v1/users/me",
+                "inner before GET /v1/users/me",
+                "inner after 503",
+                "outer after 503",
+                "outer before GET /v1/users/me",
+                "inner before GET /v1/users/me",
+                "inner after 200",
+                "outer after 200",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn middleware_can_short_circuit_with_a_canned_response() {
+        let server = TestServer::start(vec![TestResponse::json(200, USER_BODY)]).await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .middleware(Canned)
+            .build()
+            .unwrap();
+
+        let user = client.get_self().send().await.unwrap();
+
+        assert_eq!(user.id, "canned-user");
+        assert!(server.requests().await.is_empty());
+    }
+}
@@ -0,0 +1,199 @@
+//! Client-side rate limiting.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A token-bucket rate limiter shared by every request a [`Client`](crate::Client) sends.
+///
+/// Notion allows an average of about three requests per second per integration.
+/// The bucket holds up to `burst` tokens and refills at `requests_per_second`;
+/// each request takes one token and waits asynchronously when none is left.
+///
+/// Clones share the same bucket, so a limiter configured on a `Client` also
+/// throttles its clones, the request builders it hands out (including those
+/// driven by [`PaginateExt::into_stream`](crate::PaginateExt::into_stream)), and
+/// any other `Client` built with a clone of the same limiter.
+///
+/// ```no_run
+/// let client = notionrs::Client::builder()
+///     .notion_api_key(std::env::var("NOTION_API_KEY").unwrap())
+///     .rate_limiter(notionrs::RateLimiter::new(3.0, 3))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: u32,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens. Negative while waiters hold reservations.
+    tokens: f64,
+    last_refill: tokio::time::Instant,
+}
+
+impl Default for RateLimiter {
+    /// Three requests per second with a burst of three, matching Notion's documented average.
+    fn default() -> Self {
+        Self::new(3.0, 3)
+    }
+}
+
+impl RateLimiter {
+    /// Create a limiter that allows `requests_per_second` on average and up to
+    /// `burst` requests at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_second` is not a positive finite number or `burst` is `0`.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        assert!(
+            requests_per_second.is_finite() && requests_per_second > 0.0,
+            "`requests_per_second` must be a positive finite number"
+        );
+        assert!(burst > 0, "`burst` must be at least 1");
+
+        Self {
+            requests_per_second,
+            burst,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: f64::from(burst),
+                last_refill: tokio::time::Instant::now(),
+            })),
+        }
+    }
+
+    /// The average number of requests allowed per second.
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// The maximum number of requests allowed at once.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Wait until a request may be sent, then take a token.
+    ///
+    /// Waiters are served in the order they called `acquire`.
+    pub async fn acquire(&self) {
+        let wait = self.reserve();
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token, possibly on credit, and returns how long to wait before using it.
+    fn reserve(&self) -> Duration {
+        let mut bucket = self
+            .bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let now = tokio::time::Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens =
+            (bucket.tokens + elapsed * self.requests_per_second).min(f64::from(self.burst));
+        bucket.last_refill = now;
+
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.requests_per_second)
+        }
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_allows_burst_then_waits() {
+        let limiter = RateLimiter::new(2.0, 2);
+        let start = tokio::time::Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_millis(500));
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn bucket_refills_up_to_burst() {
+        let limiter = RateLimiter::new(1.0, 2);
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        tokio::time::sleep(Duration::from_secs(10)).await;
+
+        let start = tokio::time::Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clones_share_the_bucket() {
+        let limiter = RateLimiter::new(1.0, 1);
+        let clone = limiter.clone();
+        let start = tokio::time::Instant::now();
+
+        limiter.acquire().await;
+        clone.acquire().await;
+
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_waiters_are_spaced_out() {
+        let limiter = RateLimiter::new(4.0, 1);
+        let start = tokio::time::Instant::now();
+
+        let elapsed = futures::future::join_all((0..4).map(|_| {
+            let limiter = limiter.clone();
+            async move {
+                limiter.acquire().await;
+                start.elapsed()
+            }
+        }))
+        .await;
+
+        assert_eq!(
+            elapsed,
+            vec![
+                Duration::ZERO,
+                Duration::from_millis(250),
+                Duration::from_millis(500),
+                Duration::from_millis(750),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`burst` must be at least 1")]
+    fn new_rejects_zero_burst() {
+        RateLimiter::new(1.0, 0);
+    }
+}
@@ -0,0 +1,207 @@
+//! Automatic retries for transient Notion API errors.
+
+use std::time::Duration;
+
+/// How a [`Client`](crate::Client) retries requests that failed with a transient error.
+///
+/// A request is retried when Notion answers with one of the following:
+///
+/// | Status | Error code                                  |
+/// | ------ | ------------------------------------------- |
+/// | 409    | `conflict_error`                            |
+/// | 429    | `rate_limited`                              |
+/// | 503    | `service_unavailable` / `service_overload`  |
+/// | 504    | `gateway_timeout`                           |
+///
+/// `500` and `502` are not retried: Notion may already have applied a write before
+/// answering with them, and sending a `POST` again would duplicate it.
+///
+/// The wait before each retry is the `Retry-After` response header when present,
+/// otherwise an exponential backoff starting at `initial_backoff`, doubling on every
+/// attempt. Either way the wait is capped at `max_backoff`. With `jitter` enabled,
+/// the backoff is randomized between zero and the computed delay.
+///
+/// Requests whose body cannot be replayed (multipart file uploads) are sent only once.
+///
+/// ```no_run
+/// let client = notionrs::Client::builder()
+///     .notion_api_key(std::env::var("NOTION_API_KEY").unwrap())
+///     .retry_policy(notionrs::RetryPolicy::default().max_attempts(5))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, notionrs_macro::Setter)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. `1` disables retries.
+    pub(crate) max_attempts: u32,
+
+    /// Backoff before the first retry when the response has no `Retry-After` header.
+    pub(crate) initial_backoff: Duration,
+
+    /// Upper bound of every wait, including one requested by `Retry-After`.
+    pub(crate) max_backoff: Duration,
+
+    /// Randomize each backoff between zero and the computed delay.
+    pub(crate) jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// 3 attempts, 1 second initial backoff, 30 seconds maximum backoff, with jitter.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Whether a response with this status code is worth retrying.
+    pub fn is_retryable_status(status: u16) -> bool {
+        matches!(status, 409 | 429 | 503 | 504)
+    }
+
+    /// The wait before retrying after the given (1-based) failed attempt.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_backoff);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(exponent))
+            .min(self.max_backoff);
+
+        if self.jitter {
+            backoff.mul_f64(random_fraction())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Parses a `Retry-After` header given in seconds.
+///
+/// The HTTP-date form is not used by Notion and is ignored.
+pub(crate) fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    let seconds = value.trim().parse::<f64>().ok()?;
+
+    if seconds.is_finite() && seconds >= 0.0 {
+        Some(Duration::from_secs_f64(seconds))
+    } else {
+        None
+    }
+}
+
+/// A value in `[0, 1)` that differs between calls, used for jitter.
+fn random_fraction() -> f64 {
+    use std::hash::BuildHasher;
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+
+    let hash = std::collections::hash_map::RandomState::new().hash_one(nanos);
+
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn delay_grows_exponentially_up_to_max_backoff() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(500))
+            .jitter(false);
+
+        assert_eq!(policy.delay(1, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(2, None), Duration::from_millis(200));
+        assert_eq!(policy.delay(3, None), Duration::from_millis(400));
+        assert_eq!(policy.delay(4, None), Duration::from_millis(500));
+        assert_eq!(policy.delay(100, None), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn delay_with_jitter_stays_below_backoff() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .jitter(true);
+
+        for _ in 0..100 {
+            assert!(policy.delay(1, None) <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn delay_prefers_retry_after() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(7))),
+            Duration::from_secs(7)
+        );
+    }
+
+    #[test]
+    fn delay_caps_retry_after_at_max_backoff() {
+        let policy = RetryPolicy::default().max_backoff(Duration::from_secs(10));
+
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(3600))),
+            Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_seconds() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert("Retry-After", "2".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(2)));
+
+        headers.insert("Retry-After", "0.5".parse().unwrap());
+        assert_eq!(
+            parse_retry_after(&headers),
+            Some(Duration::from_millis(500))
+        );
+
+        headers.insert(
+            "Retry-After",
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        for status in [409, 429, 503, 504] {
+            assert!(RetryPolicy::is_retryable_status(status));
+        }
+        for status in [400, 401, 403, 404, 500, 502] {
+            assert!(!RetryPolicy::is_retryable_status(status));
+        }
+    }
+}
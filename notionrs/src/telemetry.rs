@@ -0,0 +1,306 @@
+//! `tracing` instrumentation for requests sent by a [`Client`](crate::Client).
+//!
+//! Every API call runs inside a `notion.request` span with these fields:
+//!
+//! | Field                 | Value                                                          |
+//! | --------------------- | -------------------------------------------------------------- |
+//! | `http.method`         | HTTP method                                                    |
+//! | `http.path`           | Path template with IDs replaced, e.g. `/v1/data_sources/{id}/query` |
+//! | `http.status_code`    | Status of the last response                                    |
+//! | `latency_ms`          | Time spent in the call, including retries and rate limiting    |
+//! | `notion.start_cursor` | `start_cursor` sent with a paginated request                   |
+//! | `notion.retries`      | Number of retries after the first attempt                      |
+//! | `notion.request_id`   | `x-notion-request-id` header, or `request_id` of an error body |
+//! | `notion.ray_id`       | `cf-ray` header                                                |
+//! | `error`               | The error returned to the caller, if any                       |
+
+/// Path segments that are followed by an object ID.
+const ID_COLLECTIONS: &[&str] = &[
+    "async_tasks",
+    "blocks",
+    "comments",
+    "data_sources",
+    "databases",
+    "file_uploads",
+    "pages",
+    "users",
+    "views",
+];
+
+/// Path segments that follow a collection but are not IDs.
+const LITERAL_SEGMENTS: &[&str] = &["me", "meeting_notes"];
+
+pub(crate) fn request_span(request: &reqwest::Request) -> tracing::Span {
+    let span = tracing::info_span!(
+        "notion.request",
+        http.method = %request.method(),
+        http.path = %path_template(request.url().path()),
+        http.status_code = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+        notion.start_cursor = tracing::field::Empty,
+        notion.retries = tracing::field::Empty,
+        notion.request_id = tracing::field::Empty,
+        notion.ray_id = tracing::field::Empty,
+        error = tracing::field::Empty,
+    );
+
+    if let Some(start_cursor) = start_cursor(request) {
+        span.record("notion.start_cursor", start_cursor.as_str());
+    }
+
+    span
+}
+
+/// Records the outcome of a call on its `notion.request` span.
+pub(crate) fn record_outcome(
+    span: &tracing::Span,
+    result: &Result<reqwest::Response, crate::error::Error>,
+    retries: u32,
+    latency: std::time::Duration,
+) {
+    span.record("latency_ms", latency.as_millis() as u64);
+    span.record("notion.retries", retries);
+
+    match result {
+        Ok(response) => {
+            span.record("http.status_code", response.status().as_u16());
+
+            let headers = response.headers();
+            if let Some(request_id) = header_value(headers, "x-notion-request-id") {
+                span.record("notion.request_id", request_id);
+            }
+            if let Some(ray_id) = header_value(headers, "cf-ray") {
+                span.record("notion.ray_id", ray_id);
+            }
+        }
+        Err(error) => {
+            if let crate::error::Error::Http { status, .. } = error {
+                span.record("http.status_code", *status);
+            }
+            if let Some(request_id) = error.request_id() {
+                span.record("notion.request_id", request_id);
+            }
+            if let Some(ray_id) = error.ray_id() {
+                span.record("notion.ray_id", ray_id);
+            }
+            span.record("error", tracing::field::display(error));
+        }
+    }
+}
+
+/// Replaces the IDs in a request path with placeholders, starting at `/v1/` so
+/// that a path prefix from the configured base URL is dropped.
+pub(crate) fn path_template(path: &str) -> String {
+    let path = path.find("/v1/").map_or(path, |index| &path[index..]);
+
+    let mut template = String::with_capacity(path.len());
+    let mut previous = "";
+
+    for segment in path.split('/').skip(1) {
+        let placeholder =
+            if ID_COLLECTIONS.contains(&previous) && !LITERAL_SEGMENTS.contains(&segment) {
+                "{id}"
+            } else if previous == "properties" {
+                "{property_id}"
+            } else if previous == "queries" {
+                "{query_id}"
+            } else {
+                segment
+            };
+
+        template.push('/');
+        template.push_str(placeholder);
+        previous = segment;
+    }
+
+    template
+}
+
+/// The `start_cursor` of a paginated request, sent either as a query parameter
+/// (`GET` endpoints) or in the JSON body (`POST` query and search endpoints).
+pub(crate) fn start_cursor(request: &reqwest::Request) -> Option<String> {
+    #[derive(serde::Deserialize)]
+    struct CursorBody {
+        start_cursor: Option<String>,
+    }
+
+    let from_query = request
+        .url()
+        .query_pairs()
+        .find(|(key, _)| key == "start_cursor")
+        .map(|(_, value)| value.into_owned());
+
+    from_query.or_else(|| {
+        let body = request.body()?.as_bytes()?;
+        serde_json::from_slice::<CursorBody>(body)
+            .ok()?
+            .start_cursor
+    })
+}
+
+fn header_value<'a>(headers: &'a reqwest::header::HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name)?.to_str().ok()
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    #[test]
+    fn path_template_replaces_ids() {
+        for (path, expected) in [
+            ("/v1/pages", "/v1/pages"),
+            ("/v1/pages/abc", "/v1/pages/{id}"),
+            ("/v1/pages/abc/markdown", "/v1/pages/{id}/markdown"),
+            (
+                "/v1/pages/abc/properties/title",
+                "/v1/pages/{id}/properties/{property_id}",
+            ),
+            ("/v1/data_sources/abc/query", "/v1/data_sources/{id}/query"),
+            ("/v1/blocks/abc/children", "/v1/blocks/{id}/children"),
+            (
+                "/v1/blocks/meeting_notes/query",
+                "/v1/blocks/meeting_notes/query",
+            ),
+            ("/v1/users/me", "/v1/users/me"),
+            (
+                "/v1/views/abc/queries/def",
+                "/v1/views/{id}/queries/{query_id}",
+            ),
+            ("/proxy/notion/v1/users/abc", "/v1/users/{id}"),
+        ] {
+            assert_eq!(path_template(path), expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn start_cursor_from_query_or_body() {
+        let client = reqwest::Client::new();
+
+        let request = client
+            .get("https://api.notion.com/v1/users?page_size=10&start_cursor=cursor-1")
+            .build()
+            .unwrap();
+        assert_eq!(start_cursor(&request).as_deref(), Some("cursor-1"));
+
+        let request = client
+            .post("https://api.notion.com/v1/data_sources/abc/query")
+            .body(r#"{"page_size":10,"start_cursor":"cursor-2"}"#)
+            .build()
+            .unwrap();
+        assert_eq!(start_cursor(&request).as_deref(), Some("cursor-2"));
+
+        let request = client
+            .post("https://api.notion.com/v1/data_sources/abc/query")
+            .body(r#"{"page_size":10}"#)
+            .build()
+            .unwrap();
+        assert_eq!(start_cursor(&request), None);
+    }
+
+    /// Collects the fields recorded on every span.
+    #[derive(Debug, Default, Clone)]
+    struct SpanRecorder {
+        spans: Arc<Mutex<Vec<HashMap<String, String>>>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl tracing::field::Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut fields = HashMap::new();
+            span.record(&mut FieldVisitor(&mut fields));
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(fields);
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            let fields = &mut spans[span.into_u64() as usize - 1];
+            values.record(&mut FieldVisitor(fields));
+        }
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, _event: &tracing::Event<'_>) {}
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    #[tokio::test]
+    async fn request_span_records_outcome() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                429,
+                r#"{"object":"error","status":429,"code":"rate_limited","message":"Rate limited."}"#,
+            )
+            .header("Retry-After", "0"),
+            TestResponse::json(
+                404,
+                r#"{"object":"error","status":404,"code":"object_not_found","message":"Not found.","request_id":"req-1"}"#,
+            )
+            .header("cf-ray", "ray-1"),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .retry_policy(crate::RetryPolicy::default().jitter(false))
+            .build()
+            .unwrap();
+
+        let recorder = SpanRecorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        client
+            .get_block_children()
+            .block_id("block-1")
+            .start_cursor("cursor-1")
+            .send()
+            .await
+            .unwrap_err();
+
+        let spans = recorder.spans.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+
+        let span = &spans[0];
+        assert_eq!(span["http.method"], "GET");
+        assert_eq!(span["http.path"], "/v1/blocks/{id}/children");
+        assert_eq!(span["http.status_code"], "404");
+        assert_eq!(span["notion.start_cursor"], "cursor-1");
+        assert_eq!(span["notion.retries"], "1");
+        assert_eq!(span["notion.request_id"], "req-1");
+        assert_eq!(span["notion.ray_id"], "ray-1");
+        assert!(span.contains_key("latency_ms"));
+        assert!(span.contains_key("error"));
+    }
+}
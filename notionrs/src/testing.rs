@@ -0,0 +1,609 @@
+//! Record and replay HTTP exchanges for deterministic, offline tests.
+//!
+//! A [`Cassette`] is a [`Middleware`](crate::middleware::Middleware) backed by a
+//! JSONL file with one request/response exchange per line.
+//!
+//! - In [`CassetteMode::Record`] mode, requests are sent to the Notion API as usual
+//!   and every exchange is appended to the file. The `Authorization` header and
+//!   secret fields of JSON bodies (OAuth tokens and the client secret) are
+//!   scrubbed before anything is written.
+//! - In [`CassetteMode::Replay`] mode, nothing is sent. Each request is answered
+//!   with the first unused recorded exchange that has the same method, path
+//!   (including the query string) and JSON body. Key order and whitespace in the
+//!   body don't matter.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // Record with `NOTIONRS_CASSETTE=record cargo test`, replay with `cargo test`.
+//! let cassette = notionrs::testing::Cassette::from_env("tests/cassettes/get_page.jsonl")?;
+//!
+//! let client = notionrs::Client::builder()
+//!     .notion_api_key(std::env::var("NOTION_API_KEY").unwrap_or_default())
+//!     .middleware(cassette)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::middleware::{BoxFuture, Middleware, Next};
+
+/// Environment variable read by [`Cassette::from_env`].
+pub const CASSETTE_MODE_ENV: &str = "NOTIONRS_CASSETTE";
+
+/// Placeholder written in place of the `Authorization` header and secret fields.
+const REDACTED: &str = "[REDACTED]";
+
+/// JSON object keys whose string values are scrubbed from recorded bodies.
+const SECRET_FIELDS: &[&str] = &["access_token", "refresh_token", "client_secret", "token"];
+
+/// Whether a [`Cassette`] records live exchanges or replays recorded ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests to the API and append every exchange to the cassette file.
+    Record,
+    /// Answer requests from the cassette file without network access.
+    Replay,
+}
+
+/// A record/replay [`Middleware`](crate::middleware::Middleware). See the
+/// [module documentation](self) for details.
+///
+/// Clones share the same file and replay state.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+enum State {
+    Record(std::fs::File),
+    Replay(Vec<(Exchange, bool)>),
+}
+
+/// One line of a cassette file.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Exchange {
+    /// The request as it was sent.
+    pub request: RecordedRequest,
+    /// The response that was received.
+    pub response: RecordedResponse,
+}
+
+/// A request stored in a cassette.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `POST`.
+    pub method: String,
+    /// Path and query string, e.g. `/v1/users?page_size=100`.
+    pub path: String,
+    /// Request headers with lowercase names. `Authorization` is scrubbed.
+    pub headers: std::collections::BTreeMap<String, String>,
+    /// JSON request body with secret fields scrubbed. `null` when the request had
+    /// no body or it wasn't JSON (for example a multipart file upload).
+    pub body: serde_json::Value,
+}
+
+/// A response stored in a cassette.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers with lowercase names.
+    pub headers: std::collections::BTreeMap<String, String>,
+    /// Response body as text. Secret fields are scrubbed from a JSON body.
+    pub body: String,
+}
+
+impl Cassette {
+    /// Create (or truncate) the cassette file at `path` and record into it.
+    ///
+    /// Missing parent directories are created.
+    pub fn record<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let file = std::fs::File::create(&path)?;
+
+        Ok(Self {
+            path,
+            mode: CassetteMode::Record,
+            state: Arc::new(Mutex::new(State::Record(file))),
+        })
+    }
+
+    /// Load the cassette file at `path` and replay its exchanges.
+    pub fn replay<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let contents = std::fs::read_to_string(&path)?;
+
+        let exchanges = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<Exchange>(line)
+                    .map(|exchange| (exchange, false))
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay,
+            state: Arc::new(Mutex::new(State::Replay(exchanges))),
+        })
+    }
+
+    /// [`record`](Cassette::record) when the `NOTIONRS_CASSETTE` environment
+    /// variable is `record`, [`replay`](Cassette::replay) otherwise.
+    pub fn from_env<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        match std::env::var(CASSETTE_MODE_ENV).as_deref() {
+            Ok("record") => Self::record(path),
+            _ => Self::replay(path),
+        }
+    }
+
+    /// The cassette file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether this cassette records or replays.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// The number of recorded exchanges that have not been replayed yet.
+    ///
+    /// Always `0` in [`CassetteMode::Record`] mode.
+    pub fn remaining(&self) -> usize {
+        match &*self.lock() {
+            State::Record(_) => 0,
+            State::Replay(exchanges) => exchanges.iter().filter(|(_, used)| !used).count(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    async fn record_exchange(
+        &self,
+        request: reqwest::Request,
+        next: Next<'_>,
+    ) -> Result<reqwest::Response, crate::error::Error> {
+        let recorded_request = RecordedRequest::from_request(&request);
+
+        let response = next.run(request).await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+
+        let exchange = Exchange {
+            request: recorded_request,
+            response: RecordedResponse {
+                status: status.as_u16(),
+                headers: header_map(&headers, |name| name == reqwest::header::SET_COOKIE),
+                body: recorded_response_body(&body),
+            },
+        };
+
+        {
+            use std::io::Write;
+
+            let line = serde_json::to_string(&exchange)?;
+
+            if let State::Record(file) = &mut *self.lock() {
+                writeln!(file, "{line}").map_err(|e| {
+                    crate::error::Error::Network(format!(
+                        "failed to write cassette `{}`: {e}",
+                        self.path.display()
+                    ))
+                })?;
+            }
+        }
+
+        let mut builder = http::Response::builder().status(status);
+        if let Some(response_headers) = builder.headers_mut() {
+            *response_headers = headers;
+        }
+
+        let response = builder
+            .body(body)
+            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+
+        Ok(reqwest::Response::from(response))
+    }
+
+    fn replay_exchange(
+        &self,
+        request: &reqwest::Request,
+    ) -> Result<reqwest::Response, crate::error::Error> {
+        let incoming = RecordedRequest::from_request(request);
+
+        let recorded = match &mut *self.lock() {
+            State::Replay(exchanges) => exchanges
+                .iter_mut()
+                .find(|(exchange, used)| !used && exchange.request.matches(&incoming))
+                .map(|(exchange, used)| {
+                    *used = true;
+                    exchange.response.clone()
+                }),
+            State::Record(_) => None,
+        };
+
+        let recorded = recorded.ok_or_else(|| {
+            crate::error::Error::Network(format!(
+                "no unused exchange in cassette `{}` matches {} {}",
+                self.path.display(),
+                incoming.method,
+                incoming.path
+            ))
+        })?;
+
+        let mut builder = http::Response::builder().status(recorded.status);
+        for (name, value) in &recorded.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder
+            .body(recorded.body)
+            .map_err(|e| crate::error::Error::Network(e.to_string()))?;
+
+        Ok(reqwest::Response::from(response))
+    }
+}
+
+impl Middleware for Cassette {
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<reqwest::Response, crate::error::Error>> {
+        match self.mode {
+            CassetteMode::Record => Box::pin(self.record_exchange(request, next)),
+            CassetteMode::Replay => {
+                let response = self.replay_exchange(&request);
+                Box::pin(async move { response })
+            }
+        }
+    }
+}
+
+impl RecordedRequest {
+    fn from_request(request: &reqwest::Request) -> Self {
+        let url = request.url();
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+
+        let mut headers = header_map(request.headers(), |_| false);
+        if headers.contains_key("authorization") {
+            headers.insert("authorization".to_string(), REDACTED.to_string());
+        }
+
+        let mut body = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .and_then(|bytes| serde_json::from_slice(bytes).ok())
+            .unwrap_or(serde_json::Value::Null);
+        scrub_secrets(&mut body);
+
+        Self {
+            method: request.method().to_string(),
+            path,
+            headers,
+            body,
+        }
+    }
+
+    /// Method, path and JSON body are equal. Headers are ignored.
+    fn matches(&self, other: &Self) -> bool {
+        self.method == other.method && self.path == other.path && self.body == other.body
+    }
+}
+
+/// The response body as text, re-serialized without its secret fields when it is
+/// JSON that contains any.
+fn recorded_response_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut json) => {
+            if scrub_secrets(&mut json) {
+                json.to_string()
+            } else {
+                String::from_utf8_lossy(body).into_owned()
+            }
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+/// Replaces the string values of [`SECRET_FIELDS`] anywhere in `value` with a
+/// placeholder. Returns whether anything was replaced.
+fn scrub_secrets(value: &mut serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut scrubbed = false;
+            for (key, value) in map.iter_mut() {
+                if SECRET_FIELDS.contains(&key.as_str()) && value.is_string() {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                    scrubbed = true;
+                } else {
+                    scrubbed |= scrub_secrets(value);
+                }
+            }
+            scrubbed
+        }
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .fold(false, |scrubbed, item| scrub_secrets(item) | scrubbed),
+        _ => false,
+    }
+}
+
+/// Collects headers into a map with lowercase names, skipping those `skip` selects
+/// and values that aren't valid visible ASCII.
+fn header_map(
+    headers: &reqwest::header::HeaderMap,
+    skip: impl Fn(&reqwest::header::HeaderName) -> bool,
+) -> std::collections::BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| !skip(name))
+        .filter_map(|(name, value)| {
+            Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+        })
+        .collect()
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    const USER_BODY: &str = r#"{"object":"user","id":"user-id","type":"bot","bot":{}}"#;
+
+    const QUERY_BODY: &str = r#"{"object":"list","results":[],"next_cursor":null,"has_more":false,"type":"page_or_data_source","page_or_data_source":{}}"#;
+
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("notionrs-cassette-{}", std::process::id()))
+            .join(format!("{name}.jsonl"))
+    }
+
+    #[tokio::test]
+    async fn records_then_replays_without_network() {
+        let path = cassette_path("records_then_replays_without_network");
+
+        let server = TestServer::start(vec![
+            TestResponse::json(200, USER_BODY).header("x-notion-request-id", "req-1"),
+            TestResponse::json(200, QUERY_BODY),
+        ])
+        .await;
+
+        // `with_token` sets `Authorization` per request, so the middleware sees it.
+        let cassette = Cassette::record(&path).unwrap();
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .middleware(cassette)
+            .build()
+            .unwrap()
+            .with_token("secret_token");
+
+        let recorded_user = client.get_self().send().await.unwrap();
+        client
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .page_size(10)
+            .send()
+            .await
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 2);
+        assert!(!contents.contains("secret_token"));
+        assert!(contents.contains(REDACTED));
+
+        // The replaying client points at a closed port; nothing may reach the network.
+        let cassette = Cassette::replay(&path).unwrap();
+        let client = crate::Client::builder()
+            .base_url("http://127.0.0.1:9")
+            .middleware(cassette.clone())
+            .build()
+            .unwrap();
+
+        let replayed_user = client.get_self().send().await.unwrap();
+        assert_eq!(replayed_user.id, recorded_user.id);
+
+        client
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .page_size(10)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(cassette.remaining(), 0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn records_oauth_exchanges_without_secrets() {
+        let path = cassette_path("records_oauth_exchanges_without_secrets");
+
+        let server = TestServer::start(vec![TestResponse::json(
+            200,
+            r#"{"access_token":"ntn_access","token_type":"bearer","refresh_token":"nrt_refresh","bot_id":"bot-id","workspace_name":"Acme","workspace_icon":null,"workspace_id":"workspace-id","owner":{"type":"workspace","workspace":true},"duplicated_template_id":null}"#,
+        )])
+        .await;
+
+        let cassette = Cassette::record(&path).unwrap();
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .middleware(cassette)
+            .build()
+            .unwrap();
+
+        // The caller still receives the live tokens.
+        let token = client
+            .refresh_oauth_token()
+            .client_id("client-id")
+            .client_secret("client-secret")
+            .refresh_token("nrt_old_refresh")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "ntn_access");
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        for secret in [
+            "ntn_access",
+            "nrt_refresh",
+            "nrt_old_refresh",
+            // HTTP Basic credentials for `client-id:client-secret`
+            "Y2xpZW50LWlkOmNsaWVudC1zZWNyZXQ=",
+        ] {
+            assert!(!contents.contains(secret), "`{secret}` was recorded");
+        }
+
+        // The scrubbed request body still matches on replay.
+        let cassette = Cassette::replay(&path).unwrap();
+        let client = crate::Client::builder()
+            .base_url("http://127.0.0.1:9")
+            .middleware(cassette.clone())
+            .build()
+            .unwrap();
+
+        let token = client
+            .refresh_oauth_token()
+            .client_id("client-id")
+            .client_secret("client-secret")
+            .refresh_token("nrt_old_refresh")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, REDACTED);
+        assert_eq!(cassette.remaining(), 0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn scrub_secrets_replaces_nested_secret_strings() {
+        let mut value = serde_json::json!({
+            "client_secret": "secret",
+            "results": [{ "token": "ntn_access", "title": "kept" }],
+            "access_token": null
+        });
+
+        assert!(scrub_secrets(&mut value));
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "client_secret": REDACTED,
+                "results": [{ "token": REDACTED, "title": "kept" }],
+                "access_token": null
+            })
+        );
+
+        let mut value = serde_json::json!({ "object": "user", "id": "user-id" });
+        assert!(!scrub_secrets(&mut value));
+    }
+
+    #[test]
+    fn recorded_request_scrubs_authorization() {
+        let request = reqwest::Client::new()
+            .post("https://api.notion.com/v1/search?x=1")
+            .bearer_auth("secret_token")
+            .body(r#"{ "query": "title" }"#)
+            .build()
+            .unwrap();
+
+        let recorded = RecordedRequest::from_request(&request);
+
+        assert_eq!(recorded.method, "POST");
+        assert_eq!(recorded.path, "/v1/search?x=1");
+        assert_eq!(recorded.headers["authorization"], REDACTED);
+        assert_eq!(recorded.body, serde_json::json!({ "query": "title" }));
+    }
+
+    #[tokio::test]
+    async fn replay_matches_normalized_body_and_fails_on_unknown_requests() {
+        let path = cassette_path("replay_matches_normalized_body");
+
+        let exchange = Exchange {
+            request: RecordedRequest {
+                method: "POST".to_string(),
+                path: "/v1/data_sources/data-source-id/query".to_string(),
+                headers: Default::default(),
+                body: serde_json::json!({ "page_size": 10 }),
+            },
+            response: RecordedResponse {
+                status: 200,
+                headers: [("content-type".to_string(), "application/json".to_string())]
+                    .into_iter()
+                    .collect(),
+                body: QUERY_BODY.to_string(),
+            },
+        };
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &path,
+            format!("{}\n", serde_json::to_string(&exchange).unwrap()),
+        )
+        .unwrap();
+
+        let cassette = Cassette::replay(&path).unwrap();
+        let client = crate::Client::builder()
+            .middleware(cassette.clone())
+            .build()
+            .unwrap();
+
+        client
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .page_size(10)
+            .send()
+            .await
+            .unwrap();
+
+        // Each exchange is replayed once.
+        let error = client
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .page_size(10)
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::Network(_)));
+
+        let error = client.get_self().send().await.unwrap_err();
+        assert!(error.to_string().contains("GET /v1/users/me"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
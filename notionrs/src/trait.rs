@@ -18,6 +18,10 @@ pub trait Paginate<T>: Clone + Send + 'static {
     >;
 }
 
+/// A stream of items or of whole pages, boxed so it can be stored and passed around.
+pub type PaginateStream<I> =
+    std::pin::Pin<Box<dyn futures::Stream<Item = Result<I, crate::error::Error>> + Send + 'static>>;
+
 pub trait PaginateExt<T>: Paginate<T> {
     /// Returns an async stream that yields all items across paginated responses.
     ///
@@ -37,60 +41,443 @@ pub trait PaginateExt<T>: Paginate<T> {
     ///     .await
     ///     .unwrap();
     /// ```
-    fn into_stream(
-        self,
-    ) -> std::pin::Pin<
-        Box<dyn futures::Stream<Item = Result<T, crate::error::Error>> + Send + 'static>,
-    >
+    fn into_stream(self) -> PaginateStream<T>
     where
         T: Send + 'static;
+
+    /// Returns an async stream that yields every response as a whole, including
+    /// its `next_cursor` and `request_status`.
+    fn into_page_stream(self) -> PaginateStream<notionrs_types::object::response::ListResponse<T>>
+    where
+        T: Send + 'static;
+
+    /// Paginate with the next page being fetched in the background while the
+    /// current one is consumed. See [`Pagination::prefetch`].
+    fn prefetch(self) -> Pagination<Self, T>
+    where
+        Self: Sized;
+
+    /// Paginate starting from a cursor saved from an earlier run.
+    /// See [`Pagination::resume_from`].
+    fn resume_from<S>(self, cursor: S) -> Pagination<Self, T>
+    where
+        Self: Sized,
+        S: AsRef<str>;
 }
 
 impl<T, U> PaginateExt<T> for U
 where
     U: Paginate<T> + Send + 'static,
 {
-    fn into_stream(
+    fn into_stream(self) -> PaginateStream<T>
+    where
+        T: Send + 'static,
+    {
+        Pagination::new(self).into_stream()
+    }
+
+    fn into_page_stream(self) -> PaginateStream<notionrs_types::object::response::ListResponse<T>>
+    where
+        T: Send + 'static,
+    {
+        Pagination::new(self).into_page_stream()
+    }
+
+    fn prefetch(self) -> Pagination<Self, T> {
+        Pagination::new(self).prefetch()
+    }
+
+    fn resume_from<S>(self, cursor: S) -> Pagination<Self, T>
+    where
+        S: AsRef<str>,
+    {
+        Pagination::new(self).resume_from(cursor)
+    }
+}
+
+/// Options for paginating a request, created by [`PaginateExt::prefetch`] or
+/// [`PaginateExt::resume_from`].
+///
+/// A long export can checkpoint the `next_cursor` of every page it has
+/// processed and, after a restart, continue from the last one:
+///
+/// ```no_run
+/// use futures::TryStreamExt;
+/// use notionrs::PaginateExt;
+///
+/// # async fn run(client: notionrs::Client, saved_cursor: Option<String>) -> Result<(), notionrs::Error> {
+/// let query = client.get_block_children().block_id("block-id");
+///
+/// let mut pages = match saved_cursor {
+///     Some(cursor) => query.resume_from(cursor).prefetch().into_page_stream(),
+///     None => query.prefetch().into_page_stream(),
+/// };
+///
+/// while let Some(page) = pages.try_next().await? {
+///     // ... process `page.results`, then save `page.next_cursor`.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pagination<C, T> {
+    client: C,
+    start_cursor: Option<String>,
+    prefetch: bool,
+    _item: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<C, T> Pagination<C, T>
+where
+    C: Paginate<T>,
+{
+    fn new(client: C) -> Self {
+        Self {
+            client,
+            start_cursor: None,
+            prefetch: false,
+            _item: std::marker::PhantomData,
+        }
+    }
+
+    /// Fetch the next page in the background while the current one is being
+    /// consumed. At most one request is in flight ahead of the consumer.
+    ///
+    /// The background request is spawned on the tokio runtime, so the stream
+    /// must be polled from within one.
+    pub fn prefetch(mut self) -> Self {
+        self.prefetch = true;
+        self
+    }
+
+    /// Start from `cursor`, the `next_cursor` of a page returned by an earlier
+    /// run, instead of from the first page.
+    pub fn resume_from<S>(mut self, cursor: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.start_cursor = Some(cursor.as_ref().to_string());
+        self
+    }
+
+    /// Returns an async stream that yields all items across paginated responses.
+    pub fn into_stream(self) -> PaginateStream<T>
+    where
+        T: Send + 'static,
+    {
+        use futures::TryStreamExt;
+
+        Box::pin(
+            self.into_page_stream()
+                .map_ok(|page| futures::stream::iter(page.results.into_iter().map(Ok)))
+                .try_flatten(),
+        )
+    }
+
+    /// Returns an async stream that yields every response as a whole.
+    pub fn into_page_stream(
         self,
-    ) -> std::pin::Pin<
-        Box<dyn futures::Stream<Item = Result<T, crate::error::Error>> + Send + 'static>,
-    >
+    ) -> PaginateStream<notionrs_types::object::response::ListResponse<T>>
     where
         T: Send + 'static,
     {
-        let client = self.clone();
+        let Self {
+            client,
+            start_cursor,
+            prefetch,
+            ..
+        } = self;
 
         Box::pin(futures::stream::try_unfold(
-            (client, None::<String>, true, Vec::<T>::new().into_iter()),
-            |(client, next_cursor, has_more, mut buffer)| async move {
-                if let Some(item) = buffer.next() {
-                    return Ok(Some((item, (client, next_cursor, has_more, buffer))));
-                } else if !has_more {
-                    return Ok(None);
+            (client, Some(NextPage::Cursor(start_cursor))),
+            move |(client, next_page)| async move {
+                let response = match next_page {
+                    None => return Ok(None),
+                    Some(NextPage::Cursor(cursor)) => {
+                        client
+                            .clone()
+                            .paginate_start_cursor(cursor)
+                            .paginate_send()
+                            .await?
+                    }
+                    Some(NextPage::Fetching(fetching)) => fetching.await?,
+                };
+
+                let next_page = match (&response.next_cursor, response.has_more) {
+                    (Some(cursor), Some(true)) if prefetch => {
+                        Some(NextPage::Fetching(AbortOnDrop::spawn(
+                            client
+                                .clone()
+                                .paginate_start_cursor(Some(cursor.clone()))
+                                .paginate_send(),
+                        )))
+                    }
+                    (Some(cursor), Some(true)) => Some(NextPage::Cursor(Some(cursor.clone()))),
+                    _ => None,
                 };
 
-                let response = client
-                    .clone()
-                    .paginate_start_cursor(next_cursor)
-                    .paginate_send()
-                    .await?;
+                Ok(Some((response, (client, next_page))))
+            },
+        ))
+    }
+}
+
+/// The page a [`Pagination`] stream yields next.
+enum NextPage<T> {
+    /// Not requested yet; `None` keeps the start cursor of the request builder.
+    Cursor(Option<String>),
+
+    /// Being fetched in the background.
+    Fetching(
+        AbortOnDrop<Result<notionrs_types::object::response::ListResponse<T>, crate::error::Error>>,
+    ),
+}
+
+/// A spawned task that is cancelled when the stream holding it is dropped.
+struct AbortOnDrop<O>(tokio::task::JoinHandle<O>);
+
+impl<O> AbortOnDrop<O>
+where
+    O: Send + 'static,
+{
+    fn spawn<F>(future: F) -> Self
+    where
+        F: Future<Output = O> + Send + 'static,
+    {
+        Self(tokio::spawn(future))
+    }
+}
+
+impl<O> Future for AbortOnDrop<O> {
+    type Output = O;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map(|result| match result {
+            Ok(output) => output,
+            // The task is only aborted on drop, so it can only have panicked.
+            Err(error) => std::panic::resume_unwind(error.into_panic()),
+        })
+    }
+}
+
+impl<O> Drop for AbortOnDrop<O> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::util::test_server::{TestResponse, TestServer};
+
+    fn user_list(ids: &[&str], next_cursor: Option<&str>) -> String {
+        serde_json::json!({
+            "object": "list",
+            "results": ids
+                .iter()
+                .map(|id| serde_json::json!({"object": "user", "id": id, "type": "bot", "bot": {}}))
+                .collect::<Vec<_>>(),
+            "next_cursor": next_cursor,
+            "has_more": next_cursor.is_some(),
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn into_stream_retries_the_failed_page_with_its_cursor() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &user_list(&["user-1", "user-2"], Some("cursor-2"))),
+            TestResponse::json(
+                429,
+                r#"{"object":"error","status":429,"code":"rate_limited","message":"Rate limited."}"#,
+            )
+            .header("Retry-After", "0"),
+            TestResponse::json(200, &user_list(&["user-3"], None)),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .retry_policy(crate::RetryPolicy::default())
+            .build()
+            .unwrap();
+
+        let users = client
+            .list_users()
+            .into_stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            users
+                .iter()
+                .map(|user| user.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["user-1", "user-2", "user-3"]
+        );
 
-                let mut new_buffer = response.results.into_iter();
+        let paths = server
+            .requests()
+            .await
+            .into_iter()
+            .map(|request| request.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/v1/users",
+                "/v1/users?start_cursor=cursor-2",
+                "/v1/users?start_cursor=cursor-2",
+            ]
+        );
+    }
+
+    fn client(base_url: String) -> crate::Client {
+        crate::Client::builder().base_url(base_url).build().unwrap()
+    }
 
-                let maybe_first_item = new_buffer.next();
+    #[tokio::test]
+    async fn into_page_stream_yields_whole_responses() {
+        let mut incomplete: serde_json::Value =
+            serde_json::from_str(&user_list(&["user-3"], None)).unwrap();
+        incomplete["request_status"] = serde_json::json!({
+            "type": "incomplete",
+            "incomplete_reason": "query_result_limit_reached"
+        });
 
-                let state = (
-                    client,
-                    response.next_cursor,
-                    response.has_more.unwrap_or_default(),
-                    new_buffer,
-                );
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &user_list(&["user-1", "user-2"], Some("cursor-2"))),
+            TestResponse::json(200, &incomplete.to_string()),
+        ])
+        .await;
 
-                match maybe_first_item {
-                    Some(first_item) => Ok(Some((first_item, state))),
-                    None => Ok(None),
+        let pages = client(server.base_url())
+            .list_users()
+            .into_page_stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].results.len(), 2);
+        assert_eq!(pages[0].next_cursor.as_deref(), Some("cursor-2"));
+        assert_eq!(
+            pages[1].request_status,
+            Some(
+                notionrs_types::object::response::RequestStatus::Incomplete {
+                    incomplete_reason: Some(
+                        notionrs_types::object::response::IncompleteReason::QueryResultLimitReached
+                    ),
                 }
-            },
-        ))
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn into_stream_continues_past_empty_pages() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &user_list(&[], Some("cursor-2"))),
+            TestResponse::json(200, &user_list(&["user-1"], None)),
+        ])
+        .await;
+
+        let users = client(server.base_url())
+            .list_users()
+            .into_stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].id, "user-1");
+    }
+
+    #[tokio::test]
+    async fn prefetch_requests_the_next_page_ahead() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, &user_list(&["user-1"], Some("cursor-2"))),
+            TestResponse::json(200, &user_list(&["user-2"], Some("cursor-3"))),
+            TestResponse::json(200, &user_list(&["user-3"], None)),
+        ])
+        .await;
+
+        let mut pages = client(server.base_url())
+            .list_users()
+            .prefetch()
+            .into_page_stream();
+
+        let first = pages.try_next().await.unwrap().unwrap();
+        assert_eq!(first.results[0].id, "user-1");
+
+        // The second page is requested without polling the stream again.
+        let mut requests = server.requests().await;
+        for _ in 0..100 {
+            if requests.len() == 2 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            requests = server.requests().await;
+        }
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].path, "/v1/users?start_cursor=cursor-2");
+
+        let rest = pages.try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(rest.len(), 2);
+        assert_eq!(rest[1].results[0].id, "user-3");
+        assert_eq!(server.requests().await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn resume_from_starts_at_the_saved_cursor() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                200,
+                r#"{"object":"list","results":[],"next_cursor":"cursor-3","has_more":true,"type":"block","block":{}}"#,
+            ),
+            TestResponse::json(
+                200,
+                r#"{"object":"list","results":[],"next_cursor":null,"has_more":false,"type":"block","block":{}}"#,
+            ),
+        ])
+        .await;
+
+        let pages = client(server.base_url())
+            .get_block_children()
+            .block_id("block-id")
+            .resume_from("cursor-2")
+            .into_page_stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].next_cursor.as_deref(), Some("cursor-3"));
+
+        let paths = server
+            .requests()
+            .await
+            .into_iter()
+            .map(|request| request.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/v1/blocks/block-id/children?page_size=100&start_cursor=cursor-2",
+                "/v1/blocks/block-id/children?page_size=100&start_cursor=cursor-3",
+            ]
+        );
     }
 }
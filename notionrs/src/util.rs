@@ -1,17 +1,172 @@
+/// Sends a request and returns the successful response.
+///
+/// Every attempt first waits for the client's [`RateLimiter`](crate::RateLimiter),
+/// if one is configured, and then passes through the client's
+/// [`Middleware`](crate::middleware::Middleware) chain. Transient failures are
+/// retried according to the client's [`RetryPolicy`](crate::RetryPolicy); any
+/// other non-2xx response, or the last failed attempt, is converted into
+/// [`Error::Http`](crate::Error::Http).
+///
+/// With the `tracing` feature, the whole call (all attempts) runs inside a
+/// `notion.request` span.
+pub(crate) async fn send_request(
+    config: &crate::client::ClientConfig,
+    request_builder: reqwest::RequestBuilder,
+) -> Result<reqwest::Response, crate::error::Error> {
+    let (reqwest_client, request) = request_builder.build_split();
+    let mut request = request.map_err(|e| crate::error::Error::Network(e.to_string()))?;
+
+    // Requests that carry their own credentials, such as the OAuth endpoints with
+    // HTTP Basic auth, keep them.
+    if let Some(access_token) = config.access_token.as_ref().filter(|_| {
+        !request
+            .headers()
+            .contains_key(reqwest::header::AUTHORIZATION)
+    }) {
+        let mut authorization = reqwest::header::HeaderValue::from_str(&format!(
+            "Bearer {access_token}"
+        ))
+        .map_err(|e| {
+            crate::error::Error::RequestParameter(format!(
+                "The access token is not a valid header value: {e}"
+            ))
+        })?;
+        authorization.set_sensitive(true);
+        request
+            .headers_mut()
+            .insert(reqwest::header::AUTHORIZATION, authorization);
+    }
+
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::Instrument;
+
+        let span = crate::telemetry::request_span(&request);
+        let start = std::time::Instant::now();
+
+        let (result, retries) = send_with_retries(config, &reqwest_client, request)
+            .instrument(span.clone())
+            .await;
+
+        crate::telemetry::record_outcome(&span, &result, retries, start.elapsed());
+
+        result
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    send_with_retries(config, &reqwest_client, request).await.0
+}
+
+/// Runs the attempts for one call and returns the outcome with the number of retries.
+async fn send_with_retries(
+    config: &crate::client::ClientConfig,
+    reqwest_client: &reqwest::Client,
+    request: reqwest::Request,
+) -> (Result<reqwest::Response, crate::error::Error>, u32) {
+    let mut request = request;
+    let mut attempt = 1;
+
+    let result = loop {
+        // A request whose body is a stream (multipart) can't be cloned and is sent only once.
+        let retry_request = match &config.retry_policy {
+            Some(policy) if attempt < policy.max_attempts => request.try_clone(),
+            _ => None,
+        };
+
+        if let Some(rate_limiter) = &config.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        let response = match crate::middleware::Next::new(reqwest_client, &config.middlewares)
+            .run(request)
+            .await
+        {
+            Ok(response) => response,
+            Err(error) => break Err(error),
+        };
+
+        if response.status().is_success() {
+            break Ok(response);
+        }
+
+        match (retry_request, &config.retry_policy) {
+            (Some(next_request), Some(policy))
+                if crate::retry::RetryPolicy::is_retryable_status(response.status().as_u16()) =>
+            {
+                let retry_after = crate::retry::parse_retry_after(response.headers());
+                let delay = policy.delay(attempt, retry_after);
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    status = response.status().as_u16(),
+                    attempt,
+                    delay_ms = delay.as_millis() as u64,
+                    "retrying Notion API request"
+                );
+
+                tokio::time::sleep(delay).await;
+
+                request = next_request;
+                attempt += 1;
+            }
+            _ => break Err(crate::error::Error::try_from_response_async(response).await),
+        }
+    };
+
+    (result, attempt - 1)
+}
+
+/// Builds a request the way [`send_request`] would send it, without sending it.
+///
+/// The client's default headers are added (request headers take precedence),
+/// `Authorization` is removed, and a streamed (multipart) body is read into memory.
+pub(crate) fn prepare_request(
+    config: &crate::client::ClientConfig,
+    request_builder: reqwest::RequestBuilder,
+) -> Result<crate::PreparedRequest, crate::error::Error> {
+    let mut request = request_builder
+        .build()
+        .map_err(|e| crate::error::Error::RequestParameter(e.to_string()))?;
+
+    let mut headers = config.default_headers.clone();
+    for name in request.headers().keys() {
+        headers.remove(name);
+    }
+    for (name, value) in request.headers() {
+        headers.append(name, value.clone());
+    }
+    headers.remove(reqwest::header::AUTHORIZATION);
+
+    let body = match request.body_mut().take() {
+        None => None,
+        Some(body) => match body.as_bytes() {
+            Some(bytes) => Some(bytes::Bytes::copy_from_slice(bytes)),
+            // A multipart body is a stream over parts that are already in memory,
+            // so it is ready without waiting on any I/O.
+            None => Some(
+                futures::executor::block_on(http_body_util::BodyExt::collect(body))
+                    .map_err(|e| crate::error::Error::RequestParameter(e.to_string()))?
+                    .to_bytes(),
+            ),
+        },
+    };
+
+    Ok(crate::PreparedRequest {
+        method: request.method().clone(),
+        url: request.url().clone(),
+        headers,
+        body,
+    })
+}
+
 pub async fn send_and_convert<ReturnType>(
+    config: &crate::client::ClientConfig,
     request_builder: reqwest::RequestBuilder,
 ) -> Result<ReturnType, crate::error::Error>
 where
     ReturnType: for<'a> serde::Deserialize<'a>,
 {
-    let response = request_builder
-        .send()
-        .await
-        .map_err(|e| crate::error::Error::Network(e.to_string()))?;
-
-    if !response.status().is_success() {
-        return Err(crate::error::Error::try_from_response_async(response).await);
-    }
+    let response = send_request(config, request_builder).await?;
 
     let body = response
         .bytes()
@@ -22,3 +177,472 @@ where
 
     Ok(parsed)
 }
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::test_server::{TestResponse, TestServer};
+
+    const RATE_LIMITED_BODY: &str =
+        r#"{"object":"error","status":429,"code":"rate_limited","message":"Rate limited."}"#;
+
+    const USER_BODY: &str = r#"{"object":"user","id":"user-id","type":"bot","bot":{}}"#;
+
+    fn client(server: &TestServer, retry_policy: Option<crate::RetryPolicy>) -> crate::Client {
+        let builder = crate::Client::builder().base_url(server.base_url());
+
+        match retry_policy {
+            Some(retry_policy) => builder.retry_policy(retry_policy),
+            None => builder,
+        }
+        .build()
+        .unwrap()
+    }
+
+    fn fast_retry_policy() -> crate::RetryPolicy {
+        crate::RetryPolicy::default()
+            .initial_backoff(std::time::Duration::from_millis(1))
+            .jitter(false)
+    }
+
+    #[tokio::test]
+    async fn retries_rate_limited_request_after_retry_after() {
+        let server = TestServer::start(vec![
+            TestResponse::json(429, RATE_LIMITED_BODY).header("Retry-After", "0"),
+            TestResponse::json(200, USER_BODY),
+        ])
+        .await;
+
+        let user = client(&server, Some(fast_retry_policy()))
+            .get_self()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(user.id, "user-id");
+        assert_eq!(server.requests().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_with_backoff() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                503,
+                r#"{"object":"error","status":503,"code":"service_unavailable","message":"Unavailable."}"#,
+            ),
+            TestResponse::json(
+                504,
+                r#"{"object":"error","status":504,"code":"gateway_timeout","message":"Timeout."}"#,
+            ),
+            TestResponse::json(200, USER_BODY),
+        ])
+        .await;
+
+        client(&server, Some(fast_retry_policy()))
+            .get_self()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(server.requests().await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let server = TestServer::start(vec![
+            TestResponse::json(429, RATE_LIMITED_BODY),
+            TestResponse::json(429, RATE_LIMITED_BODY),
+            TestResponse::json(200, USER_BODY),
+        ])
+        .await;
+
+        let error = client(&server, Some(fast_retry_policy().max_attempts(2)))
+            .get_self()
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, crate::Error::Http { status: 429, .. }));
+        assert_eq!(server.requests().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                400,
+                r#"{"object":"error","status":400,"code":"validation_error","message":"Invalid."}"#,
+            ),
+            TestResponse::json(200, USER_BODY),
+        ])
+        .await;
+
+        let error = client(&server, Some(fast_retry_policy()))
+            .get_self()
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, crate::Error::Http { status: 400, .. }));
+        assert_eq!(server.requests().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_without_retry_policy() {
+        let server = TestServer::start(vec![
+            TestResponse::json(429, RATE_LIMITED_BODY),
+            TestResponse::json(200, USER_BODY),
+        ])
+        .await;
+
+        let error = client(&server, None).get_self().send().await.unwrap_err();
+
+        assert!(matches!(error, crate::Error::Http { status: 429, .. }));
+        assert_eq!(server.requests().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_is_shared_by_cloned_clients_and_request_builders() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, USER_BODY),
+            TestResponse::json(200, USER_BODY),
+            TestResponse::json(200, USER_BODY),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .base_url(server.base_url())
+            .rate_limiter(crate::RateLimiter::new(20.0, 1))
+            .build()
+            .unwrap();
+        let cloned_client = client.clone();
+
+        let start = std::time::Instant::now();
+
+        let (first, second, third) = futures::join!(
+            client.get_self().send(),
+            cloned_client.get_self().send(),
+            client.clone().get_self().send(),
+        );
+        first.unwrap();
+        second.unwrap();
+        third.unwrap();
+
+        // One token is available immediately; the other two wait 50ms each.
+        assert!(start.elapsed() >= std::time::Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn with_token_overrides_authorization_per_client_view() {
+        let server = TestServer::start(vec![
+            TestResponse::json(200, USER_BODY),
+            TestResponse::json(200, USER_BODY),
+        ])
+        .await;
+
+        let client = crate::Client::builder()
+            .notion_api_key("integration-secret")
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        client
+            .with_token("workspace-token")
+            .get_self()
+            .send()
+            .await
+            .unwrap();
+        client.get_self().send().await.unwrap();
+
+        let error = client
+            .with_token("invalid\ntoken")
+            .get_self()
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::RequestParameter(_)));
+
+        let requests = server.requests().await;
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer workspace-token")
+        );
+        assert_eq!(
+            requests[1].header("authorization"),
+            Some("Bearer integration-secret")
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_json_body_unchanged() {
+        let server = TestServer::start(vec![
+            TestResponse::json(
+                409,
+                r#"{"object":"error","status":409,"code":"conflict_error","message":"Conflict."}"#,
+            ),
+            TestResponse::json(
+                200,
+                r#"{"object":"list","results":[],"next_cursor":null,"has_more":false}"#,
+            ),
+        ])
+        .await;
+
+        client(&server, Some(fast_retry_policy()))
+            .query_data_source()
+            .data_source_id("data-source-id")
+            .page_size(10)
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests().await;
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].body, requests[1].body);
+        assert_eq!(requests[1].json()["page_size"], 10);
+    }
+}
+
+/// A minimal HTTP/1.1 server for unit tests that need to inspect outgoing requests.
+///
+/// Each connection serves exactly one request, answered with the next queued
+/// [`TestResponse`](test_server::TestResponse). Once the queue is exhausted every
+/// request gets a `404 object_not_found`.
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::sync::Arc;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct TestResponse {
+        pub(crate) status: u16,
+        pub(crate) headers: Vec<(String, String)>,
+        pub(crate) body: String,
+    }
+
+    impl TestResponse {
+        pub(crate) fn json(status: u16, body: &str) -> Self {
+            Self {
+                status,
+                headers: vec![("content-type".to_string(), "application/json".to_string())],
+                body: body.to_string(),
+            }
+        }
+
+        pub(crate) fn header(mut self, name: &str, value: &str) -> Self {
+            self.headers.push((name.to_string(), value.to_string()));
+            self
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct RecordedRequest {
+        pub(crate) method: String,
+        /// Path including the query string.
+        pub(crate) path: String,
+        pub(crate) headers: Vec<(String, String)>,
+        pub(crate) body: Vec<u8>,
+    }
+
+    impl RecordedRequest {
+        pub(crate) fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+
+        pub(crate) fn json(&self) -> serde_json::Value {
+            serde_json::from_slice(&self.body).unwrap()
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct State {
+        responses: std::collections::VecDeque<TestResponse>,
+        requests: Vec<RecordedRequest>,
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct TestServer {
+        address: std::net::SocketAddr,
+        state: Arc<tokio::sync::Mutex<State>>,
+    }
+
+    impl TestServer {
+        pub(crate) async fn start(responses: Vec<TestResponse>) -> Self {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let address = listener.local_addr().unwrap();
+
+            let state = Arc::new(tokio::sync::Mutex::new(State {
+                responses: responses.into(),
+                requests: Vec::new(),
+            }));
+
+            let server_state = state.clone();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(handle_connection(stream, server_state.clone()));
+                }
+            });
+
+            Self { address, state }
+        }
+
+        pub(crate) fn base_url(&self) -> String {
+            format!("http://{}", self.address)
+        }
+
+        pub(crate) async fn requests(&self) -> Vec<RecordedRequest> {
+            self.state.lock().await.requests.clone()
+        }
+    }
+
+    /// A page object as the API returns it.
+    pub(crate) fn page_json(
+        id: &str,
+        parent: serde_json::Value,
+        properties: serde_json::Value,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "object": "page",
+            "id": id,
+            "created_time": "2026-01-01T00:00:00.000Z",
+            "last_edited_time": "2026-01-01T00:00:00.000Z",
+            "created_by": { "object": "user", "id": "user-id" },
+            "last_edited_by": { "object": "user", "id": "user-id" },
+            "cover": null,
+            "icon": null,
+            "parent": parent,
+            "archived": false,
+            "in_trash": false,
+            "is_locked": false,
+            "properties": properties,
+            "url": format!("https://www.notion.so/{id}"),
+            "public_url": null,
+        })
+    }
+
+    /// A block object as the API returns it, with `block` holding its `type`
+    /// and content.
+    pub(crate) fn block_json(
+        id: &str,
+        has_children: bool,
+        block: serde_json::Value,
+    ) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "object": "block",
+            "id": id,
+            "parent": { "type": "page_id", "page_id": "page-id" },
+            "created_time": "2026-01-01T00:00:00.000Z",
+            "last_edited_time": "2026-01-01T00:00:00.000Z",
+            "created_by": { "object": "user", "id": "user-id" },
+            "last_edited_by": { "object": "user", "id": "user-id" },
+            "has_children": has_children,
+            "archived": false,
+            "in_trash": false,
+        });
+        value
+            .as_object_mut()
+            .unwrap()
+            .extend(block.as_object().unwrap().clone());
+        value
+    }
+
+    /// A list object holding all of `results`.
+    pub(crate) fn list_json(results: Vec<serde_json::Value>) -> serde_json::Value {
+        serde_json::json!({
+            "object": "list",
+            "results": results,
+            "next_cursor": null,
+            "has_more": false,
+        })
+    }
+
+    async fn handle_connection(
+        mut stream: tokio::net::TcpStream,
+        state: Arc<tokio::sync::Mutex<State>>,
+    ) {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 4096];
+
+        let head_end = loop {
+            let Ok(read) = stream.read(&mut chunk).await else {
+                return;
+            };
+            if read == 0 {
+                return;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+            if let Some(position) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+                break position + 4;
+            }
+        };
+
+        let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+        let mut lines = head.split("\r\n");
+        let mut request_line = lines.next().unwrap_or_default().split(' ');
+        let method = request_line.next().unwrap_or_default().to_string();
+        let path = request_line.next().unwrap_or_default().to_string();
+
+        let headers = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+            .collect::<Vec<_>>();
+
+        let content_length = headers
+            .iter()
+            .find(|(name, _)| name == "content-length")
+            .and_then(|(_, value)| value.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        // Drain the body so the client never sees the connection reset mid-upload.
+        while buffer.len() < head_end + content_length {
+            let Ok(read) = stream.read(&mut chunk).await else {
+                return;
+            };
+            if read == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+
+        let body = buffer[head_end..].to_vec();
+
+        let response = {
+            let mut state = state.lock().await;
+            state.requests.push(RecordedRequest {
+                method,
+                path,
+                headers,
+                body,
+            });
+            state.responses.pop_front().unwrap_or_else(|| {
+                TestResponse::json(
+                    404,
+                    r#"{"object":"error","status":404,"code":"object_not_found","message":"No response queued."}"#,
+                )
+            })
+        };
+
+        let mut raw = format!(
+            "HTTP/1.1 {} Test\r\ncontent-length: {}\r\nconnection: close\r\n",
+            response.status,
+            response.body.len()
+        );
+        for (name, value) in &response.headers {
+            raw.push_str(&format!("{name}: {value}\r\n"));
+        }
+        raw.push_str("\r\n");
+        raw.push_str(&response.body);
+
+        let _ = stream.write_all(raw.as_bytes()).await;
+        let _ = stream.shutdown().await;
+    }
+}
@@ -0,0 +1,133 @@
+use quote::quote;
+use syn::{FnArg, ImplItem, ItemImpl, Pat, ReturnType, Type};
+
+pub fn generate_blocking(item: ItemImpl) -> proc_macro::TokenStream {
+    if let Some((path, _)) = &item.trait_ {
+        return syn::Error::new_spanned(path, "blocking can only be used on inherent impls")
+            .to_compile_error()
+            .into();
+    }
+
+    let self_ty = &item.self_ty;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    let mut errors = Vec::<syn::Error>::new();
+
+    let forwarders = item
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(method) => Some(method),
+            _ => None,
+        })
+        .filter(|method| matches!(method.vis, syn::Visibility::Public(_)))
+        .filter(|method| {
+            matches!(
+                method.sig.inputs.first(),
+                Some(FnArg::Receiver(receiver)) if matches!(receiver.kind, syn::ReceiverKind::Value)
+            )
+        })
+        .filter_map(|method| {
+            let sig = &method.sig;
+            let name = &sig.ident;
+            let generics = &sig.generics;
+            let where_clause = &sig.generics.where_clause;
+
+            let mut parameters = Vec::new();
+            let mut arguments = Vec::new();
+
+            for input in sig.inputs.iter().skip(1) {
+                let FnArg::Typed(typed) = input else {
+                    continue;
+                };
+                let Pat::Ident(ident) = typed.pat.as_ref() else {
+                    errors.push(syn::Error::new_spanned(
+                        &typed.pat,
+                        "blocking requires arguments to be plain identifiers",
+                    ));
+                    return None;
+                };
+                let argument = &ident.ident;
+                let ty = &typed.ty;
+                parameters.push(quote! { #argument: #ty });
+                arguments.push(quote! { #argument });
+            }
+
+            let attrs = method
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("deprecated"))
+                .collect::<Vec<_>>();
+
+            if sig.asyncness.is_some() {
+                let output = &sig.output;
+                return Some(quote! {
+                    #(#attrs)*
+                    #[allow(deprecated)]
+                    pub fn #name #generics (self, #(#parameters),*) #output #where_clause {
+                        self.block_on(|inner| inner.#name(#(#arguments),*))
+                    }
+                });
+            }
+
+            let (output, body) = match &sig.output {
+                ReturnType::Type(_, ty) if is_self(ty.as_ref()) => (
+                    quote! { Self },
+                    quote! { self.forward(|inner| inner.#name(#(#arguments),*)) },
+                ),
+                ReturnType::Type(_, ty) if same_builder(ty.as_ref(), self_ty) => (
+                    quote! { crate::blocking::Request<#ty> },
+                    quote! { self.forward(|inner| inner.#name(#(#arguments),*)) },
+                ),
+                // Methods that finish the builder without sending, like `build_request`.
+                ReturnType::Type(_, ty) => (
+                    quote! { #ty },
+                    quote! { self.into_inner().#name(#(#arguments),*) },
+                ),
+                ReturnType::Default => return None,
+            };
+
+            Some(quote! {
+                #(#attrs)*
+                #[allow(deprecated)]
+                pub fn #name #generics (self, #(#parameters),*) -> #output #where_clause {
+                    #body
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(error) = errors.into_iter().reduce(|mut acc, e| {
+        acc.combine(e);
+        acc
+    }) {
+        return error.to_compile_error().into();
+    }
+
+    let expanded = quote! {
+        #item
+
+        #[cfg(feature = "blocking")]
+        impl #impl_generics crate::blocking::Request<#self_ty> #where_clause {
+            #(#forwarders)*
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+fn is_self(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("Self"))
+}
+
+/// Whether `ty` is the request builder itself with other generic arguments,
+/// such as `CreatePageClient<U>` returned by `CreatePageClient::<T>::typed`.
+fn same_builder(ty: &Type, self_ty: &Type) -> bool {
+    let (Type::Path(ty), Type::Path(self_ty)) = (ty, self_ty) else {
+        return false;
+    };
+    match (ty.path.segments.last(), self_ty.path.segments.last()) {
+        (Some(ty), Some(self_ty)) => ty.ident == self_ty.ident,
+        _ => false,
+    }
+}
@@ -1,5 +1,6 @@
 use syn::DeriveInput;
 
+mod blocking;
 mod setter;
 
 #[proc_macro_derive(Setter, attributes(setter))]
@@ -7,3 +8,16 @@ pub fn derive_setter(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     let input: DeriveInput = syn::parse_macro_input!(input);
     setter::generate_setters(input)
 }
+
+/// Mirrors the builder methods and `async fn send` of a request builder's
+/// inherent impl onto `crate::blocking::Request`, with a synchronous `send`.
+///
+/// The generated impl is compiled only with the `blocking` feature.
+#[proc_macro_attribute]
+pub fn blocking(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item: syn::ItemImpl = syn::parse_macro_input!(item);
+    blocking::generate_blocking(item)
+}
@@ -41,6 +41,8 @@ pub fn generate_setters(input: DeriveInput) -> proc_macro::TokenStream {
         }
     };
 
+    let blocking = is_blocking(&input.attrs);
+
     let mut errors = Vec::<syn::Error>::new();
 
     let field_setters = fields.iter().map(|f| {
@@ -113,15 +115,64 @@ pub fn generate_setters(input: DeriveInput) -> proc_macro::TokenStream {
         return combined.unwrap().to_compile_error().into();
     }
 
+    let forwarders = if blocking {
+        let forwarders = fields
+            .iter()
+            .filter(|f| !is_skip(&f.attrs))
+            .map(generate_forwarder);
+
+        quote! {
+            #[cfg(feature = "blocking")]
+            impl #impl_generics crate::blocking::Request<#struct_name #ty_generics> #where_clause {
+                #(#forwarders)*
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
         impl #impl_generics #struct_name #ty_generics #where_clause {
             #(#setters)*
         }
+
+        #forwarders
     };
 
     proc_macro::TokenStream::from(expanded)
 }
 
+/// Generates the setter of [`crate::blocking::Request`] that forwards to the
+/// setter of the wrapped request builder.
+fn generate_forwarder(f: &syn::Field) -> proc_macro2::TokenStream {
+    let field_name = &f.ident;
+    let field_ty = &f.ty;
+
+    let comment = generate_comment(f);
+
+    let inner_ty = option_inner_ty(field_ty).filter(|_| is_option_type(field_ty));
+
+    if is_string_type(field_ty) || inner_ty.is_some_and(is_string_type) {
+        quote! {
+            #comment
+            pub fn #field_name<S>(self, #field_name: S) -> Self
+            where
+                S: AsRef<str>,
+            {
+                self.forward(|inner| inner.#field_name(#field_name))
+            }
+        }
+    } else {
+        let argument_ty = inner_ty.unwrap_or(field_ty);
+        quote! {
+            #comment
+            pub fn #field_name(self, #field_name: #argument_ty) -> Self {
+                self.forward(|inner| inner.#field_name(#field_name))
+            }
+        }
+    }
+}
+
 fn generate_comment(f: &syn::Field) -> proc_macro2::TokenStream {
     let field_name = &f.ident;
     let setter_comment = format!(
@@ -198,13 +249,23 @@ fn option_inner_ty(ty: &Type) -> Option<&Type> {
 }
 
 fn is_skip(attrs: &[syn::Attribute]) -> bool {
+    has_setter_option(attrs, "skip")
+}
+
+/// `#[setter(blocking)]` on a request builder also generates its setters on
+/// `crate::blocking::Request` when the `blocking` feature is enabled.
+fn is_blocking(attrs: &[syn::Attribute]) -> bool {
+    has_setter_option(attrs, "blocking")
+}
+
+fn has_setter_option(attrs: &[syn::Attribute], option: &str) -> bool {
     attrs.iter().any(|attr| {
         if !attr.path().is_ident("setter") {
             return false;
         }
         let mut found = false;
         let _ = attr.parse_nested_meta(|meta| {
-            if meta.path.is_ident("skip") {
+            if meta.path.is_ident(option) {
                 found = true;
             }
             Ok(())
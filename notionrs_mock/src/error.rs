@@ -0,0 +1,97 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+/// An error answered with a Notion-shaped error body:
+///
+/// ```json
+/// { "object": "error", "status": 404, "code": "object_not_found", "message": "...", "request_id": "..." }
+/// ```
+#[derive(Debug, Clone)]
+pub(crate) struct ApiError {
+    status: StatusCode,
+    code: &'static str,
+    message: String,
+}
+
+impl ApiError {
+    pub(crate) fn new<M: Into<String>>(status: StatusCode, code: &'static str, message: M) -> Self {
+        Self {
+            status,
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// `404 object_not_found`
+    pub(crate) fn not_found(object: &str, id: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "object_not_found",
+            format!(
+                "Could not find {object} with ID: {id}. Make sure the relevant pages and databases are shared with your integration."
+            ),
+        )
+    }
+
+    /// `400 validation_error`
+    pub(crate) fn validation<M: Into<String>>(message: M) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "validation_error", message)
+    }
+
+    /// `400 invalid_json`
+    pub(crate) fn invalid_json() -> Self {
+        Self::new(
+            StatusCode::BAD_REQUEST,
+            "invalid_json",
+            "Error parsing JSON body.",
+        )
+    }
+
+    /// `400 invalid_request_url`
+    pub(crate) fn invalid_request_url() -> Self {
+        Self::new(
+            StatusCode::BAD_REQUEST,
+            "invalid_request_url",
+            "Invalid request URL.",
+        )
+    }
+
+    /// `400 missing_version`
+    pub(crate) fn missing_version() -> Self {
+        Self::new(
+            StatusCode::BAD_REQUEST,
+            "missing_version",
+            "Notion-Version header failed validation: Notion-Version header should be defined, instead was `undefined`.",
+        )
+    }
+
+    /// `401 unauthorized`
+    pub(crate) fn unauthorized() -> Self {
+        Self::new(
+            StatusCode::UNAUTHORIZED,
+            "unauthorized",
+            "API token is invalid.",
+        )
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let request_id = crate::request_id();
+
+        let body = serde_json::json!({
+            "object": "error",
+            "status": self.status.as_u16(),
+            "code": self.code,
+            "message": self.message,
+            "request_id": request_id,
+        });
+
+        (
+            self.status,
+            [("x-notion-request-id", request_id)],
+            axum::Json(body),
+        )
+            .into_response()
+    }
+}
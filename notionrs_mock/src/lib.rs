@@ -0,0 +1,162 @@
+#![deny(missing_debug_implementations)]
+
+//! An in-process, in-memory mock of the Notion API for testing code built on
+//! [notionrs](https://docs.rs/notionrs) without a real workspace.
+//!
+//! [`MockServer::start`] binds a local HTTP server that implements the core
+//! endpoints on top of an in-memory store:
+//!
+//! | Resource     | Endpoints                                                                 |
+//! | ------------ | ------------------------------------------------------------------------- |
+//! | Pages        | create, retrieve, update (properties, icon, cover, trash)                 |
+//! | Blocks       | retrieve, update, delete, retrieve children, append children              |
+//! | Data sources | create, retrieve, query (common filters and sorts)                        |
+//! | Search       | by title, filtered by object type, sorted by last edited time             |
+//! | Comments     | create, list, retrieve, update, delete                                    |
+//! | Users        | list, retrieve, retrieve the bot user (`/v1/users/me`)                    |
+//! | File uploads | create, send (single and multi-part), complete, retrieve, list            |
+//!
+//! Responses are shaped like Notion's and deserialize into the `notionrs_types`
+//! schema types. Lists are paginated with `next_cursor`/`has_more`, and failures
+//! are answered with Notion-shaped error bodies such as `404 object_not_found`
+//! or `400 validation_error`. Requests without a `Notion-Version` or
+//! `Authorization` header are rejected the way Notion rejects them.
+//!
+//! The store starts with a bot user and a root page shared with the
+//! integration ([`MockServer::root_page_id`]) that pages can be created under.
+//! Data sources can be created with any `database_id`.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = notionrs_mock::MockServer::start().await;
+//!
+//! let client = notionrs::Client::builder()
+//!     .notion_api_key("secret_mock")
+//!     .base_url(server.base_url())
+//!     .build()?;
+//!
+//! let user = client.get_self().send().await?;
+//! assert_eq!(user.id, server.bot_id());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+mod error;
+mod pagination;
+mod properties;
+mod query;
+mod rich_text;
+mod routes;
+mod store;
+
+/// A running mock Notion API server. It shuts down when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    base_url: String,
+    store: Arc<Mutex<store::Store>>,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Start a server on a random local port.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no local port can be bound, or when called outside a Tokio runtime.
+    pub async fn start() -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind a local port for the mock server");
+        let address = listener
+            .local_addr()
+            .expect("failed to read the mock server address");
+        let base_url = format!("http://{address}");
+
+        let mut store = store::Store::new();
+        store.base_url = base_url.clone();
+        let store = Arc::new(Mutex::new(store));
+
+        let router = routes::router(store.clone());
+        let (shutdown, shutdown_signal) = tokio::sync::oneshot::channel::<()>();
+
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown_signal.await;
+                })
+                .await;
+        });
+
+        Self {
+            base_url,
+            store,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// The URL to pass to `ClientBuilder::base_url`, e.g. `http://127.0.0.1:49152`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Only accept requests with `Authorization: Bearer <token>`. Other tokens are
+    /// answered with `401 unauthorized`. By default any token is accepted.
+    pub fn require_token<T: AsRef<str>>(&self, token: T) {
+        self.lock().token = Some(token.as_ref().to_string());
+    }
+
+    /// The ID of the integration's bot user, returned by `GET /v1/users/me`.
+    pub fn bot_id(&self) -> String {
+        self.lock().bot_id.clone()
+    }
+
+    /// The ID of a page shared with the integration, to create pages under.
+    pub fn root_page_id(&self) -> String {
+        self.lock().root_page_id.clone()
+    }
+
+    /// Add a person to the workspace and return their user ID.
+    pub fn add_user<N, E>(&self, name: N, email: E) -> String
+    where
+        N: AsRef<str>,
+        E: AsRef<str>,
+    {
+        let mut store = self.lock();
+        let id = store.new_id();
+
+        store.users.push(serde_json::json!({
+            "object": "user",
+            "id": id,
+            "name": name.as_ref(),
+            "avatar_url": null,
+            "type": "person",
+            "person": { "email": email.as_ref() }
+        }));
+
+        id
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, store::Store> {
+        self.store
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// A new value for `request_id` fields and the `x-notion-request-id` header.
+pub(crate) fn request_id() -> String {
+    static NEXT: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+
+    let next = NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    format!("00000000-0000-4000-9000-{next:012x}")
+}
@@ -0,0 +1,100 @@
+use serde_json::{Value, json};
+
+use crate::error::ApiError;
+
+/// The largest `page_size` Notion accepts, also used when none is given.
+pub(crate) const MAX_PAGE_SIZE: u64 = 100;
+
+/// `start_cursor` and `page_size` of a list request.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PageParams {
+    pub(crate) start_cursor: Option<String>,
+    pub(crate) page_size: Option<u64>,
+}
+
+impl PageParams {
+    /// Reads the parameters from the query string of a `GET` request.
+    pub(crate) fn from_query(
+        query: &std::collections::HashMap<String, String>,
+    ) -> Result<Self, ApiError> {
+        let page_size = query
+            .get("page_size")
+            .map(|page_size| {
+                page_size.parse::<u64>().map_err(|_| {
+                    ApiError::validation(format!(
+                        "page_size should be a number, instead was `{page_size}`."
+                    ))
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            start_cursor: query.get("start_cursor").cloned(),
+            page_size,
+        })
+    }
+
+    /// Reads the parameters from the JSON body of a `POST` request.
+    pub(crate) fn from_body(body: &Value) -> Result<Self, ApiError> {
+        let page_size = match &body["page_size"] {
+            Value::Null => None,
+            value => Some(value.as_u64().ok_or_else(|| {
+                ApiError::validation(format!(
+                    "body.page_size should be a number, instead was `{value}`."
+                ))
+            })?),
+        };
+
+        Ok(Self {
+            start_cursor: body["start_cursor"].as_str().map(str::to_string),
+            page_size,
+        })
+    }
+}
+
+/// Builds a Notion list response from `(id, object)` pairs.
+///
+/// Like Notion, the cursor of the next page is the ID of its first item.
+pub(crate) fn list(
+    items: Vec<(String, Value)>,
+    list_type: &str,
+    params: &PageParams,
+) -> Result<Value, ApiError> {
+    let page_size = match params.page_size {
+        None => MAX_PAGE_SIZE,
+        Some(page_size) if (1..=MAX_PAGE_SIZE).contains(&page_size) => page_size,
+        Some(page_size) => {
+            return Err(ApiError::validation(format!(
+                "page_size should be between 1 and {MAX_PAGE_SIZE}, instead was `{page_size}`."
+            )));
+        }
+    } as usize;
+
+    let start = match &params.start_cursor {
+        None => 0,
+        Some(cursor) => items
+            .iter()
+            .position(|(id, _)| crate::store::same_id(id, cursor))
+            .ok_or_else(|| {
+                ApiError::validation(format!("The start_cursor provided is invalid: {cursor}"))
+            })?,
+    };
+
+    let end = (start + page_size).min(items.len());
+    let next_cursor = items.get(end).map(|(id, _)| id.clone());
+
+    let results = items[start..end]
+        .iter()
+        .map(|(_, object)| object.clone())
+        .collect::<Vec<_>>();
+
+    Ok(json!({
+        "object": "list",
+        "results": results,
+        "next_cursor": next_cursor,
+        "has_more": next_cursor.is_some(),
+        "type": list_type,
+        list_type: {},
+        "request_id": crate::request_id(),
+    }))
+}
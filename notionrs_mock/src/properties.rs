@@ -0,0 +1,318 @@
+use serde_json::{Map, Value, json};
+
+use crate::error::ApiError;
+use crate::store::{Page, Store, format_time};
+
+/// Property types whose values Notion computes; they can't be set on pages.
+const COMPUTED: [&str; 9] = [
+    "button",
+    "created_by",
+    "created_time",
+    "formula",
+    "last_edited_by",
+    "last_edited_time",
+    "rollup",
+    "unique_id",
+    "verification",
+];
+
+/// The type of a property in a request: its `type`, or else the one key
+/// besides `id`, `name`, `description` and `type`.
+fn property_type(property: &Map<String, Value>) -> Option<String> {
+    match property.get("type").and_then(Value::as_str) {
+        Some(r#type) => Some(r#type.to_string()),
+        None => property
+            .keys()
+            .find(|key| !matches!(key.as_str(), "id" | "name" | "description" | "type"))
+            .cloned(),
+    }
+}
+
+/// Builds a data source schema from the `properties` of a create request.
+///
+/// Each property gets an `id`, `name` and `type`, and select-like options get
+/// IDs and colors. Exactly one property must be a `title`.
+pub(crate) fn build_schema(
+    store: &mut Store,
+    input: &Value,
+) -> Result<Map<String, Value>, ApiError> {
+    let input = input
+        .as_object()
+        .ok_or_else(|| ApiError::validation("body.properties should be an object."))?;
+
+    let mut schema = Map::new();
+
+    for (name, property) in input {
+        let property = property.as_object().ok_or_else(|| {
+            ApiError::validation(format!("body.properties.{name} should be an object."))
+        })?;
+
+        let r#type = property_type(property).ok_or_else(|| {
+            ApiError::validation(format!(
+                "body.properties.{name} should define a property type."
+            ))
+        })?;
+
+        let id = if r#type == "title" {
+            "title".to_string()
+        } else {
+            let id = store.new_id();
+            id[id.len() - 4..].to_string()
+        };
+
+        let mut config = property.get(&r#type).cloned().unwrap_or_else(|| json!({}));
+
+        if matches!(r#type.as_str(), "select" | "multi_select" | "status") {
+            let options = config["options"].as_array().cloned().unwrap_or_default();
+            let options = match (r#type.as_str(), options.is_empty()) {
+                ("status", true) => vec![
+                    json!({ "name": "Not started", "color": "default" }),
+                    json!({ "name": "In progress", "color": "blue" }),
+                    json!({ "name": "Done", "color": "green" }),
+                ],
+                _ => options,
+            };
+
+            config["options"] = Value::Array(
+                options
+                    .into_iter()
+                    .map(|option| new_option(store, option))
+                    .collect(),
+            );
+        }
+
+        if r#type == "unique_id" && config["prefix"].is_null() {
+            config["prefix"] = Value::Null;
+        }
+
+        let description = property.get("description").cloned();
+
+        let mut property = json!({
+            "id": id,
+            "name": name,
+            "type": r#type,
+            r#type.clone(): config,
+        });
+        if let Some(description) = description {
+            property["description"] = description;
+        }
+
+        serde_json::from_value::<notionrs_types::object::data_source::DataSourceProperty>(
+            property.clone(),
+        )
+        .map_err(|e| ApiError::validation(format!("body.properties.{name} is invalid: {e}")))?;
+
+        schema.insert(name.clone(), property);
+    }
+
+    let titles = schema
+        .values()
+        .filter(|property| property["type"] == "title")
+        .count();
+    if titles != 1 {
+        return Err(ApiError::validation(format!(
+            "body.properties should contain exactly one title property, instead contained {titles}."
+        )));
+    }
+
+    Ok(schema)
+}
+
+/// A select option with an `id` and a `color`.
+fn new_option(store: &mut Store, mut option: Value) -> Value {
+    if option["id"].is_null() {
+        option["id"] = Value::String(store.new_id());
+    }
+    if option["color"].is_null() {
+        option["color"] = Value::String("default".to_string());
+    }
+    option
+}
+
+/// Finds the schema entry for a property given by name or by ID.
+pub(crate) fn find_in_schema<'a>(schema: &'a Map<String, Value>, key: &str) -> Option<&'a str> {
+    schema
+        .iter()
+        .find(|(name, property)| name.as_str() == key || property["id"] == key)
+        .map(|(name, _)| name.as_str())
+}
+
+/// Turns a property value from a request into the stored `PageProperty` shape,
+/// `{"id": ..., "type": ..., "<type>": ...}`.
+///
+/// Unknown select and multi-select options are added to `schema_property`, as
+/// Notion does.
+pub(crate) fn normalize_value(
+    store: &mut Store,
+    name: &str,
+    schema_property: &mut Value,
+    value: &Value,
+) -> Result<Value, ApiError> {
+    let r#type = schema_property["type"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+
+    if let Some(given) = value
+        .as_object()
+        .and_then(property_type)
+        .filter(|given| *given != r#type)
+    {
+        return Err(ApiError::validation(format!(
+            "{name} is expected to be {type}, instead was `{given}`.",
+            type = r#type
+        )));
+    }
+
+    if COMPUTED.contains(&r#type.as_str()) {
+        return Err(ApiError::validation(format!(
+            "{name} is a {type} property, whose value can't be set.",
+            type = r#type
+        )));
+    }
+
+    let raw = &value[&r#type];
+
+    let normalized = match r#type.as_str() {
+        "title" | "rich_text" => crate::rich_text::normalize(raw, &format!("properties.{name}"))?,
+        "select" => match raw {
+            Value::Null => Value::Null,
+            option => resolve_option(store, name, schema_property, option, true)?,
+        },
+        "status" => resolve_option(store, name, schema_property, raw, false)?,
+        "multi_select" => Value::Array(
+            raw.as_array()
+                .ok_or_else(|| {
+                    ApiError::validation(format!("{name}.multi_select should be an array."))
+                })?
+                .iter()
+                .map(|option| resolve_option(store, name, schema_property, option, true))
+                .collect::<Result<_, _>>()?,
+        ),
+        "people" => Value::Array(
+            raw.as_array()
+                .ok_or_else(|| ApiError::validation(format!("{name}.people should be an array.")))?
+                .iter()
+                .map(|user| {
+                    let id = user["id"].as_str().unwrap_or_default();
+                    store
+                        .user(id)
+                        .cloned()
+                        .ok_or_else(|| ApiError::not_found("user", id))
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        "date" => match raw {
+            Value::Null => Value::Null,
+            date => json!({
+                "start": date["start"],
+                "end": date["end"],
+                "time_zone": date["time_zone"],
+            }),
+        },
+        _ => raw.clone(),
+    };
+
+    let mut property = json!({
+        "id": schema_property["id"],
+        "type": r#type,
+        r#type.clone(): normalized,
+    });
+    if r#type == "relation" {
+        property["has_more"] = Value::Bool(false);
+    }
+
+    serde_json::from_value::<notionrs_types::object::page::PageProperty>(property.clone())
+        .map_err(|e| ApiError::validation(format!("{name} is invalid: {e}")))?;
+
+    Ok(property)
+}
+
+/// Looks up a select, multi-select or status option by `id` or `name`,
+/// creating it if allowed.
+fn resolve_option(
+    store: &mut Store,
+    name: &str,
+    schema_property: &mut Value,
+    option: &Value,
+    create: bool,
+) -> Result<Value, ApiError> {
+    let r#type = schema_property["type"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+
+    let existing = schema_property[&r#type]["options"]
+        .as_array()
+        .and_then(|options| {
+            options.iter().find(|candidate| {
+                (!option["id"].is_null() && candidate["id"] == option["id"])
+                    || (!option["name"].is_null() && candidate["name"] == option["name"])
+            })
+        })
+        .cloned();
+
+    match existing {
+        Some(existing) => Ok(existing),
+        None if create && option["name"].is_string() => {
+            let option = new_option(
+                store,
+                json!({ "name": option["name"], "color": option["color"] }),
+            );
+            if let Some(options) = schema_property[&r#type]["options"].as_array_mut() {
+                options.push(option.clone());
+            }
+            Ok(option)
+        }
+        None => Err(ApiError::validation(format!(
+            "{name} has no {type} option matching `{option}`.",
+            type = r#type
+        ))),
+    }
+}
+
+/// The value of a property the page doesn't store: computed values, or the
+/// empty value of its type.
+pub(crate) fn default_value(store: &Store, page: &Page, schema_property: &Value) -> Value {
+    let r#type = schema_property["type"].as_str().unwrap_or_default();
+    let config = &schema_property[r#type];
+
+    let value = match r#type {
+        "title" | "rich_text" | "multi_select" | "people" | "files" | "relation" => json!([]),
+        "checkbox" => json!(false),
+        "status" => config["options"][0].clone(),
+        "created_time" => json!(format_time(page.created_time)),
+        "last_edited_time" => json!(format_time(page.last_edited_time)),
+        "created_by" | "last_edited_by" => store.bot_reference(),
+        "unique_id" => json!({ "prefix": config["prefix"], "number": page.number }),
+        "formula" => json!({ "type": "string", "string": null }),
+        "rollup" => json!({
+            "type": "incomplete",
+            "incomplete": {},
+            "function": config["function"].as_str().unwrap_or("show_original"),
+        }),
+        "button" => json!({}),
+        "verification" => json!({ "state": "unverified", "verified_by": null, "date": null }),
+        _ => Value::Null,
+    };
+
+    let mut property = json!({
+        "id": schema_property["id"],
+        "type": r#type,
+        r#type: value,
+    });
+    if r#type == "relation" {
+        property["has_more"] = Value::Bool(false);
+    }
+
+    property
+}
+
+/// The plain text title of a page.
+pub(crate) fn page_title(page: &Page) -> String {
+    page.properties
+        .values()
+        .find(|property| property["type"] == "title")
+        .map(|property| crate::rich_text::plain_text(&property["title"]))
+        .unwrap_or_default()
+}
@@ -0,0 +1,584 @@
+//! Filters and sorts of `POST /v1/data_sources/{id}/query`, evaluated against
+//! rendered pages.
+
+use std::cmp::Ordering;
+
+use notionrs_types::object::request::filter::{
+    Condition, DateOrRelativeDate, Filter, RelativeDateValue, StringOrStringArray,
+};
+use serde_json::Value;
+
+use crate::error::ApiError;
+
+fn unsupported(what: &str) -> ApiError {
+    ApiError::validation(format!("notionrs_mock does not support {what}."))
+}
+
+/// Parses the `filter` of a query body.
+pub(crate) fn parse_filter(value: &Value) -> Result<Option<Filter>, ApiError> {
+    match value {
+        Value::Null => Ok(None),
+        value => serde_json::from_value::<Filter>(value.clone())
+            .map(Some)
+            .map_err(|e| ApiError::validation(format!("body.filter is invalid: {e}"))),
+    }
+}
+
+/// Whether a rendered page matches `filter`.
+pub(crate) fn matches(filter: &Filter, page: &Value) -> Result<bool, ApiError> {
+    if let Some(filters) = &filter.and {
+        for filter in filters {
+            if !matches(filter, page)? {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+
+    if let Some(filters) = &filter.or {
+        for filter in filters {
+            if matches(filter, page)? {
+                return Ok(true);
+            }
+        }
+        return Ok(false);
+    }
+
+    let Some(condition) = &filter.condition else {
+        return Err(unsupported("this filter"));
+    };
+
+    if let Some(timestamp) = &filter.timestamp {
+        return match (timestamp.as_str(), condition) {
+            ("created_time", Condition::Timestamp(condition))
+            | ("last_edited_time", Condition::LastEditedTime(condition)) => date_matches(
+                &page[timestamp.as_str()],
+                &condition.equals,
+                &condition.before,
+                &condition.after,
+                &condition.on_or_before,
+                &condition.on_or_after,
+                condition.is_empty,
+                condition.is_not_empty,
+                relative_ranges_set(condition),
+            ),
+            (timestamp, _) => Err(unsupported(&format!("`{timestamp}` timestamp filters"))),
+        };
+    }
+
+    let property = filter.property.as_deref().ok_or_else(|| {
+        ApiError::validation("body.filter should define `property` or `timestamp`.")
+    })?;
+
+    let value = page["properties"]
+        .as_object()
+        .and_then(|properties| {
+            properties
+                .get(property)
+                .or_else(|| properties.values().find(|value| value["id"] == property))
+        })
+        .ok_or_else(|| {
+            ApiError::validation(format!(
+                "Could not find property with name or id: {property}"
+            ))
+        })?;
+
+    let r#type = value["type"].as_str().unwrap_or_default();
+    let inner = &value[r#type];
+
+    match condition {
+        Condition::Checkbox(condition) => Ok(condition
+            .equals
+            .is_none_or(|equals| inner.as_bool() == Some(equals))),
+
+        Condition::Number(condition) => {
+            let number = inner.as_f64();
+            Ok(number_matches(
+                number,
+                condition.equals,
+                condition.does_not_equal,
+                condition.greater_than,
+                condition.greater_than_or_equal_to,
+                condition.less_than,
+                condition.less_than_or_equal_to,
+                condition.is_empty,
+                condition.is_not_empty,
+            ))
+        }
+
+        Condition::UniqueId(condition) => {
+            let number = inner["number"].as_f64();
+            let to_f64 = |value: Option<u64>| value.map(|value| value as f64);
+            Ok(number_matches(
+                number,
+                to_f64(condition.equals),
+                to_f64(condition.does_not_equal),
+                to_f64(condition.greater_than),
+                to_f64(condition.greater_than_or_equal_to),
+                to_f64(condition.less_than),
+                to_f64(condition.less_than_or_equal_to),
+                None,
+                None,
+            ))
+        }
+
+        Condition::RichText(condition) => Ok(text_matches(
+            &text_of(r#type, inner),
+            &condition.equals,
+            &condition.does_not_equal,
+            &condition.contains,
+            &condition.does_not_contain,
+            &condition.starts_with,
+            &condition.ends_with,
+            condition.is_empty,
+            condition.is_not_empty,
+        )),
+
+        Condition::PhoneNumber(condition) => Ok(text_matches(
+            &text_of(r#type, inner),
+            &condition.equals,
+            &condition.does_not_equal,
+            &condition.contains,
+            &condition.does_not_contain,
+            &condition.starts_with,
+            &condition.ends_with,
+            condition.is_empty,
+            condition.is_not_empty,
+        )),
+
+        Condition::Select(condition) => Ok(option_matches(
+            inner["name"].as_str(),
+            &condition.equals,
+            &condition.does_not_equal,
+            condition.is_empty,
+            condition.is_not_empty,
+        )),
+
+        Condition::Status(condition) => Ok(option_matches(
+            inner["name"].as_str(),
+            &condition.equals,
+            &condition.does_not_equal,
+            condition.is_empty,
+            condition.is_not_empty,
+        )),
+
+        Condition::MultiSelect(condition) => {
+            let names = inner
+                .as_array()
+                .map(|options| {
+                    options
+                        .iter()
+                        .filter_map(|option| option["name"].as_str())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            let contains = |expected: &StringOrStringArray| {
+                strings(expected)
+                    .iter()
+                    .any(|expected| names.contains(&expected.as_str()))
+            };
+
+            Ok(condition.contains.as_ref().is_none_or(contains)
+                && condition
+                    .does_not_contain
+                    .as_ref()
+                    .is_none_or(|expected| !contains(expected))
+                && empty_matches(names.is_empty(), condition.is_empty, condition.is_not_empty))
+        }
+
+        Condition::People(condition) => Ok(ids_match(
+            inner,
+            &condition.contains,
+            &condition.does_not_contain,
+            condition.is_empty,
+            condition.is_not_empty,
+        )),
+
+        Condition::Relation(condition) => Ok(ids_match(
+            inner,
+            &condition.contains,
+            &condition.does_not_contain,
+            condition.is_empty,
+            condition.is_not_empty,
+        )),
+
+        Condition::Files(condition) => Ok(empty_matches(
+            inner.as_array().is_none_or(Vec::is_empty),
+            condition.is_empty,
+            condition.is_not_empty,
+        )),
+
+        Condition::Date(condition) => {
+            let date = match r#type {
+                "date" => &inner["start"],
+                _ => inner,
+            };
+            date_matches(
+                date,
+                &condition.equals,
+                &condition.before,
+                &condition.after,
+                &condition.on_or_before,
+                &condition.on_or_after,
+                condition.is_empty,
+                condition.is_not_empty,
+                condition.past_week.is_some()
+                    || condition.past_month.is_some()
+                    || condition.past_year.is_some()
+                    || condition.this_week.is_some()
+                    || condition.next_week.is_some()
+                    || condition.next_month.is_some()
+                    || condition.next_year.is_some(),
+            )
+        }
+
+        Condition::Timestamp(_) => Err(ApiError::validation(
+            "body.filter with `created_time` should define `timestamp`.",
+        )),
+        Condition::LastEditedTime(_) => Err(ApiError::validation(
+            "body.filter with `last_edited_time` should define `timestamp`.",
+        )),
+
+        Condition::Formula(_) => Err(unsupported("formula filters")),
+        Condition::Rollup(_) => Err(unsupported("rollup filters")),
+        Condition::Verification(_) => Err(unsupported("verification filters")),
+    }
+}
+
+fn relative_ranges_set(
+    condition: &notionrs_types::object::request::filter::TimestampFilter,
+) -> bool {
+    condition.past_week.is_some()
+        || condition.past_month.is_some()
+        || condition.past_year.is_some()
+        || condition.this_week.is_some()
+        || condition.next_week.is_some()
+        || condition.next_month.is_some()
+        || condition.next_year.is_some()
+}
+
+/// The text a rich text filter applies to.
+fn text_of(r#type: &str, inner: &Value) -> String {
+    match r#type {
+        "title" | "rich_text" => crate::rich_text::plain_text(inner),
+        _ => inner.as_str().unwrap_or_default().to_string(),
+    }
+}
+
+fn empty_matches(empty: bool, is_empty: Option<bool>, is_not_empty: Option<bool>) -> bool {
+    is_empty.is_none_or(|is_empty| !is_empty || empty)
+        && is_not_empty.is_none_or(|is_not_empty| !is_not_empty || !empty)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn number_matches(
+    number: Option<f64>,
+    equals: Option<f64>,
+    does_not_equal: Option<f64>,
+    greater_than: Option<f64>,
+    greater_than_or_equal_to: Option<f64>,
+    less_than: Option<f64>,
+    less_than_or_equal_to: Option<f64>,
+    is_empty: Option<bool>,
+    is_not_empty: Option<bool>,
+) -> bool {
+    let compare = |expected: Option<f64>, accept: fn(f64, f64) -> bool| {
+        expected.is_none_or(|expected| number.is_some_and(|number| accept(number, expected)))
+    };
+
+    compare(equals, |a, b| a == b)
+        && does_not_equal.is_none_or(|expected| number != Some(expected))
+        && compare(greater_than, |a, b| a > b)
+        && compare(greater_than_or_equal_to, |a, b| a >= b)
+        && compare(less_than, |a, b| a < b)
+        && compare(less_than_or_equal_to, |a, b| a <= b)
+        && empty_matches(number.is_none(), is_empty, is_not_empty)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn text_matches(
+    text: &str,
+    equals: &Option<String>,
+    does_not_equal: &Option<String>,
+    contains: &Option<String>,
+    does_not_contain: &Option<String>,
+    starts_with: &Option<String>,
+    ends_with: &Option<String>,
+    is_empty: Option<bool>,
+    is_not_empty: Option<bool>,
+) -> bool {
+    // Notion compares text case-insensitively.
+    let text = text.to_lowercase();
+    let check = |expected: &Option<String>, accept: &dyn Fn(&str, &str) -> bool| {
+        expected
+            .as_ref()
+            .is_none_or(|expected| accept(&text, &expected.to_lowercase()))
+    };
+
+    check(equals, &|a, b| a == b)
+        && check(does_not_equal, &|a, b| a != b)
+        && check(contains, &|a, b| a.contains(b))
+        && check(does_not_contain, &|a, b| !a.contains(b))
+        && check(starts_with, &|a, b| a.starts_with(b))
+        && check(ends_with, &|a, b| a.ends_with(b))
+        && empty_matches(text.is_empty(), is_empty, is_not_empty)
+}
+
+fn strings(value: &StringOrStringArray) -> Vec<String> {
+    match value {
+        StringOrStringArray::String(value) => vec![value.clone()],
+        StringOrStringArray::Array(values) => values.clone(),
+    }
+}
+
+fn option_matches(
+    name: Option<&str>,
+    equals: &Option<StringOrStringArray>,
+    does_not_equal: &Option<StringOrStringArray>,
+    is_empty: Option<bool>,
+    is_not_empty: Option<bool>,
+) -> bool {
+    let any = |expected: &StringOrStringArray| {
+        strings(expected)
+            .iter()
+            .any(|expected| Some(expected.as_str()) == name)
+    };
+
+    equals.as_ref().is_none_or(any)
+        && does_not_equal
+            .as_ref()
+            .is_none_or(|expected| !any(expected))
+        && empty_matches(name.is_none(), is_empty, is_not_empty)
+}
+
+fn ids_match(
+    inner: &Value,
+    contains: &Option<String>,
+    does_not_contain: &Option<String>,
+    is_empty: Option<bool>,
+    is_not_empty: Option<bool>,
+) -> bool {
+    let ids = inner
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item["id"].as_str())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let has = |id: &String| {
+        ids.iter()
+            .any(|candidate| crate::store::same_id(candidate, id))
+    };
+
+    contains.as_ref().is_none_or(has)
+        && does_not_contain.as_ref().is_none_or(|id| !has(id))
+        && empty_matches(ids.is_empty(), is_empty, is_not_empty)
+}
+
+/// A date or date-time from a page or a filter. Date-only values are `true`
+/// in the second field.
+fn parse_date(value: &str) -> Option<(time::OffsetDateTime, bool)> {
+    if let Ok(date_time) =
+        time::OffsetDateTime::parse(value, &time::format_description::well_known::Rfc3339)
+    {
+        return Some((date_time, false));
+    }
+
+    let format = time::macros::format_description!("[year]-[month]-[day]");
+    time::Date::parse(value.get(..10)?, &format)
+        .ok()
+        .map(|date| (date.midnight().assume_utc(), value.len() == 10))
+}
+
+fn resolve(value: &DateOrRelativeDate) -> Result<(time::OffsetDateTime, bool), ApiError> {
+    match value {
+        DateOrRelativeDate::Date(value) => parse_date(value).ok_or_else(|| {
+            ApiError::validation(format!("`{value}` is not a valid ISO 8601 date."))
+        }),
+        DateOrRelativeDate::Relative(relative) => {
+            let today = time::OffsetDateTime::now_utc()
+                .date()
+                .midnight()
+                .assume_utc();
+            let date = match relative {
+                RelativeDateValue::Today => today,
+                RelativeDateValue::Tomorrow => today + time::Duration::DAY,
+                RelativeDateValue::Yesterday => today - time::Duration::DAY,
+                RelativeDateValue::OneWeekAgo => today - time::Duration::WEEK,
+                RelativeDateValue::OneWeekFromNow => today + time::Duration::WEEK,
+                RelativeDateValue::OneMonthAgo => today - time::Duration::days(30),
+                RelativeDateValue::OneMonthFromNow => today + time::Duration::days(30),
+            };
+            Ok((date, true))
+        }
+    }
+}
+
+/// Compares a page date with a filter date, by day when the filter is date-only.
+fn compare(date: time::OffsetDateTime, filter: &DateOrRelativeDate) -> Result<Ordering, ApiError> {
+    let (expected, date_only) = resolve(filter)?;
+
+    Ok(if date_only {
+        date.to_offset(time::UtcOffset::UTC)
+            .date()
+            .cmp(&expected.date())
+    } else {
+        date.cmp(&expected)
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn date_matches(
+    value: &Value,
+    equals: &Option<DateOrRelativeDate>,
+    before: &Option<DateOrRelativeDate>,
+    after: &Option<DateOrRelativeDate>,
+    on_or_before: &Option<DateOrRelativeDate>,
+    on_or_after: &Option<DateOrRelativeDate>,
+    is_empty: Option<bool>,
+    is_not_empty: Option<bool>,
+    relative_ranges: bool,
+) -> Result<bool, ApiError> {
+    if relative_ranges {
+        return Err(unsupported(
+            "`past_week`, `this_week` and similar relative date ranges",
+        ));
+    }
+
+    let date = value.as_str().and_then(parse_date).map(|(date, _)| date);
+
+    let checks = [
+        (equals, Ordering::is_eq as fn(Ordering) -> bool),
+        (before, Ordering::is_lt),
+        (after, Ordering::is_gt),
+        (on_or_before, Ordering::is_le),
+        (on_or_after, Ordering::is_ge),
+    ];
+
+    for (expected, accept) in checks {
+        if let Some(expected) = expected {
+            match date {
+                Some(date) if accept(compare(date, expected)?) => {}
+                _ => return Ok(false),
+            }
+        }
+    }
+
+    Ok(empty_matches(date.is_none(), is_empty, is_not_empty))
+}
+
+/// Orders rendered pages by the `sorts` of a query body. Without sorts, pages
+/// are ordered by creation time, newest first.
+pub(crate) fn sort(pages: &mut [Value], sorts: &Value) -> Result<(), ApiError> {
+    let sorts = match sorts {
+        Value::Null => Vec::new(),
+        Value::Array(sorts) => sorts.clone(),
+        _ => return Err(ApiError::validation("body.sorts should be an array.")),
+    };
+
+    if sorts.is_empty() {
+        pages.sort_by(|a, b| b["created_time"].as_str().cmp(&a["created_time"].as_str()));
+        return Ok(());
+    }
+
+    let mut keys = Vec::new();
+    for sort in &sorts {
+        let descending = match sort["direction"].as_str() {
+            Some("ascending") | None => false,
+            Some("descending") => true,
+            Some(direction) => {
+                return Err(ApiError::validation(format!(
+                    "body.sorts[].direction should be `ascending` or `descending`, instead was `{direction}`."
+                )));
+            }
+        };
+
+        let key = match (sort["property"].as_str(), sort["timestamp"].as_str()) {
+            (Some(property), _) => SortKey::Property(property.to_string()),
+            (None, Some(timestamp @ ("created_time" | "last_edited_time"))) => {
+                SortKey::Timestamp(timestamp.to_string())
+            }
+            _ => {
+                return Err(ApiError::validation(
+                    "body.sorts[] should define `property` or `timestamp`.",
+                ));
+            }
+        };
+
+        keys.push((key, descending));
+    }
+
+    pages.sort_by(|a, b| {
+        keys.iter()
+            .map(|(key, descending)| {
+                let ordering = compare_values(&key.value(a), &key.value(b));
+                if *descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    Ok(())
+}
+
+enum SortKey {
+    Property(String),
+    Timestamp(String),
+}
+
+impl SortKey {
+    /// A comparable value of a page for this key.
+    fn value(&self, page: &Value) -> Value {
+        match self {
+            SortKey::Timestamp(timestamp) => page[timestamp].clone(),
+            SortKey::Property(property) => {
+                let value = page["properties"]
+                    .as_object()
+                    .and_then(|properties| {
+                        properties
+                            .get(property)
+                            .or_else(|| properties.values().find(|value| value["id"] == **property))
+                    })
+                    .cloned()
+                    .unwrap_or(Value::Null);
+
+                let r#type = value["type"].as_str().unwrap_or_default().to_string();
+                let inner = &value[&r#type];
+
+                match r#type.as_str() {
+                    "title" | "rich_text" => {
+                        Value::String(crate::rich_text::plain_text(inner).to_lowercase())
+                    }
+                    "select" | "status" => inner["name"].clone(),
+                    "date" => inner["start"].clone(),
+                    "unique_id" => inner["number"].clone(),
+                    _ => inner.clone(),
+                }
+            }
+        }
+    }
+}
+
+/// Orders JSON scalars. Empty values sort last, as in Notion.
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Greater,
+        (_, Value::Null) => Ordering::Less,
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
@@ -0,0 +1,116 @@
+use serde_json::{Value, json};
+
+use crate::error::ApiError;
+
+/// A plain text rich text object.
+pub(crate) fn text(content: &str) -> Value {
+    json!({
+        "type": "text",
+        "text": { "content": content, "link": null },
+        "annotations": {
+            "bold": false,
+            "italic": false,
+            "strikethrough": false,
+            "underline": false,
+            "code": false,
+            "color": "default"
+        },
+        "plain_text": content,
+        "href": null
+    })
+}
+
+/// Fills in what Notion derives for a rich text array given in a request
+/// (`type`, `annotations`, `plain_text`, `href`) and checks that the result is a
+/// valid `Vec<RichText>`.
+pub(crate) fn normalize(value: &Value, field: &str) -> Result<Value, ApiError> {
+    let items = value
+        .as_array()
+        .ok_or_else(|| ApiError::validation(format!("body.{field} should be an array.")))?;
+
+    let items = items
+        .iter()
+        .map(|item| normalize_item(item, field))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let value = Value::Array(items);
+
+    serde_json::from_value::<Vec<notionrs_types::object::rich_text::RichText>>(value.clone())
+        .map_err(|e| ApiError::validation(format!("body.{field} is invalid: {e}")))?;
+
+    Ok(value)
+}
+
+fn normalize_item(item: &Value, field: &str) -> Result<Value, ApiError> {
+    let mut item = item
+        .as_object()
+        .cloned()
+        .ok_or_else(|| ApiError::validation(format!("body.{field} should contain objects.")))?;
+
+    let r#type = match item.get("type").and_then(Value::as_str) {
+        Some(r#type) => r#type.to_string(),
+        None => ["text", "mention", "equation"]
+            .into_iter()
+            .find(|r#type| item.contains_key(*r#type))
+            .ok_or_else(|| {
+                ApiError::validation(format!(
+                    "body.{field} items should define `text`, `mention` or `equation`."
+                ))
+            })?
+            .to_string(),
+    };
+
+    // Indexing a `Value` (unlike a `Map`) yields `Null` for missing keys.
+    let view = Value::Object(item.clone());
+
+    let plain_text = match r#type.as_str() {
+        "text" => view["text"]["content"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        "equation" => view["equation"]["expression"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        _ => view["plain_text"].as_str().unwrap_or_default().to_string(),
+    };
+
+    if r#type == "text" {
+        if let Some(text) = item.get_mut("text").and_then(Value::as_object_mut) {
+            text.entry("link").or_insert(Value::Null);
+        }
+    }
+
+    let default_annotations = text("")["annotations"].clone();
+    let annotations = item
+        .entry("annotations")
+        .or_insert_with(|| default_annotations.clone());
+    if let (Some(annotations), Some(defaults)) =
+        (annotations.as_object_mut(), default_annotations.as_object())
+    {
+        for (key, value) in defaults {
+            annotations.entry(key).or_insert_with(|| value.clone());
+        }
+    }
+
+    let href = view["text"]["link"]["url"].clone();
+    item.insert("type".to_string(), Value::String(r#type));
+    item.entry("plain_text")
+        .or_insert(Value::String(plain_text));
+    item.entry("href").or_insert(href);
+
+    Ok(Value::Object(item))
+}
+
+/// The concatenated `plain_text` of a rich text array.
+pub(crate) fn plain_text(value: &Value) -> String {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item["plain_text"].as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,576 @@
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use serde_json::{Map, Value, json};
+
+use super::{ApiResult, SharedStore, lock, ok, parse_body};
+use crate::error::ApiError;
+use crate::pagination::{PageParams, list};
+use crate::store::{Block, Store, format_time, now, same_id};
+
+/// The most children a single append request can carry, as in Notion.
+const MAX_CHILDREN: usize = 100;
+
+pub(crate) fn render(store: &Store, block: &Block) -> Value {
+    let mut rendered = json!({
+        "object": "block",
+        "id": block.id,
+        "parent": block.parent,
+        "created_time": format_time(block.created_time),
+        "last_edited_time": format_time(block.last_edited_time),
+        "created_by": store.bot_reference(),
+        "last_edited_by": store.bot_reference(),
+        "has_children": store.children(&block.id).next().is_some(),
+        "archived": block.in_trash,
+        "in_trash": block.in_trash,
+    });
+
+    if let (Some(rendered), Some(content)) = (rendered.as_object_mut(), block.content.as_object()) {
+        rendered.extend(content.clone());
+    }
+
+    rendered
+}
+
+/// Splits a block from a request into its `{"type": ..., "<type>": {...}}`
+/// content and its nested `children`, after checking it's a valid `Block`.
+fn split_block(block: &Value) -> Result<(Value, Vec<Value>), ApiError> {
+    let object = block
+        .as_object()
+        .ok_or_else(|| ApiError::validation("body.children should contain block objects."))?;
+
+    let r#type = match object.get("type").and_then(Value::as_str) {
+        Some(r#type) => r#type.to_string(),
+        None => object
+            .keys()
+            .find(|key| !matches!(key.as_str(), "object" | "type" | "children"))
+            .cloned()
+            .ok_or_else(|| {
+                ApiError::validation("body.children items should define a block type.")
+            })?,
+    };
+
+    if matches!(r#type.as_str(), "child_page" | "child_database") {
+        return Err(ApiError::validation(format!(
+            "Blocks of type `{type}` can't be appended. Create a page or database instead.",
+            type = r#type
+        )));
+    }
+
+    let mut body = object
+        .get(&r#type)
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+
+    let children = match body.remove("children") {
+        Some(Value::Array(children)) => children,
+        _ => Vec::new(),
+    };
+
+    normalize_rich_text(&mut body)?;
+
+    let content = json!({ "type": r#type, r#type.clone(): body });
+
+    serde_json::from_value::<notionrs_types::object::block::Block>(content.clone()).map_err(
+        |e| {
+            ApiError::validation(
+                format!("body.children contains an invalid `{type}` block: {e}", type = r#type),
+            )
+        },
+    )?;
+
+    Ok((content, children))
+}
+
+/// Normalizes the rich text arrays of a block body.
+fn normalize_rich_text(body: &mut Map<String, Value>) -> Result<(), ApiError> {
+    for key in ["rich_text", "caption"] {
+        if let Some(value) = body.get_mut(key) {
+            *value = crate::rich_text::normalize(value, key)?;
+        }
+    }
+
+    if let Some(Value::Array(cells)) = body.get_mut("cells") {
+        for cell in cells {
+            *cell = crate::rich_text::normalize(cell, "cells")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `children` can be appended, without changing the store.
+pub(crate) fn validate_children(children: &[Value]) -> Result<(), ApiError> {
+    if children.len() > MAX_CHILDREN {
+        return Err(ApiError::validation(format!(
+            "body.children.length should be ≤ `{MAX_CHILDREN}`, instead was `{}`.",
+            children.len()
+        )));
+    }
+
+    for child in children {
+        let (_, nested) = split_block(child)?;
+        validate_children(&nested)?;
+    }
+
+    Ok(())
+}
+
+/// Stores `children` (and their nested children) under `parent_id`, starting at
+/// `index` in `store.blocks`. Returns the IDs of the top-level blocks.
+pub(crate) fn insert_children(
+    store: &mut Store,
+    parent_id: &str,
+    children: &[Value],
+    mut index: usize,
+) -> Result<Vec<String>, ApiError> {
+    validate_children(children)?;
+
+    let parent = if store.page(parent_id).is_some() {
+        json!({ "type": "page_id", "page_id": parent_id })
+    } else {
+        json!({ "type": "block_id", "block_id": parent_id })
+    };
+
+    let mut ids = Vec::new();
+    let mut nested = Vec::new();
+
+    for child in children {
+        let (content, children) = split_block(child)?;
+        let now = now();
+
+        let block = Block {
+            id: store.new_id(),
+            parent_id: parent_id.to_string(),
+            parent: parent.clone(),
+            content,
+            created_time: now,
+            last_edited_time: now,
+            in_trash: false,
+        };
+
+        ids.push(block.id.clone());
+        if !children.is_empty() {
+            nested.push((block.id.clone(), children));
+        }
+
+        store.blocks.insert(index, block);
+        index += 1;
+    }
+
+    for (block_id, children) in nested {
+        let end = store.blocks.len();
+        insert_children(store, &block_id, &children, end)?;
+    }
+
+    Ok(ids)
+}
+
+/// Trashes a block, and the page it stands for if it's a `child_page` block.
+pub(crate) fn trash_block(store: &mut Store, block_id: &str, in_trash: bool) {
+    let now = now();
+
+    if let Some(block) = store
+        .blocks
+        .iter_mut()
+        .find(|block| same_id(&block.id, block_id))
+    {
+        block.in_trash = in_trash;
+        block.last_edited_time = now;
+    }
+
+    if let Some(page) = store.page_mut(block_id) {
+        page.in_trash = in_trash;
+        page.last_edited_time = now;
+    }
+}
+
+fn live_block<'a>(store: &'a Store, block_id: &str) -> Result<&'a Block, ApiError> {
+    store
+        .block(block_id)
+        .filter(|block| !block.in_trash)
+        .ok_or_else(|| ApiError::not_found("block", block_id))
+}
+
+/// `GET /v1/blocks/{block_id}`
+pub(crate) async fn get_block(
+    State(store): State<SharedStore>,
+    Path(block_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+
+    let block = store
+        .block(&block_id)
+        .ok_or_else(|| ApiError::not_found("block", &block_id))?;
+
+    ok(render(&store, block))
+}
+
+/// `PATCH /v1/blocks/{block_id}`
+pub(crate) async fn update_block(
+    State(store): State<SharedStore>,
+    Path(block_id): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let body = parse_body(&body)?;
+    let mut store = lock(&store);
+
+    let current = store
+        .block(&block_id)
+        .cloned()
+        .ok_or_else(|| ApiError::not_found("block", &block_id))?;
+    let r#type = current.content["type"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+
+    let in_trash = body["in_trash"].as_bool().or(body["archived"].as_bool());
+
+    if current.in_trash && in_trash != Some(false) {
+        return Err(ApiError::validation(
+            "Can't edit block that is archived. You must unarchive the block before editing.",
+        ));
+    }
+
+    let content = match body.get(&r#type) {
+        Some(update) => {
+            let mut merged = current.content[&r#type]
+                .as_object()
+                .cloned()
+                .unwrap_or_default();
+            merged.extend(update.as_object().cloned().unwrap_or_default());
+            let (content, children) =
+                split_block(&json!({ "type": r#type, r#type.clone(): merged }))?;
+
+            if !children.is_empty() {
+                return Err(ApiError::validation(
+                    "Use the append block children endpoint to add children.",
+                ));
+            }
+            Some(content)
+        }
+        None => {
+            if let Some(other) = body
+                .as_object()
+                .and_then(|body| body.get("type"))
+                .and_then(Value::as_str)
+                .filter(|other| *other != r#type)
+            {
+                return Err(ApiError::validation(format!(
+                    "Block type can't be changed from `{type}` to `{other}`.",
+                    type = r#type
+                )));
+            }
+            None
+        }
+    };
+
+    if let Some(in_trash) = in_trash {
+        trash_block(&mut store, &block_id, in_trash);
+    }
+
+    let now = now();
+    let block = store
+        .blocks
+        .iter_mut()
+        .find(|block| same_id(&block.id, &block_id))
+        .ok_or_else(|| ApiError::not_found("block", &block_id))?;
+
+    if let Some(content) = content {
+        block.content = content;
+    }
+    block.last_edited_time = now;
+
+    let block = block.clone();
+    ok(render(&store, &block))
+}
+
+/// `DELETE /v1/blocks/{block_id}`
+pub(crate) async fn delete_block(
+    State(store): State<SharedStore>,
+    Path(block_id): Path<String>,
+) -> ApiResult {
+    let mut store = lock(&store);
+
+    let block = live_block(&store, &block_id)?.id.clone();
+    trash_block(&mut store, &block, true);
+
+    let block = store
+        .block(&block)
+        .ok_or_else(|| ApiError::not_found("block", &block_id))?;
+
+    ok(render(&store, block))
+}
+
+/// `GET /v1/blocks/{block_id}/children`
+pub(crate) async fn get_block_children(
+    State(store): State<SharedStore>,
+    Path(block_id): Path<String>,
+    Query(query): Query<HashMap<String, String>>,
+) -> ApiResult {
+    let params = PageParams::from_query(&query)?;
+    let store = lock(&store);
+
+    if !store.is_live_parent(&block_id) {
+        return Err(ApiError::not_found("block", &block_id));
+    }
+
+    let children = store
+        .children(&block_id)
+        .map(|block| (block.id.clone(), render(&store, block)))
+        .collect();
+
+    ok(list(children, "block", &params)?)
+}
+
+/// `PATCH /v1/blocks/{block_id}/children`
+pub(crate) async fn append_block_children(
+    State(store): State<SharedStore>,
+    Path(block_id): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let body = parse_body(&body)?;
+    let mut store = lock(&store);
+
+    if !store.is_live_parent(&block_id) {
+        return Err(ApiError::not_found("block", &block_id));
+    }
+
+    // The parent as stored, so children get its canonical (hyphenated) ID.
+    let parent_id = store
+        .page(&block_id)
+        .map(|page| page.id.clone())
+        .or_else(|| store.block(&block_id).map(|block| block.id.clone()))
+        .unwrap_or(block_id);
+
+    let children = body["children"]
+        .as_array()
+        .cloned()
+        .ok_or_else(|| ApiError::validation("body.children should be an array."))?;
+
+    let after = match (&body["position"]["type"], &body["after"]) {
+        (Value::String(position), _) if position == "after_block" => {
+            body["position"]["after_block"]["id"]
+                .as_str()
+                .map(str::to_string)
+        }
+        (Value::String(position), _) if position == "start" => None,
+        (Value::String(position), _) if position == "end" => Some(String::new()),
+        (Value::String(position), _) => {
+            return Err(ApiError::validation(format!(
+                "body.position.type should be `after_block`, `start` or `end`, instead was `{position}`."
+            )));
+        }
+        (_, Value::String(after)) => Some(after.clone()),
+        _ => Some(String::new()),
+    };
+
+    let index = match after.as_deref() {
+        // The end of the list: every existing child comes before the end of `blocks`.
+        Some("") => store.blocks.len(),
+        Some(after) => {
+            let index = store
+                .blocks
+                .iter()
+                .position(|block| {
+                    !block.in_trash
+                        && same_id(&block.id, after)
+                        && same_id(&block.parent_id, &parent_id)
+                })
+                .ok_or_else(|| {
+                    ApiError::validation(format!(
+                        "Block {after} is not a child of block {parent_id}."
+                    ))
+                })?;
+            index + 1
+        }
+        None => store
+            .blocks
+            .iter()
+            .position(|block| same_id(&block.parent_id, &parent_id))
+            .unwrap_or(store.blocks.len()),
+    };
+
+    let ids = insert_children(&mut store, &parent_id, &children, index)?;
+
+    let now = now();
+    if let Some(page) = store.page_mut(&parent_id) {
+        page.last_edited_time = now;
+    }
+
+    let appended = ids
+        .iter()
+        .filter_map(|id| store.block(id))
+        .map(|block| (block.id.clone(), render(&store, block)))
+        .collect();
+
+    ok(list(appended, "block", &PageParams::default())?)
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::MockServer;
+    use crate::routes::unit_tests::client;
+
+    use notionrs_types::object::block::Block;
+    use notionrs_types::object::block::paragraph::ParagraphBlock;
+
+    fn paragraph(text: &str) -> Block {
+        Block::Paragraph {
+            paragraph: ParagraphBlock::from(text),
+        }
+    }
+
+    fn texts(blocks: &[notionrs_types::object::block::BlockResponse]) -> Vec<String> {
+        blocks.iter().map(|block| block.block.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn append_children_in_position() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+        let page_id = server.root_page_id();
+
+        let appended = client
+            .append_block_children()
+            .block_id(&page_id)
+            .children(vec![paragraph("b"), paragraph("d")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(appended.results.len(), 2);
+
+        client
+            .append_block_children()
+            .block_id(&page_id)
+            .children(vec![paragraph("a")])
+            .position_start()
+            .send()
+            .await
+            .unwrap();
+
+        client
+            .append_block_children()
+            .block_id(&page_id)
+            .children(vec![paragraph("c")])
+            .position_after_block(&appended.results[0].id)
+            .send()
+            .await
+            .unwrap();
+
+        let children = client
+            .get_block_children()
+            .block_id(&page_id)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(texts(&children.results), ["a", "b", "c", "d"]);
+    }
+
+    #[tokio::test]
+    async fn nested_children_are_stored_under_their_parent() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+
+        let toggle = Block::Paragraph {
+            paragraph: ParagraphBlock::from("parent").children(vec![paragraph("child")]),
+        };
+
+        let appended = client
+            .append_block_children()
+            .block_id(server.root_page_id())
+            .children(vec![toggle])
+            .send()
+            .await
+            .unwrap();
+        assert!(appended.results[0].has_children);
+
+        let children = client
+            .get_block_children()
+            .block_id(&appended.results[0].id)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(texts(&children.results), ["child"]);
+    }
+
+    #[tokio::test]
+    async fn update_and_delete_block() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+
+        let appended = client
+            .append_block_children()
+            .block_id(server.root_page_id())
+            .children(vec![paragraph("before")])
+            .send()
+            .await
+            .unwrap();
+        let block_id = appended.results[0].id.clone();
+
+        let updated = client
+            .update_block()
+            .block_id(&block_id)
+            .block(paragraph("after"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(updated.block.to_string(), "after");
+
+        let deleted = client
+            .delete_block()
+            .block_id(&block_id)
+            .send()
+            .await
+            .unwrap();
+        assert!(deleted.in_trash);
+
+        let children = client
+            .get_block_children()
+            .block_id(server.root_page_id())
+            .send()
+            .await
+            .unwrap();
+        assert!(children.results.is_empty());
+
+        let error = client
+            .delete_block()
+            .block_id(&block_id)
+            .send()
+            .await
+            .unwrap_err();
+        assert!(error.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn block_children_respect_page_size() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+
+        client
+            .append_block_children()
+            .block_id(server.root_page_id())
+            .children((0..3).map(|i| paragraph(&i.to_string())).collect())
+            .send()
+            .await
+            .unwrap();
+
+        let first = client
+            .get_block_children()
+            .block_id(server.root_page_id())
+            .page_size(2)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(texts(&first.results), ["0", "1"]);
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use serde_json::{Value, json};
+
+use super::{ApiResult, SharedStore, lock, ok, parse_body, parse_parent};
+use crate::error::ApiError;
+use crate::pagination::{PageParams, list};
+use crate::store::{Comment, Store, format_time, now, same_id};
+
+fn render(store: &Store, comment: &Comment) -> Value {
+    json!({
+        "object": "comment",
+        "id": comment.id,
+        "parent": comment.parent,
+        "discussion_id": comment.discussion_id,
+        "created_time": format_time(comment.created_time),
+        "last_edited_time": format_time(comment.last_edited_time),
+        "created_by": store.bot_reference(),
+        "rich_text": comment.rich_text,
+        "display_name": { "type": "integration", "resolved_name": "notionrs_mock" },
+        "request_id": crate::request_id(),
+    })
+}
+
+/// Reads `rich_text` from a create or update body. `markdown` isn't supported.
+fn rich_text(body: &Value) -> Result<Value, ApiError> {
+    if !body["markdown"].is_null() {
+        return Err(ApiError::validation(
+            "notionrs_mock does not support `markdown` comments. Use `rich_text` instead.",
+        ));
+    }
+
+    crate::rich_text::normalize(&body["rich_text"], "rich_text")
+}
+
+/// `POST /v1/comments`
+pub(crate) async fn create_comment(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let body = parse_body(&body)?;
+    let rich_text = rich_text(&body)?;
+
+    let mut store = lock(&store);
+
+    let (parent_id, parent, discussion_id) = match body["discussion_id"].as_str() {
+        Some(discussion_id) => {
+            let discussion = store
+                .comments
+                .iter()
+                .find(|comment| same_id(&comment.discussion_id, discussion_id))
+                .ok_or_else(|| ApiError::not_found("discussion", discussion_id))?;
+
+            (
+                discussion.parent_id.clone(),
+                discussion.parent.clone(),
+                discussion.discussion_id.clone(),
+            )
+        }
+        None => {
+            let (parent_type, parent_id) = parse_parent(&body["parent"])?;
+
+            if !matches!(parent_type.as_str(), "page_id" | "block_id")
+                || !store.is_live_parent(&parent_id)
+            {
+                return Err(ApiError::not_found("block", &parent_id));
+            }
+
+            let parent = json!({ "type": parent_type, parent_type.clone(): parent_id });
+            (parent_id, parent, store.new_id())
+        }
+    };
+
+    let now = now();
+    let comment = Comment {
+        id: store.new_id(),
+        parent_id,
+        parent,
+        discussion_id,
+        rich_text,
+        created_time: now,
+        last_edited_time: now,
+        in_trash: false,
+    };
+
+    let rendered = render(&store, &comment);
+    store.comments.push(comment);
+
+    ok(rendered)
+}
+
+/// `GET /v1/comments?block_id=...`
+pub(crate) async fn list_comments(
+    State(store): State<SharedStore>,
+    Query(query): Query<HashMap<String, String>>,
+) -> ApiResult {
+    let params = PageParams::from_query(&query)?;
+    let block_id = query
+        .get("block_id")
+        .ok_or_else(|| ApiError::validation("block_id should be defined."))?;
+
+    let store = lock(&store);
+
+    if !store.is_live_parent(block_id) {
+        return Err(ApiError::not_found("block", block_id));
+    }
+
+    let comments = store
+        .comments
+        .iter()
+        .filter(|comment| !comment.in_trash && same_id(&comment.parent_id, block_id))
+        .map(|comment| (comment.id.clone(), render(&store, comment)))
+        .collect();
+
+    ok(list(comments, "comment", &params)?)
+}
+
+/// `GET /v1/comments/{comment_id}`
+pub(crate) async fn retrieve_comment(
+    State(store): State<SharedStore>,
+    Path(comment_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+
+    let comment = store
+        .comments
+        .iter()
+        .find(|comment| !comment.in_trash && same_id(&comment.id, &comment_id))
+        .ok_or_else(|| ApiError::not_found("comment", &comment_id))?;
+
+    ok(render(&store, comment))
+}
+
+/// `PATCH /v1/comments/{comment_id}`
+pub(crate) async fn update_comment(
+    State(store): State<SharedStore>,
+    Path(comment_id): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let body = parse_body(&body)?;
+    let rich_text = rich_text(&body)?;
+
+    let mut store = lock(&store);
+
+    let comment = store
+        .comment_mut(&comment_id)
+        .ok_or_else(|| ApiError::not_found("comment", &comment_id))?;
+    comment.rich_text = rich_text;
+    comment.last_edited_time = now();
+
+    let comment = comment.clone();
+    ok(render(&store, &comment))
+}
+
+/// `DELETE /v1/comments/{comment_id}`
+pub(crate) async fn delete_comment(
+    State(store): State<SharedStore>,
+    Path(comment_id): Path<String>,
+) -> ApiResult {
+    let mut store = lock(&store);
+
+    let comment = store
+        .comment_mut(&comment_id)
+        .ok_or_else(|| ApiError::not_found("comment", &comment_id))?;
+    comment.in_trash = true;
+
+    let comment = comment.clone();
+    ok(render(&store, &comment))
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::MockServer;
+    use crate::routes::unit_tests::client;
+
+    #[tokio::test]
+    async fn create_list_update_and_delete_comments() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+
+        let comment = client
+            .create_comment()
+            .page_id(server.root_page_id())
+            .rich_text(vec!["First".into()])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(comment.to_string(), "First");
+
+        let reply = client
+            .create_comment()
+            .discussion_id(comment.discussion_id.clone().unwrap())
+            .rich_text(vec!["Reply".into()])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(reply.discussion_id, comment.discussion_id);
+
+        let updated = client
+            .update_comment()
+            .comment_id(&reply.id)
+            .rich_text(vec!["Edited".into()])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(updated.to_string(), "Edited");
+
+        client
+            .delete_comment()
+            .comment_id(&comment.id)
+            .send()
+            .await
+            .unwrap();
+
+        let comments = client
+            .retrieve_comments()
+            .block_id(server.root_page_id())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(comments.results.len(), 1);
+        assert_eq!(comments.results[0].to_string(), "Edited");
+    }
+
+    #[tokio::test]
+    async fn comment_on_unknown_page_is_not_found() {
+        let server = MockServer::start().await;
+
+        let error = client(&server)
+            .create_comment()
+            .page_id("00000000-0000-0000-0000-000000000000")
+            .rich_text(vec!["Hello".into()])
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(error.is_not_found());
+    }
+}
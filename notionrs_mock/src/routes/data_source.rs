@@ -0,0 +1,346 @@
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use serde_json::{Value, json};
+
+use super::{ApiResult, SharedStore, lock, ok, parse_body, parse_parent};
+use crate::error::ApiError;
+use crate::pagination::{PageParams, list};
+use crate::store::{DataSource, Store, format_time, notion_url, now, same_id};
+
+pub(crate) fn render(store: &Store, data_source: &DataSource) -> Value {
+    json!({
+        "object": "data_source",
+        "id": data_source.id,
+        "created_time": format_time(data_source.created_time),
+        "last_edited_time": format_time(data_source.last_edited_time),
+        "created_by": store.bot_reference(),
+        "last_edited_by": store.bot_reference(),
+        "cover": null,
+        "icon": data_source.icon,
+        "parent": { "type": "database_id", "database_id": data_source.database_id },
+        "database_parent": { "type": "workspace", "workspace": true },
+        "properties": data_source.properties,
+        "url": notion_url(&data_source.id),
+        "title": data_source.title,
+        "description": [],
+        "archived": data_source.in_trash,
+        "in_trash": data_source.in_trash,
+        "is_inline": false,
+        "public_url": null,
+        "request_id": crate::request_id(),
+    })
+}
+
+/// `POST /v1/data_sources`
+pub(crate) async fn create_data_source(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let body = parse_body(&body)?;
+
+    let (parent_type, database_id) = parse_parent(&body["parent"])?;
+    if parent_type != "database_id" {
+        return Err(ApiError::validation("body.parent should be a database_id."));
+    }
+
+    let title = match &body["title"] {
+        Value::Null => json!([]),
+        title => crate::rich_text::normalize(title, "title")?,
+    };
+
+    let icon = match &body["icon"] {
+        Value::Null => Value::Null,
+        icon => {
+            serde_json::from_value::<notionrs_types::object::emoji_and_icon::EmojiAndIcon>(
+                icon.clone(),
+            )
+            .map_err(|e| ApiError::validation(format!("body.icon is invalid: {e}")))?;
+            icon.clone()
+        }
+    };
+
+    let mut store = lock(&store);
+    let properties = crate::properties::build_schema(&mut store, &body["properties"])?;
+
+    let now = now();
+    let data_source = DataSource {
+        id: store.new_id(),
+        database_id,
+        title,
+        properties,
+        icon,
+        created_time: now,
+        last_edited_time: now,
+        in_trash: false,
+    };
+
+    let rendered = render(&store, &data_source);
+    store.data_sources.push(data_source);
+
+    ok(rendered)
+}
+
+/// `GET /v1/data_sources/{data_source_id}`
+pub(crate) async fn retrieve_data_source(
+    State(store): State<SharedStore>,
+    Path(data_source_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+
+    let data_source = store
+        .data_source(&data_source_id)
+        .ok_or_else(|| ApiError::not_found("data source", &data_source_id))?;
+
+    ok(render(&store, data_source))
+}
+
+/// `POST /v1/data_sources/{data_source_id}/query`
+pub(crate) async fn query_data_source(
+    State(store): State<SharedStore>,
+    Path(data_source_id): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let body = parse_body(&body)?;
+    let params = PageParams::from_body(&body)?;
+    let filter = crate::query::parse_filter(&body["filter"])?;
+
+    let store = lock(&store);
+
+    let data_source = store
+        .data_source(&data_source_id)
+        .filter(|data_source| !data_source.in_trash)
+        .ok_or_else(|| ApiError::not_found("data source", &data_source_id))?;
+
+    let mut pages = Vec::new();
+    for page in store.pages.iter().filter(|page| {
+        !page.in_trash
+            && page.parent["data_source_id"]
+                .as_str()
+                .is_some_and(|id| same_id(id, &data_source.id))
+    }) {
+        let rendered = super::page::render(&store, page);
+
+        let included = match &filter {
+            Some(filter) => crate::query::matches(filter, &rendered)?,
+            None => true,
+        };
+        if included {
+            pages.push(rendered);
+        }
+    }
+
+    crate::query::sort(&mut pages, &body["sorts"])?;
+
+    let pages = pages
+        .into_iter()
+        .map(|page| (page["id"].as_str().unwrap_or_default().to_string(), page))
+        .collect();
+
+    ok(list(pages, "page_or_data_source", &params)?)
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::HashMap;
+
+    use crate::MockServer;
+    use crate::routes::unit_tests::client;
+
+    use notionrs_types::object::data_source::DataSourceProperty;
+    use notionrs_types::object::page::PageProperty;
+    use notionrs_types::object::request::filter::Filter;
+    use notionrs_types::object::request::sort::Sort;
+
+    async fn tasks(server: &MockServer) -> String {
+        let properties = HashMap::from([
+            (
+                "Name".to_string(),
+                DataSourceProperty::Title(Default::default()),
+            ),
+            (
+                "Points".to_string(),
+                DataSourceProperty::Number(Default::default()),
+            ),
+            (
+                "Done".to_string(),
+                DataSourceProperty::Checkbox(Default::default()),
+            ),
+            (
+                "Tag".to_string(),
+                DataSourceProperty::Select(Default::default()),
+            ),
+            (
+                "ID".to_string(),
+                DataSourceProperty::UniqueId(Default::default()),
+            ),
+        ]);
+
+        client(server)
+            .create_data_source()
+            .database_id("00000000-0000-4000-a000-000000000001")
+            .properties(properties)
+            .send()
+            .await
+            .unwrap()
+            .id
+    }
+
+    async fn add_task(
+        server: &MockServer,
+        data_source_id: &str,
+        name: &str,
+        points: f64,
+        done: bool,
+    ) {
+        let properties = HashMap::from([
+            ("Name".to_string(), PageProperty::Title(name.into())),
+            ("Points".to_string(), PageProperty::Number(points.into())),
+            ("Done".to_string(), PageProperty::Checkbox(done.into())),
+            (
+                "Tag".to_string(),
+                PageProperty::Select(if done { "old" } else { "new" }.into()),
+            ),
+        ]);
+
+        client(server)
+            .create_page::<HashMap<String, PageProperty>>()
+            .data_source_id(data_source_id)
+            .properties(properties)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn create_and_retrieve_data_source() {
+        let server = MockServer::start().await;
+        let data_source_id = tasks(&server).await;
+
+        let data_source = client(&server)
+            .retrieve_data_source()
+            .data_source_id(&data_source_id)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(data_source.properties.len(), 5);
+        assert!(matches!(
+            data_source.properties["Name"],
+            DataSourceProperty::Title(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn query_filters_sorts_and_paginates() {
+        let server = MockServer::start().await;
+        let data_source_id = tasks(&server).await;
+
+        add_task(&server, &data_source_id, "Write docs", 3.0, false).await;
+        add_task(&server, &data_source_id, "Fix bug", 5.0, true).await;
+        add_task(&server, &data_source_id, "Write tests", 8.0, false).await;
+
+        let client = client(&server);
+
+        let open = client
+            .query_data_source()
+            .data_source_id(&data_source_id)
+            .filter(Filter::and(vec![
+                Filter::checkbox_is_not_checked("Done"),
+                Filter::rich_text_starts_with("Name", "write"),
+            ]))
+            .sorts(vec![Sort::desc("Points")])
+            .send()
+            .await
+            .unwrap();
+
+        let names = open
+            .results
+            .iter()
+            .map(|page| page.properties["Name"].to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Write tests", "Write docs"]);
+
+        let first = client
+            .query_data_source()
+            .data_source_id(&data_source_id)
+            .sorts(vec![Sort::asc("ID")])
+            .page_size(2)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(first.results.len(), 2);
+        assert_eq!(first.has_more, Some(true));
+        assert!(matches!(
+            &first.results[0].properties["ID"],
+            PageProperty::UniqueId(id) if id.unique_id.number == Some(1)
+        ));
+
+        let second = client
+            .query_data_source()
+            .data_source_id(&data_source_id)
+            .sorts(vec![Sort::asc("ID")])
+            .page_size(2)
+            .start_cursor(first.next_cursor.unwrap())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(second.results.len(), 1);
+        assert_eq!(second.has_more, Some(false));
+
+        let tagged = client
+            .query_data_source()
+            .data_source_id(&data_source_id)
+            .filter(Filter::select_equals("Tag", "old"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(tagged.results.len(), 1);
+        assert_eq!(tagged.results[0].properties["Name"].to_string(), "Fix bug");
+    }
+
+    #[tokio::test]
+    async fn timestamp_filters() {
+        let server = MockServer::start().await;
+        let data_source_id = tasks(&server).await;
+        add_task(&server, &data_source_id, "Write docs", 3.0, false).await;
+
+        let client = client(&server);
+
+        for (filter, expected) in [
+            (Filter::timestamp_on_or_after("2000-01-01"), 1),
+            (Filter::timestamp_before("2000-01-01"), 0),
+            (Filter::last_edited_time_on_or_after("2000-01-01"), 1),
+            (Filter::last_edited_time_before("2000-01-01"), 0),
+        ] {
+            let pages = client
+                .query_data_source()
+                .data_source_id(&data_source_id)
+                .filter(filter)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(pages.results.len(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn unsupported_filters_are_reported() {
+        let server = MockServer::start().await;
+        let data_source_id = tasks(&server).await;
+        add_task(&server, &data_source_id, "Write docs", 3.0, false).await;
+
+        let error = client(&server)
+            .query_data_source()
+            .data_source_id(&data_source_id)
+            .filter(Filter::timestamp_past_week())
+            .send()
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.status(), Some(400));
+        assert!(error.to_string().contains("notionrs_mock does not support"));
+    }
+}
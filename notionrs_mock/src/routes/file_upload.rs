@@ -0,0 +1,440 @@
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use serde_json::{Value, json};
+
+use super::{ApiResult, SharedStore, lock, ok, parse_body};
+use crate::error::ApiError;
+use crate::pagination::{PageParams, list};
+use crate::store::{FileUpload, format_time, now};
+
+/// The most parts a multi-part upload can be split into.
+const MAX_PARTS: u64 = 1_000;
+
+fn render(base_url: &str, file_upload: &FileUpload) -> Value {
+    let pending = file_upload.status == "pending";
+
+    let upload_url = (pending && file_upload.mode != "external_url")
+        .then(|| format!("{base_url}/v1/file_uploads/{}/send", file_upload.id));
+    let complete_url = (pending && file_upload.mode == "multi_part")
+        .then(|| format!("{base_url}/v1/file_uploads/{}/complete", file_upload.id));
+    let expiry_time = pending.then(|| format_time(file_upload.created_time + time::Duration::HOUR));
+
+    json!({
+        "object": "file_upload",
+        "id": file_upload.id,
+        "created_time": format_time(file_upload.created_time),
+        "last_edited_time": format_time(file_upload.last_edited_time),
+        "expiry_time": expiry_time,
+        "upload_url": upload_url,
+        "complete_url": complete_url,
+        "in_trash": false,
+        "archived": false,
+        "status": file_upload.status,
+        "filename": file_upload.filename,
+        "content_type": file_upload.content_type,
+        "content_length": file_upload.content_length,
+        "number_of_parts": file_upload.parts.map(|(total, sent)| json!({ "total": total, "sent": sent })),
+        "request_id": crate::request_id(),
+    })
+}
+
+/// `POST /v1/file_uploads`
+pub(crate) async fn create_file_upload(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let body = parse_body(&body)?;
+
+    let mode = body["mode"].as_str().unwrap_or("single_part").to_string();
+    let filename = body["filename"].as_str().map(str::to_string);
+
+    let (status, parts) = match mode.as_str() {
+        "single_part" => ("pending", None),
+        "multi_part" => {
+            let total = body["number_of_parts"]
+                .as_u64()
+                .filter(|total| (1..=MAX_PARTS).contains(total))
+                .ok_or_else(|| {
+                    ApiError::validation(format!(
+                        "body.number_of_parts should be between 1 and {MAX_PARTS} for multi_part uploads."
+                    ))
+                })?;
+            ("pending", Some((total as u32, 0)))
+        }
+        "external_url" => {
+            if body["external_url"].as_str().is_none() {
+                return Err(ApiError::validation(
+                    "body.external_url should be defined for external_url uploads.",
+                ));
+            }
+            ("uploaded", None)
+        }
+        other => {
+            return Err(ApiError::validation(format!(
+                "body.mode should be `single_part`, `multi_part` or `external_url`, instead was `{other}`."
+            )));
+        }
+    };
+
+    if mode != "single_part" && filename.is_none() {
+        return Err(ApiError::validation(format!(
+            "body.filename should be defined for {mode} uploads."
+        )));
+    }
+
+    let mut store = lock(&store);
+    let now = now();
+
+    let file_upload = FileUpload {
+        id: store.new_id(),
+        mode,
+        status: status.to_string(),
+        filename,
+        content_type: body["content_type"].as_str().map(str::to_string),
+        content_length: None,
+        parts,
+        created_time: now,
+        last_edited_time: now,
+    };
+
+    let rendered = render(&store.base_url, &file_upload);
+    store.file_uploads.push(file_upload);
+
+    ok(rendered)
+}
+
+/// `POST /v1/file_uploads/{file_upload_id}/send`
+pub(crate) async fn send_file_upload(
+    State(store): State<SharedStore>,
+    Path(file_upload_id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> ApiResult {
+    let form = parse_multipart(&headers, &body)?;
+
+    let file = form
+        .get("file")
+        .ok_or_else(|| ApiError::validation("The `file` form field should be defined."))?;
+
+    let part_number = form
+        .get("part_number")
+        .map(|part_number| {
+            std::str::from_utf8(&part_number.data)
+                .ok()
+                .and_then(|part_number| part_number.trim().parse::<u32>().ok())
+                .ok_or_else(|| ApiError::validation("part_number should be a number."))
+        })
+        .transpose()?;
+
+    let mut store = lock(&store);
+    let base_url = store.base_url.clone();
+
+    let file_upload = store
+        .file_upload_mut(&file_upload_id)
+        .ok_or_else(|| ApiError::not_found("file upload", &file_upload_id))?;
+
+    if file_upload.status != "pending" || file_upload.mode == "external_url" {
+        return Err(ApiError::validation(format!(
+            "File upload {file_upload_id} can't accept content in status `{}`.",
+            file_upload.status
+        )));
+    }
+
+    match (&mut file_upload.parts, part_number) {
+        (Some((total, sent)), Some(part_number)) => {
+            if part_number == 0 || part_number > *total {
+                return Err(ApiError::validation(format!(
+                    "part_number should be between 1 and {total}, instead was `{part_number}`."
+                )));
+            }
+            *sent += 1;
+            file_upload.content_length =
+                Some(file_upload.content_length.unwrap_or_default() + file.data.len() as u64);
+        }
+        (Some(_), None) => {
+            return Err(ApiError::validation(
+                "part_number should be defined for multi_part uploads.",
+            ));
+        }
+        (None, Some(_)) => {
+            return Err(ApiError::validation(
+                "part_number should only be defined for multi_part uploads.",
+            ));
+        }
+        (None, None) => {
+            file_upload.status = "uploaded".to_string();
+            file_upload.content_length = Some(file.data.len() as u64);
+        }
+    }
+
+    if file_upload.filename.is_none() {
+        file_upload.filename = file.filename.clone();
+    }
+    if file_upload.content_type.is_none() {
+        file_upload.content_type = file.content_type.clone();
+    }
+    file_upload.last_edited_time = now();
+
+    ok(render(&base_url, file_upload))
+}
+
+/// `POST /v1/file_uploads/{file_upload_id}/complete`
+pub(crate) async fn complete_file_upload(
+    State(store): State<SharedStore>,
+    Path(file_upload_id): Path<String>,
+) -> ApiResult {
+    let mut store = lock(&store);
+    let base_url = store.base_url.clone();
+
+    let file_upload = store
+        .file_upload_mut(&file_upload_id)
+        .ok_or_else(|| ApiError::not_found("file upload", &file_upload_id))?;
+
+    match file_upload.parts {
+        Some((total, sent)) if file_upload.status == "pending" && sent >= total => {
+            file_upload.status = "uploaded".to_string();
+            file_upload.last_edited_time = now();
+            ok(render(&base_url, file_upload))
+        }
+        Some((total, sent)) if file_upload.status == "pending" => Err(ApiError::validation(
+            format!("Only {sent} of {total} parts of file upload {file_upload_id} were sent."),
+        )),
+        _ => Err(ApiError::validation(format!(
+            "File upload {file_upload_id} is not a pending multi_part upload."
+        ))),
+    }
+}
+
+/// `GET /v1/file_uploads/{file_upload_id}`
+pub(crate) async fn retrieve_file_upload(
+    State(store): State<SharedStore>,
+    Path(file_upload_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+
+    let file_upload = store
+        .file_upload(&file_upload_id)
+        .ok_or_else(|| ApiError::not_found("file upload", &file_upload_id))?;
+
+    ok(render(&store.base_url, file_upload))
+}
+
+/// `GET /v1/file_uploads`
+pub(crate) async fn list_file_uploads(
+    State(store): State<SharedStore>,
+    Query(query): Query<HashMap<String, String>>,
+) -> ApiResult {
+    let params = PageParams::from_query(&query)?;
+    let store = lock(&store);
+
+    let file_uploads = store
+        .file_uploads
+        .iter()
+        .rev()
+        .filter(|file_upload| {
+            query
+                .get("status")
+                .is_none_or(|status| *status == file_upload.status)
+        })
+        .map(|file_upload| (file_upload.id.clone(), render(&store.base_url, file_upload)))
+        .collect();
+
+    ok(list(file_uploads, "file_upload", &params)?)
+}
+
+/// A field of a `multipart/form-data` body.
+#[derive(Debug)]
+struct FormField {
+    filename: Option<String>,
+    content_type: Option<String>,
+    data: Vec<u8>,
+}
+
+/// Just enough of `multipart/form-data` to read what `send_file_upload` sends.
+fn parse_multipart(
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<HashMap<String, FormField>, ApiError> {
+    let invalid = || ApiError::validation("The request body should be multipart/form-data.");
+
+    let boundary = headers
+        .get(axum::http::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| value.starts_with("multipart/form-data"))
+        .and_then(|value| value.split("boundary=").nth(1))
+        .map(|boundary| format!("--{}", boundary.trim_matches('"')))
+        .ok_or_else(invalid)?;
+
+    let mut fields = HashMap::new();
+
+    for part in split(body, boundary.as_bytes()).into_iter().skip(1) {
+        if part.starts_with(b"--") {
+            break;
+        }
+
+        let part = part.strip_prefix(b"\r\n").unwrap_or(part);
+        let part = part.strip_suffix(b"\r\n").unwrap_or(part);
+
+        let header_end = find(part, b"\r\n\r\n").ok_or_else(invalid)?;
+        let head = String::from_utf8_lossy(&part[..header_end]);
+        let data = part[header_end + 4..].to_vec();
+
+        let mut name = None;
+        let mut filename = None;
+        let mut content_type = None;
+
+        for line in head.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+
+            if key.eq_ignore_ascii_case("content-type") {
+                content_type = Some(value.trim().to_string());
+            } else if key.eq_ignore_ascii_case("content-disposition") {
+                for param in value.split(';').map(str::trim) {
+                    if let Some(value) = param.strip_prefix("name=") {
+                        name = Some(value.trim_matches('"').to_string());
+                    } else if let Some(value) = param.strip_prefix("filename=") {
+                        filename = Some(value.trim_matches('"').to_string());
+                    }
+                }
+            }
+        }
+
+        if let Some(name) = name {
+            fields.insert(
+                name,
+                FormField {
+                    filename,
+                    content_type,
+                    data,
+                },
+            );
+        }
+    }
+
+    Ok(fields)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn split<'a>(mut haystack: &'a [u8], separator: &[u8]) -> Vec<&'a [u8]> {
+    let mut parts = Vec::new();
+
+    while let Some(index) = find(haystack, separator) {
+        parts.push(&haystack[..index]);
+        haystack = &haystack[index + separator.len()..];
+    }
+    parts.push(haystack);
+
+    parts
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::MockServer;
+    use crate::routes::unit_tests::client;
+
+    use notionrs::client::file_upload::create_file_upload::FileUploadMode;
+    use notionrs_types::object::file_upload::FileUploadStatus;
+
+    #[tokio::test]
+    async fn single_part_upload() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+
+        let created = client.create_file_upload().send().await.unwrap();
+        assert!(matches!(created.status, FileUploadStatus::Pending));
+        assert!(created.upload_url.unwrap().starts_with(server.base_url()));
+
+        let sent = client
+            .send_file_upload()
+            .file_upload_id(&created.id)
+            .file(b"hello".to_vec())
+            .filename("hello.txt")
+            .content_type("text/plain")
+            .send()
+            .await
+            .unwrap();
+        assert!(matches!(sent.status, FileUploadStatus::Uploaded));
+        assert_eq!(sent.content_length, Some(5));
+        assert_eq!(sent.filename.as_deref(), Some("hello.txt"));
+        assert_eq!(sent.content_type.as_deref(), Some("text/plain"));
+
+        let uploads = client
+            .list_file_uploads()
+            .status(FileUploadStatus::Uploaded)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(uploads.results.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn multi_part_upload_completes_after_every_part() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+
+        let created = client
+            .create_file_upload()
+            .mode(FileUploadMode::MultiPart)
+            .filename("large.bin")
+            .number_of_parts(2)
+            .send()
+            .await
+            .unwrap();
+
+        client
+            .send_file_upload()
+            .file_upload_id(&created.id)
+            .file(vec![0; 3])
+            .part_number(1)
+            .send()
+            .await
+            .unwrap();
+
+        let error = client
+            .complete_file_upload()
+            .file_upload_id(&created.id)
+            .send()
+            .await
+            .unwrap_err();
+        assert_eq!(error.status(), Some(400));
+
+        client
+            .send_file_upload()
+            .file_upload_id(&created.id)
+            .file(vec![0; 4])
+            .part_number(2)
+            .send()
+            .await
+            .unwrap();
+
+        let completed = client
+            .complete_file_upload()
+            .file_upload_id(&created.id)
+            .send()
+            .await
+            .unwrap();
+        assert!(matches!(completed.status, FileUploadStatus::Uploaded));
+        assert_eq!(completed.content_length, Some(7));
+
+        let retrieved = client
+            .retrieve_file_upload()
+            .file_upload_id(&created.id)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(retrieved.number_of_parts.unwrap().sent, 2);
+    }
+}
@@ -0,0 +1,248 @@
+use std::sync::{Arc, Mutex};
+
+use axum::Router;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use serde_json::Value;
+
+use crate::error::ApiError;
+use crate::store::Store;
+
+mod block;
+mod comment;
+mod data_source;
+mod file_upload;
+mod page;
+mod search;
+mod user;
+
+pub(crate) type SharedStore = Arc<Mutex<Store>>;
+
+/// The result of every handler: a JSON object, or a Notion-shaped error.
+pub(crate) type ApiResult = Result<axum::Json<Value>, ApiError>;
+
+pub(crate) fn router(store: SharedStore) -> Router {
+    Router::new()
+        .route("/v1/pages", post(page::create_page))
+        .route(
+            "/v1/pages/{page_id}",
+            get(page::get_page).patch(page::update_page),
+        )
+        .route(
+            "/v1/blocks/{block_id}",
+            get(block::get_block)
+                .patch(block::update_block)
+                .delete(block::delete_block),
+        )
+        .route(
+            "/v1/blocks/{block_id}/children",
+            get(block::get_block_children).patch(block::append_block_children),
+        )
+        .route("/v1/data_sources", post(data_source::create_data_source))
+        .route(
+            "/v1/data_sources/{data_source_id}",
+            get(data_source::retrieve_data_source),
+        )
+        .route(
+            "/v1/data_sources/{data_source_id}/query",
+            post(data_source::query_data_source),
+        )
+        .route("/v1/search", post(search::search))
+        .route(
+            "/v1/comments",
+            get(comment::list_comments).post(comment::create_comment),
+        )
+        .route(
+            "/v1/comments/{comment_id}",
+            get(comment::retrieve_comment)
+                .patch(comment::update_comment)
+                .delete(comment::delete_comment),
+        )
+        .route("/v1/users", get(user::list_users))
+        .route("/v1/users/me", get(user::get_self))
+        .route("/v1/users/{user_id}", get(user::get_user))
+        .route(
+            "/v1/file_uploads",
+            get(file_upload::list_file_uploads).post(file_upload::create_file_upload),
+        )
+        .route(
+            "/v1/file_uploads/{file_upload_id}",
+            get(file_upload::retrieve_file_upload),
+        )
+        .route(
+            "/v1/file_uploads/{file_upload_id}/send",
+            post(file_upload::send_file_upload),
+        )
+        .route(
+            "/v1/file_uploads/{file_upload_id}/complete",
+            post(file_upload::complete_file_upload),
+        )
+        .fallback(|| async { ApiError::invalid_request_url() })
+        .layer(axum::middleware::from_fn_with_state(
+            store.clone(),
+            check_headers,
+        ))
+        .with_state(store)
+}
+
+/// Rejects requests without `Notion-Version`, or without a valid bearer token.
+async fn check_headers(State(store): State<SharedStore>, request: Request, next: Next) -> Response {
+    if !request.headers().contains_key("notion-version") {
+        return ApiError::missing_version().into_response();
+    }
+
+    let token = request
+        .headers()
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .filter(|token| !token.is_empty());
+
+    let authorized = match (token, &lock(&store).token) {
+        (None, _) => false,
+        (Some(token), Some(expected)) => token == expected,
+        (Some(_), None) => true,
+    };
+
+    if !authorized {
+        return ApiError::unauthorized().into_response();
+    }
+
+    next.run(request).await
+}
+
+pub(crate) fn lock(store: &SharedStore) -> std::sync::MutexGuard<'_, Store> {
+    store
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Parses a JSON request body. An empty body is treated as `{}`.
+pub(crate) fn parse_body(body: &[u8]) -> Result<Value, ApiError> {
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(Value::Object(Default::default()));
+    }
+
+    match serde_json::from_slice::<Value>(body) {
+        Ok(value @ Value::Object(_)) => Ok(value),
+        _ => Err(ApiError::invalid_json()),
+    }
+}
+
+/// Reads the Notion-shaped parent of a request body: `{"page_id": ...}`,
+/// `{"data_source_id": ...}`, `{"database_id": ...}`, `{"block_id": ...}` or
+/// `{"workspace": true}`, with or without `type`.
+pub(crate) fn parse_parent(value: &Value) -> Result<(String, String), ApiError> {
+    for key in ["page_id", "data_source_id", "database_id", "block_id"] {
+        if let Some(id) = value[key].as_str() {
+            return Ok((key.to_string(), id.to_string()));
+        }
+    }
+
+    if value["workspace"].as_bool() == Some(true) {
+        return Ok(("workspace".to_string(), String::new()));
+    }
+
+    Err(ApiError::validation(
+        "body.parent should define `page_id`, `data_source_id`, `database_id`, `block_id` or `workspace`.",
+    ))
+}
+
+/// A rendered JSON object in a handler result.
+pub(crate) fn ok(value: Value) -> ApiResult {
+    Ok(axum::Json(value))
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::MockServer;
+
+    /// A client that sends every request to `server`.
+    pub(crate) fn client(server: &MockServer) -> notionrs::Client {
+        notionrs::Client::builder()
+            .notion_api_key("secret_mock")
+            .base_url(server.base_url())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn rejects_requests_without_a_valid_token() {
+        let server = MockServer::start().await;
+        server.require_token("secret_expected");
+
+        let error = client(&server).get_self().send().await.unwrap_err();
+
+        assert!(error.is_unauthorized());
+        assert!(error.request_id().is_some());
+    }
+
+    #[tokio::test]
+    async fn unknown_routes_are_invalid_request_urls() {
+        let server = MockServer::start().await;
+
+        let response = reqwest_get(&format!("{}/v1/unknown", server.base_url())).await;
+
+        assert_eq!(response.0, 400);
+        assert_eq!(response.1["code"], "invalid_request_url");
+    }
+
+    #[tokio::test]
+    async fn requests_without_notion_version_are_rejected() {
+        let server = MockServer::start().await;
+
+        let response =
+            reqwest_get_without_version(&format!("{}/v1/users/me", server.base_url())).await;
+
+        assert_eq!(response.0, 400);
+        assert_eq!(response.1["code"], "missing_version");
+    }
+
+    async fn reqwest_get(url: &str) -> (u16, serde_json::Value) {
+        send(url, true).await
+    }
+
+    async fn reqwest_get_without_version(url: &str) -> (u16, serde_json::Value) {
+        send(url, false).await
+    }
+
+    /// A raw HTTP/1.1 GET, to send requests notionrs never would.
+    async fn send(url: &str, with_version: bool) -> (u16, serde_json::Value) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let address = url.trim_start_matches("http://");
+        let (host, path) = address.split_at(address.find('/').unwrap());
+
+        let mut stream = tokio::net::TcpStream::connect(host).await.unwrap();
+        let version = if with_version {
+            "Notion-Version: 2026-03-11\r\n"
+        } else {
+            ""
+        };
+        stream
+            .write_all(
+                format!(
+                    "GET {path} HTTP/1.1\r\nHost: {host}\r\nAuthorization: Bearer secret\r\n{version}Connection: close\r\n\r\n"
+                )
+                .as_bytes(),
+            )
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        let status = response[9..12].parse().unwrap();
+        let body = &response[response.find("\r\n\r\n").unwrap() + 4..];
+
+        (status, serde_json::from_str(body).unwrap())
+    }
+}
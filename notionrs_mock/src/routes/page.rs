@@ -0,0 +1,470 @@
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use serde_json::{Map, Value, json};
+
+use super::{ApiResult, SharedStore, lock, ok, parse_body, parse_parent};
+use crate::error::ApiError;
+use crate::properties::{default_value, find_in_schema, normalize_value, page_title};
+use crate::store::{Block, Page, Store, format_time, notion_url, now, same_id};
+
+/// The schema of a page under a page or the workspace: just its title.
+fn title_schema() -> Map<String, Value> {
+    Map::from_iter([(
+        "title".to_string(),
+        json!({ "id": "title", "name": "title", "type": "title", "title": {} }),
+    )])
+}
+
+/// The property schema of a page: its data source's, or [`title_schema`].
+fn schema_of(store: &Store, page: &Page) -> Map<String, Value> {
+    page.parent["data_source_id"]
+        .as_str()
+        .and_then(|id| store.data_source(id))
+        .map(|data_source| data_source.properties.clone())
+        .unwrap_or_else(title_schema)
+}
+
+pub(crate) fn render(store: &Store, page: &Page) -> Value {
+    let properties = schema_of(store, page)
+        .iter()
+        .map(|(name, schema_property)| {
+            let value = page
+                .properties
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| default_value(store, page, schema_property));
+            (name.clone(), value)
+        })
+        .collect::<Map<_, _>>();
+
+    json!({
+        "object": "page",
+        "id": page.id,
+        "created_time": format_time(page.created_time),
+        "last_edited_time": format_time(page.last_edited_time),
+        "created_by": store.bot_reference(),
+        "last_edited_by": store.bot_reference(),
+        "cover": page.cover,
+        "icon": page.icon,
+        "parent": page.parent,
+        "archived": page.in_trash,
+        "in_trash": page.in_trash,
+        "is_locked": false,
+        "properties": properties,
+        "url": notion_url(&page.id),
+        "public_url": null,
+        "request_id": crate::request_id(),
+    })
+}
+
+/// Normalizes the `properties` of a create or update body against `schema`,
+/// returning them keyed by property name.
+fn normalize_properties(
+    store: &mut Store,
+    schema: &mut Map<String, Value>,
+    properties: &Value,
+) -> Result<Map<String, Value>, ApiError> {
+    let properties = match properties {
+        Value::Null => return Ok(Map::new()),
+        Value::Object(properties) => properties,
+        _ => return Err(ApiError::validation("body.properties should be an object.")),
+    };
+
+    let mut normalized = Map::new();
+
+    for (key, value) in properties {
+        let name = find_in_schema(schema, key)
+            .ok_or_else(|| ApiError::validation(format!("{key} is not a property that exists.")))?
+            .to_string();
+
+        let schema_property = schema
+            .get_mut(&name)
+            .ok_or_else(|| ApiError::validation(format!("{key} is not a property that exists.")))?;
+
+        let value = normalize_value(store, &name, schema_property, value)?;
+        normalized.insert(name, value);
+    }
+
+    Ok(normalized)
+}
+
+/// Validates an `icon` or `cover` from a request body.
+fn decoration<T: serde::de::DeserializeOwned>(
+    body: &Value,
+    field: &str,
+) -> Result<Option<Value>, ApiError> {
+    match body.get(field) {
+        None => Ok(None),
+        Some(Value::Null) => Ok(Some(Value::Null)),
+        Some(value) => {
+            serde_json::from_value::<T>(value.clone())
+                .map_err(|e| ApiError::validation(format!("body.{field} is invalid: {e}")))?;
+            Ok(Some(value.clone()))
+        }
+    }
+}
+
+/// Keeps the `child_page` block of a page in sync with its title and trash state.
+fn sync_child_page_block(store: &mut Store, page_id: &str) {
+    let Some(page) = store.page(page_id).cloned() else {
+        return;
+    };
+
+    if let Some(block) = store
+        .blocks
+        .iter_mut()
+        .find(|block| same_id(&block.id, page_id))
+    {
+        block.content =
+            json!({ "type": "child_page", "child_page": { "title": page_title(&page) } });
+        block.in_trash = page.in_trash;
+        block.last_edited_time = page.last_edited_time;
+    }
+}
+
+/// `POST /v1/pages`
+pub(crate) async fn create_page(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let body = parse_body(&body)?;
+
+    if !body["markdown"].is_null() {
+        return Err(ApiError::validation(
+            "notionrs_mock does not support `markdown` page content. Use `children` instead.",
+        ));
+    }
+    if !body["template"].is_null() {
+        return Err(ApiError::validation(
+            "notionrs_mock does not support page templates.",
+        ));
+    }
+
+    let (parent_type, parent_id) = parse_parent(&body["parent"])?;
+    let mut store = lock(&store);
+
+    let (parent, mut schema) = match parent_type.as_str() {
+        "page_id" => {
+            let parent = store
+                .page(&parent_id)
+                .filter(|page| !page.in_trash)
+                .ok_or_else(|| ApiError::not_found("page", &parent_id))?;
+            (
+                json!({ "type": "page_id", "page_id": parent.id }),
+                title_schema(),
+            )
+        }
+        "data_source_id" | "database_id" => {
+            let data_source = store
+                .data_sources
+                .iter()
+                .find(|data_source| {
+                    !data_source.in_trash
+                        && (same_id(&data_source.id, &parent_id)
+                            || same_id(&data_source.database_id, &parent_id))
+                })
+                .ok_or_else(|| ApiError::not_found("data source", &parent_id))?;
+            (
+                json!({
+                    "type": "data_source_id",
+                    "data_source_id": data_source.id,
+                    "database_id": data_source.database_id,
+                }),
+                data_source.properties.clone(),
+            )
+        }
+        _ => {
+            return Err(ApiError::validation(
+                "body.parent should be a page_id or data_source_id.",
+            ));
+        }
+    };
+
+    let properties = normalize_properties(&mut store, &mut schema, &body["properties"])?;
+    let icon = decoration::<notionrs_types::object::emoji_and_icon::EmojiAndIcon>(&body, "icon")?;
+    let cover = decoration::<notionrs_types::object::file::File>(&body, "cover")?;
+
+    let children = match &body["children"] {
+        Value::Null => Vec::new(),
+        Value::Array(children) => children.clone(),
+        _ => return Err(ApiError::validation("body.children should be an array.")),
+    };
+    super::block::validate_children(&children)?;
+
+    // Creating options on the fly changes the data source schema.
+    if let Some(data_source_id) = parent["data_source_id"].as_str() {
+        if let Some(data_source) = store
+            .data_sources
+            .iter_mut()
+            .find(|data_source| data_source.id == data_source_id)
+        {
+            data_source.properties = schema;
+        }
+    }
+
+    let number = match parent["data_source_id"].as_str() {
+        Some(data_source_id) => {
+            store
+                .pages
+                .iter()
+                .filter(|page| page.parent["data_source_id"] == data_source_id)
+                .count() as u64
+                + 1
+        }
+        None => 0,
+    };
+
+    let now = now();
+    let page = Page {
+        id: store.new_id(),
+        parent: parent.clone(),
+        properties,
+        icon: icon.unwrap_or(Value::Null),
+        cover: cover.unwrap_or(Value::Null),
+        created_time: now,
+        last_edited_time: now,
+        in_trash: false,
+        number,
+    };
+    let page_id = page.id.clone();
+
+    if let Some(parent_page_id) = parent["page_id"].as_str() {
+        store.blocks.push(Block {
+            id: page_id.clone(),
+            parent_id: parent_page_id.to_string(),
+            parent: parent.clone(),
+            content: json!({ "type": "child_page", "child_page": { "title": page_title(&page) } }),
+            created_time: now,
+            last_edited_time: now,
+            in_trash: false,
+        });
+    }
+
+    store.pages.push(page);
+
+    let end = store.blocks.len();
+    super::block::insert_children(&mut store, &page_id, &children, end)?;
+
+    let page = store
+        .page(&page_id)
+        .ok_or_else(|| ApiError::not_found("page", &page_id))?;
+
+    ok(render(&store, page))
+}
+
+/// `GET /v1/pages/{page_id}`
+pub(crate) async fn get_page(
+    State(store): State<SharedStore>,
+    Path(page_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+
+    let page = store
+        .page(&page_id)
+        .ok_or_else(|| ApiError::not_found("page", &page_id))?;
+
+    ok(render(&store, page))
+}
+
+/// `PATCH /v1/pages/{page_id}`
+pub(crate) async fn update_page(
+    State(store): State<SharedStore>,
+    Path(page_id): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let body = parse_body(&body)?;
+
+    if !body["template"].is_null() {
+        return Err(ApiError::validation(
+            "notionrs_mock does not support page templates.",
+        ));
+    }
+
+    let mut store = lock(&store);
+
+    let page = store
+        .page(&page_id)
+        .cloned()
+        .ok_or_else(|| ApiError::not_found("page", &page_id))?;
+
+    let in_trash = body["in_trash"].as_bool().or(body["archived"].as_bool());
+    let edits = ["properties", "icon", "cover"]
+        .iter()
+        .any(|field| body.get(field).is_some_and(|value| !is_empty(value)));
+
+    if page.in_trash && in_trash != Some(false) && edits {
+        return Err(ApiError::validation(
+            "Can't edit page that is archived. You must unarchive the page before editing.",
+        ));
+    }
+
+    let mut schema = schema_of(&store, &page);
+    let properties = normalize_properties(&mut store, &mut schema, &body["properties"])?;
+    let icon = decoration::<notionrs_types::object::emoji_and_icon::EmojiAndIcon>(&body, "icon")?;
+    let cover = decoration::<notionrs_types::object::file::File>(&body, "cover")?;
+
+    if let Some(data_source_id) = page.parent["data_source_id"].as_str() {
+        if let Some(data_source) = store
+            .data_sources
+            .iter_mut()
+            .find(|data_source| data_source.id == data_source_id)
+        {
+            data_source.properties = schema;
+        }
+    }
+
+    let page = store
+        .page_mut(&page_id)
+        .ok_or_else(|| ApiError::not_found("page", &page_id))?;
+
+    page.properties.extend(properties);
+    if let Some(icon) = icon {
+        page.icon = icon;
+    }
+    if let Some(cover) = cover {
+        page.cover = cover;
+    }
+    if let Some(in_trash) = in_trash {
+        page.in_trash = in_trash;
+    }
+    page.last_edited_time = now();
+
+    let page_id = page.id.clone();
+    sync_child_page_block(&mut store, &page_id);
+
+    let page = store
+        .page(&page_id)
+        .ok_or_else(|| ApiError::not_found("page", &page_id))?;
+
+    ok(render(&store, page))
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::HashMap;
+
+    use crate::MockServer;
+    use crate::routes::unit_tests::client;
+
+    use notionrs_types::object::block::Block;
+    use notionrs_types::object::page::PageProperty;
+    use notionrs_types::object::page::title::PageTitleProperty;
+
+    fn title(text: &str) -> HashMap<String, PageProperty> {
+        HashMap::from([(
+            "title".to_string(),
+            PageProperty::Title(PageTitleProperty::from(text)),
+        )])
+    }
+
+    #[tokio::test]
+    async fn create_get_and_update_page() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+
+        let created = client
+            .create_page::<HashMap<String, PageProperty>>()
+            .page_id(server.root_page_id())
+            .properties(title("Hello"))
+            .children(vec![Block::Paragraph {
+                paragraph: "body".into(),
+            }])
+            .send()
+            .await
+            .unwrap()
+            .into_page()
+            .unwrap();
+        assert_eq!(created.properties["title"].to_string(), "Hello");
+
+        let children = client
+            .get_block_children()
+            .block_id(&created.id)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(children.results[0].block.to_string(), "body");
+
+        let parent_children = client
+            .get_block_children()
+            .block_id(server.root_page_id())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(parent_children.results[0].id, created.id);
+        assert_eq!(parent_children.results[0].block.to_string(), "Hello");
+
+        let updated = client
+            .update_page::<HashMap<String, PageProperty>>()
+            .page_id(&created.id)
+            .properties(title("Renamed"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(updated.properties["title"].to_string(), "Renamed");
+
+        let trashed = client
+            .update_page::<HashMap<String, PageProperty>>()
+            .page_id(&created.id)
+            .in_trash(true)
+            .send()
+            .await
+            .unwrap();
+        assert!(trashed.in_trash);
+
+        let retrieved = client
+            .get_page::<HashMap<String, PageProperty>>()
+            .page_id(&created.id)
+            .send()
+            .await
+            .unwrap();
+        assert!(retrieved.in_trash);
+        assert_eq!(retrieved.properties["title"].to_string(), "Renamed");
+    }
+
+    #[tokio::test]
+    async fn unknown_pages_are_not_found() {
+        let server = MockServer::start().await;
+
+        let error = client(&server)
+            .get_page::<HashMap<String, PageProperty>>()
+            .page_id("00000000-0000-0000-0000-000000000000")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(error.is_not_found());
+        assert_eq!(error.status(), Some(404));
+    }
+
+    #[tokio::test]
+    async fn unknown_properties_are_validation_errors() {
+        let server = MockServer::start().await;
+
+        let error = client(&server)
+            .create_page::<HashMap<String, PageProperty>>()
+            .page_id(server.root_page_id())
+            .properties(HashMap::from([(
+                "Missing".to_string(),
+                PageProperty::Title(PageTitleProperty::from("Hello")),
+            )]))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.status(), Some(400));
+        assert_eq!(
+            error.code(),
+            Some(&notionrs::error::ApiErrorCode::ValidationError)
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use axum::body::Bytes;
+use axum::extract::State;
+use serde_json::Value;
+
+use super::{ApiResult, SharedStore, lock, ok, parse_body};
+use crate::error::ApiError;
+use crate::pagination::{PageParams, list};
+use crate::properties::page_title;
+
+/// `POST /v1/search`
+///
+/// Matches titles case-insensitively and orders results by `last_edited_time`,
+/// newest first unless `sort.direction` is `ascending`.
+pub(crate) async fn search(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let body = parse_body(&body)?;
+    let params = PageParams::from_body(&body)?;
+
+    let query = body["query"].as_str().unwrap_or_default().to_lowercase();
+    let in_trash = body["filter"]["in_trash"].as_bool().unwrap_or(false);
+
+    let (pages, data_sources) = match body["filter"]["value"].as_str() {
+        None => (true, true),
+        Some("page") => (true, false),
+        Some("data_source") => (false, true),
+        Some(value) => {
+            return Err(ApiError::validation(format!(
+                "body.filter.value should be `page` or `data_source`, instead was `{value}`."
+            )));
+        }
+    };
+
+    let ascending = match body["sort"]["direction"].as_str() {
+        None | Some("descending") => false,
+        Some("ascending") => true,
+        Some(direction) => {
+            return Err(ApiError::validation(format!(
+                "body.sort.direction should be `ascending` or `descending`, instead was `{direction}`."
+            )));
+        }
+    };
+
+    let store = lock(&store);
+    let mut results = Vec::new();
+
+    if pages {
+        results.extend(
+            store
+                .pages
+                .iter()
+                .filter(|page| page.in_trash == in_trash)
+                .filter(|page| page_title(page).to_lowercase().contains(&query))
+                .map(|page| (page.last_edited_time, super::page::render(&store, page))),
+        );
+    }
+
+    if data_sources {
+        results.extend(
+            store
+                .data_sources
+                .iter()
+                .filter(|data_source| data_source.in_trash == in_trash)
+                .filter(|data_source| {
+                    crate::rich_text::plain_text(&data_source.title)
+                        .to_lowercase()
+                        .contains(&query)
+                })
+                .map(|data_source| {
+                    (
+                        data_source.last_edited_time,
+                        super::data_source::render(&store, data_source),
+                    )
+                }),
+        );
+    }
+
+    results.sort_by_key(|(last_edited_time, _)| *last_edited_time);
+    if !ascending {
+        results.reverse();
+    }
+
+    let results = results
+        .into_iter()
+        .map(|(_, result): (_, Value)| {
+            (
+                result["id"].as_str().unwrap_or_default().to_string(),
+                result,
+            )
+        })
+        .collect();
+
+    ok(list(results, "page_or_data_source", &params)?)
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::HashMap;
+
+    use crate::MockServer;
+    use crate::routes::unit_tests::client;
+
+    use notionrs_types::object::page::PageProperty;
+
+    #[tokio::test]
+    async fn search_pages_by_title() {
+        let server = MockServer::start().await;
+        let client = client(&server);
+
+        for title in ["Meeting notes", "Roadmap", "Weekly meeting"] {
+            client
+                .create_page::<HashMap<String, PageProperty>>()
+                .page_id(server.root_page_id())
+                .properties(HashMap::from([(
+                    "title".to_string(),
+                    PageProperty::Title(title.into()),
+                )]))
+                .send()
+                .await
+                .unwrap();
+        }
+
+        let results = client.search_page().query("MEETING").send().await.unwrap();
+
+        let titles = results
+            .results
+            .iter()
+            .map(|page| page.properties["title"].to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(titles.len(), 2);
+        assert!(titles.contains(&"Meeting notes".to_string()));
+        assert!(titles.contains(&"Weekly meeting".to_string()));
+    }
+}
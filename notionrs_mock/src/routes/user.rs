@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use axum::extract::{Path, Query, State};
+
+use super::{ApiResult, SharedStore, lock, ok};
+use crate::error::ApiError;
+use crate::pagination::{PageParams, list};
+
+/// `GET /v1/users`
+pub(crate) async fn list_users(
+    State(store): State<SharedStore>,
+    Query(query): Query<HashMap<String, String>>,
+) -> ApiResult {
+    let params = PageParams::from_query(&query)?;
+    let store = lock(&store);
+
+    let users = store
+        .users
+        .iter()
+        .map(|user| {
+            (
+                user["id"].as_str().unwrap_or_default().to_string(),
+                user.clone(),
+            )
+        })
+        .collect();
+
+    ok(list(users, "user", &params)?)
+}
+
+/// `GET /v1/users/me`
+pub(crate) async fn get_self(State(store): State<SharedStore>) -> ApiResult {
+    let store = lock(&store);
+
+    let bot = store
+        .user(&store.bot_id)
+        .cloned()
+        .ok_or_else(|| ApiError::not_found("user", &store.bot_id))?;
+
+    ok(bot)
+}
+
+/// `GET /v1/users/{user_id}`
+pub(crate) async fn get_user(
+    State(store): State<SharedStore>,
+    Path(user_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+
+    let user = store
+        .user(&user_id)
+        .cloned()
+        .ok_or_else(|| ApiError::not_found("user", &user_id))?;
+
+    ok(user)
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::MockServer;
+    use crate::routes::unit_tests::client;
+
+    #[tokio::test]
+    async fn get_self_returns_the_bot_user() {
+        let server = MockServer::start().await;
+
+        let user = client(&server).get_self().send().await.unwrap();
+
+        assert_eq!(user.id, server.bot_id());
+        assert_eq!(user.r#type.as_deref(), Some("bot"));
+    }
+
+    #[tokio::test]
+    async fn list_users_is_paginated() {
+        let server = MockServer::start().await;
+        let alice = server.add_user("Alice", "alice@example.com");
+        server.add_user("Bob", "bob@example.com");
+
+        let client = client(&server);
+
+        let first = client.list_users().page_size(2).send().await.unwrap();
+        assert_eq!(first.results.len(), 2);
+        assert_eq!(first.has_more, Some(true));
+        assert_eq!(first.results[1].id, alice);
+
+        let second = client
+            .list_users()
+            .page_size(2)
+            .start_cursor(first.next_cursor.unwrap())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(second.results.len(), 1);
+        assert_eq!(second.has_more, Some(false));
+        assert_eq!(second.results[0].name.as_deref(), Some("Bob"));
+    }
+
+    #[tokio::test]
+    async fn get_unknown_user_is_not_found() {
+        let server = MockServer::start().await;
+
+        let error = client(&server)
+            .get_user()
+            .user_id("00000000-0000-0000-0000-000000000000")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(error.is_not_found());
+    }
+}
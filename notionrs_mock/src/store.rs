@@ -0,0 +1,257 @@
+use serde_json::{Map, Value, json};
+
+/// Everything the mock server knows about, shared by all request handlers.
+#[derive(Debug)]
+pub(crate) struct Store {
+    /// Base URL of the server, used for `upload_url`s.
+    pub(crate) base_url: String,
+
+    /// Bearer token every request must carry. Any token is accepted when unset.
+    pub(crate) token: Option<String>,
+
+    next_id: u64,
+
+    /// The integration's bot user, returned by `GET /v1/users/me`.
+    pub(crate) bot_id: String,
+
+    /// A page shared with the integration that new pages can be created under.
+    pub(crate) root_page_id: String,
+
+    pub(crate) users: Vec<Value>,
+
+    pub(crate) pages: Vec<Page>,
+
+    /// Blocks in document order. Children of a parent are the blocks with its
+    /// `parent_id`, in the order they appear here.
+    pub(crate) blocks: Vec<Block>,
+
+    pub(crate) data_sources: Vec<DataSource>,
+
+    pub(crate) comments: Vec<Comment>,
+
+    pub(crate) file_uploads: Vec<FileUpload>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Page {
+    pub(crate) id: String,
+    /// Notion-shaped parent object, including its `type`.
+    pub(crate) parent: Value,
+    /// Property values keyed by property name. Each value is a serialized `PageProperty`.
+    pub(crate) properties: Map<String, Value>,
+    pub(crate) icon: Value,
+    pub(crate) cover: Value,
+    pub(crate) created_time: time::OffsetDateTime,
+    pub(crate) last_edited_time: time::OffsetDateTime,
+    pub(crate) in_trash: bool,
+    /// Value of `unique_id` properties for pages in a data source.
+    pub(crate) number: u64,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Block {
+    pub(crate) id: String,
+    pub(crate) parent_id: String,
+    /// Notion-shaped parent object, including its `type`.
+    pub(crate) parent: Value,
+    /// `{"type": "<type>", "<type>": {...}}` without nested `children`.
+    pub(crate) content: Value,
+    pub(crate) created_time: time::OffsetDateTime,
+    pub(crate) last_edited_time: time::OffsetDateTime,
+    pub(crate) in_trash: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DataSource {
+    pub(crate) id: String,
+    pub(crate) database_id: String,
+    pub(crate) title: Value,
+    /// Property schema keyed by property name, each with `id`, `name` and `type`.
+    pub(crate) properties: Map<String, Value>,
+    pub(crate) icon: Value,
+    pub(crate) created_time: time::OffsetDateTime,
+    pub(crate) last_edited_time: time::OffsetDateTime,
+    pub(crate) in_trash: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Comment {
+    pub(crate) id: String,
+    /// ID of the page or block the discussion belongs to.
+    pub(crate) parent_id: String,
+    pub(crate) parent: Value,
+    pub(crate) discussion_id: String,
+    pub(crate) rich_text: Value,
+    pub(crate) created_time: time::OffsetDateTime,
+    pub(crate) last_edited_time: time::OffsetDateTime,
+    pub(crate) in_trash: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct FileUpload {
+    pub(crate) id: String,
+    pub(crate) mode: String,
+    pub(crate) status: String,
+    pub(crate) filename: Option<String>,
+    pub(crate) content_type: Option<String>,
+    pub(crate) content_length: Option<u64>,
+    /// `(total, sent)` for multi-part uploads.
+    pub(crate) parts: Option<(u32, u32)>,
+    pub(crate) created_time: time::OffsetDateTime,
+    pub(crate) last_edited_time: time::OffsetDateTime,
+}
+
+impl Store {
+    pub(crate) fn new() -> Self {
+        let mut store = Self {
+            base_url: String::new(),
+            token: None,
+            next_id: 0,
+            bot_id: String::new(),
+            root_page_id: String::new(),
+            users: Vec::new(),
+            pages: Vec::new(),
+            blocks: Vec::new(),
+            data_sources: Vec::new(),
+            comments: Vec::new(),
+            file_uploads: Vec::new(),
+        };
+
+        store.bot_id = store.new_id();
+        store.users.push(json!({
+            "object": "user",
+            "id": store.bot_id,
+            "name": "notionrs_mock",
+            "avatar_url": null,
+            "type": "bot",
+            "bot": {
+                "owner": { "type": "workspace", "workspace": true },
+                "workspace_name": "Mock workspace"
+            }
+        }));
+
+        let now = now();
+        store.root_page_id = store.new_id();
+        store.pages.push(Page {
+            id: store.root_page_id.clone(),
+            parent: json!({ "type": "workspace", "workspace": true }),
+            properties: Map::from_iter([(
+                "title".to_string(),
+                json!({
+                    "id": "title",
+                    "type": "title",
+                    "title": [crate::rich_text::text("Mock workspace")]
+                }),
+            )]),
+            icon: Value::Null,
+            cover: Value::Null,
+            created_time: now,
+            last_edited_time: now,
+            in_trash: false,
+            number: 0,
+        });
+
+        store
+    }
+
+    /// A new UUID-formatted ID, unique within this store.
+    pub(crate) fn new_id(&mut self) -> String {
+        self.next_id += 1;
+        format!("00000000-0000-4000-8000-{:012x}", self.next_id)
+    }
+
+    pub(crate) fn user(&self, id: &str) -> Option<&Value> {
+        self.users.iter().find(|user| {
+            user["id"]
+                .as_str()
+                .is_some_and(|user_id| same_id(user_id, id))
+        })
+    }
+
+    /// `{"object": "user", "id": ...}`, the partial user Notion embeds in other objects.
+    pub(crate) fn bot_reference(&self) -> Value {
+        json!({ "object": "user", "id": self.bot_id })
+    }
+
+    pub(crate) fn page(&self, id: &str) -> Option<&Page> {
+        self.pages.iter().find(|page| same_id(&page.id, id))
+    }
+
+    pub(crate) fn page_mut(&mut self, id: &str) -> Option<&mut Page> {
+        self.pages.iter_mut().find(|page| same_id(&page.id, id))
+    }
+
+    pub(crate) fn block(&self, id: &str) -> Option<&Block> {
+        self.blocks.iter().find(|block| same_id(&block.id, id))
+    }
+
+    pub(crate) fn data_source(&self, id: &str) -> Option<&DataSource> {
+        self.data_sources
+            .iter()
+            .find(|data_source| same_id(&data_source.id, id))
+    }
+
+    pub(crate) fn comment_mut(&mut self, id: &str) -> Option<&mut Comment> {
+        self.comments
+            .iter_mut()
+            .find(|comment| !comment.in_trash && same_id(&comment.id, id))
+    }
+
+    pub(crate) fn file_upload(&self, id: &str) -> Option<&FileUpload> {
+        self.file_uploads
+            .iter()
+            .find(|file_upload| same_id(&file_upload.id, id))
+    }
+
+    pub(crate) fn file_upload_mut(&mut self, id: &str) -> Option<&mut FileUpload> {
+        self.file_uploads
+            .iter_mut()
+            .find(|file_upload| same_id(&file_upload.id, id))
+    }
+
+    /// Whether `id` is a page or block that can hold children, and isn't in the trash.
+    pub(crate) fn is_live_parent(&self, id: &str) -> bool {
+        self.page(id).is_some_and(|page| !page.in_trash)
+            || self.block(id).is_some_and(|block| !block.in_trash)
+    }
+
+    /// The live (not trashed) children of a page or block, in order.
+    pub(crate) fn children(&self, parent_id: &str) -> impl Iterator<Item = &Block> {
+        self.blocks
+            .iter()
+            .filter(move |block| !block.in_trash && same_id(&block.parent_id, parent_id))
+    }
+}
+
+/// Compares Notion IDs, which may be given with or without hyphens.
+pub(crate) fn same_id(a: &str, b: &str) -> bool {
+    a.chars()
+        .filter(|c| *c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .eq(b
+            .chars()
+            .filter(|c| *c != '-')
+            .map(|c| c.to_ascii_lowercase()))
+}
+
+/// The current time, truncated to milliseconds like Notion's timestamps.
+pub(crate) fn now() -> time::OffsetDateTime {
+    let now = time::OffsetDateTime::now_utc();
+    now.replace_millisecond(now.millisecond()).unwrap_or(now)
+}
+
+/// Formats a timestamp the way Notion does, e.g. `2025-08-31T16:29:00.000Z`.
+pub(crate) fn format_time(time: time::OffsetDateTime) -> String {
+    let format = time::macros::format_description!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z"
+    );
+
+    time.to_offset(time::UtcOffset::UTC)
+        .format(&format)
+        .unwrap_or_default()
+}
+
+/// The `https://www.notion.so/...` URL of a page.
+pub(crate) fn notion_url(id: &str) -> String {
+    format!("https://www.notion.so/{}", id.replace('-', ""))
+}
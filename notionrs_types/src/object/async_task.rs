@@ -80,6 +80,54 @@ pub struct AsyncTaskSucceeded {
     pub result: std::collections::HashMap<String, serde_json::Value>,
 }
 
+impl AsyncTaskSucceeded {
+    /// Decode `result` into `R`, e.g. a [`PageResponse`](crate::object::page::PageResponse).
+    pub fn result_as<R>(&self) -> Result<R, serde_json::Error>
+    where
+        R: serde::de::DeserializeOwned,
+    {
+        serde_json::from_value(serde_json::Value::Object(
+            self.result
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        ))
+    }
+
+    /// Decode `result` into the payload of the operation named in `operation.name`.
+    ///
+    /// Falls back to [`AsyncTaskResult::Other`] for operations without a typed
+    /// payload, and for results that only carry part of the object (e.g. its `id`).
+    pub fn typed_result(&self) -> AsyncTaskResult {
+        let typed = match self.operation.name.as_str() {
+            "create_page" | "update_page" | "move_page" => self
+                .result_as::<crate::object::page::PageResponse>()
+                .map(|page| AsyncTaskResult::Page(Box::new(page)))
+                .ok(),
+            "update_page_markdown" => self
+                .result_as::<crate::object::page_markdown::PageMarkdownResponse>()
+                .map(AsyncTaskResult::PageMarkdown)
+                .ok(),
+            _ => None,
+        };
+
+        typed.unwrap_or_else(|| AsyncTaskResult::Other(self.result.clone()))
+    }
+}
+
+/// The result of a succeeded async task, decoded by [`AsyncTaskSucceeded::typed_result`].
+#[derive(Debug, Clone)]
+pub enum AsyncTaskResult {
+    /// The page returned by `create_page`, `update_page` or `move_page`.
+    Page(Box<crate::object::page::PageResponse>),
+
+    /// The markdown returned by `update_page_markdown`.
+    PageMarkdown(crate::object::page_markdown::PageMarkdownResponse),
+
+    /// Any other result, as returned by the API.
+    Other(std::collections::HashMap<String, serde_json::Value>),
+}
+
 /// Fields for a task that failed.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct AsyncTaskFailed {
@@ -279,6 +327,48 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn typed_result_by_operation() {
+        let succeeded = |name: &str, result: serde_json::Value| AsyncTaskSucceeded {
+            object: "async_task".to_string(),
+            id: "task-id-123".to_string(),
+            status_url: "https://api.notion.com/v1/async_tasks/task-id-123".to_string(),
+            created_time: "2026-01-01T00:00:00.000Z".to_string(),
+            operation: AsyncTaskOperation {
+                surface: AsyncTaskOperationSurface::Rest,
+                name: name.to_string(),
+            },
+            result: serde_json::from_value(result).unwrap(),
+        };
+
+        let markdown = succeeded(
+            "update_page_markdown",
+            serde_json::json!({
+                "object": "page_markdown",
+                "id": "page-id-456",
+                "markdown": "# Hello",
+                "truncated": false,
+                "unknown_block_ids": []
+            }),
+        );
+        match markdown.typed_result() {
+            AsyncTaskResult::PageMarkdown(markdown) => assert_eq!(markdown.markdown, "# Hello"),
+            other => panic!("Expected PageMarkdown, got {other:?}"),
+        }
+
+        // Only the ID of the created page.
+        let reference = succeeded("create_page", serde_json::json!({ "id": "page-id-456" }));
+        assert!(matches!(
+            reference.typed_result(),
+            AsyncTaskResult::Other(_)
+        ));
+
+        let id = reference
+            .result_as::<std::collections::HashMap<String, String>>()
+            .unwrap();
+        assert_eq!(id["id"], "page-id-456");
+    }
+
     #[test]
     fn deserialize_async_task_failed() {
         let json = r#"
@@ -17,6 +17,9 @@ pub struct CalloutBlock {
 
     /// The color of the block.
     pub color: crate::object::color::Color,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<super::Block>>,
 }
 
 impl CalloutBlock {
@@ -0,0 +1,1636 @@
+use super::{
+    Block, bulleted_list_item::BulletedListItemBlock, code::CodeBlock, equation::EquationBlock,
+    heading::HeadingBlock, numbered_list_item::NumberedListItemBlock, paragraph::ParagraphBlock,
+    quote::QuoteBlock, table::TableBlock, table_row::TableRowBlock, to_do::ToDoBlock,
+};
+use crate::object::file::{ExternalFile, File};
+use crate::object::language::Language;
+use crate::object::rich_text::{RichText, RichTextAnnotations, equation::Equation};
+
+/// Notion accepts at most 2000 characters in a rich text object.
+const MAX_TEXT_LENGTH: usize = 2000;
+
+impl Block {
+    /// Convert Markdown to blocks.
+    ///
+    /// Supports CommonMark with the GFM table, task list, strikethrough and
+    /// autolink extensions, plus `$$` equation blocks and `$` inline equations:
+    ///
+    /// - ATX and setext headings become `heading_1` to `heading_4`; levels 5
+    ///   and 6 become `heading_4` too.
+    /// - List items become `bulleted_list_item`, `numbered_list_item` or
+    ///   `to_do` blocks, with the blocks nested in them as children.
+    /// - Fenced and indented code becomes `code`, in the [`Language`] named by
+    ///   the info string (`rs`, `py` and other common aliases included).
+    /// - Block quotes, tables and thematic breaks become `quote`, `table` and
+    ///   `divider` blocks, and an image alone in a paragraph an `image` block.
+    ///
+    /// Emphasis, strong emphasis, strikethrough, code spans and links become
+    /// rich text annotations. Notion only accepts absolute URLs, so links and
+    /// images with a relative URL are kept as plain text. Raw HTML and
+    /// reference links are kept as they are written.
+    ///
+    /// The blocks may be nested deeper than a single request accepts, which
+    /// `Client::append_block_tree` takes care of.
+    pub fn from_markdown<T: AsRef<str>>(markdown: T) -> Vec<Block> {
+        let lines = markdown
+            .as_ref()
+            .lines()
+            .map(expand_tabs)
+            .collect::<Vec<_>>();
+
+        parse_blocks(&lines)
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// blocks
+//
+// # --------------------------------------------------------------------------------
+
+/// Replace the tabs of the indentation with spaces, to tab stops of 4.
+fn expand_tabs(line: &str) -> String {
+    let mut expanded = String::new();
+
+    for (index, c) in line.char_indices() {
+        match c {
+            ' ' => expanded.push(' '),
+            '\t' => expanded.extend(std::iter::repeat_n(' ', 4 - expanded.len() % 4)),
+            _ => {
+                expanded.push_str(&line[index..]);
+                break;
+            }
+        }
+    }
+
+    expanded
+}
+
+/// The number of leading spaces of a line, and the rest of it.
+fn indentation(line: &str) -> (usize, &str) {
+    let rest = line.trim_start_matches(' ');
+    (line.len() - rest.len(), rest)
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+fn parse_blocks(lines: &[String]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph = Vec::<&str>::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index].as_str();
+        let (indent, rest) = indentation(line);
+
+        if is_blank(line) {
+            blocks.extend(paragraph_block(&paragraph));
+            paragraph.clear();
+            index += 1;
+            continue;
+        }
+
+        if !paragraph.is_empty() && indent < 4 {
+            if let Some(level) = setext_level(rest) {
+                blocks.push(heading(level, &join_lines(&paragraph)));
+                paragraph.clear();
+                index += 1;
+                continue;
+            }
+        }
+
+        if paragraph.is_empty() && indent >= 4 {
+            let (block, consumed) = indented_code(&lines[index..]);
+            blocks.push(block);
+            index += consumed;
+            continue;
+        }
+
+        match block_start(&lines[index..], !paragraph.is_empty()) {
+            Some((parsed, consumed)) => {
+                blocks.extend(paragraph_block(&paragraph));
+                paragraph.clear();
+                blocks.extend(parsed);
+                index += consumed;
+            }
+            None => {
+                paragraph.push(line);
+                index += 1;
+            }
+        }
+    }
+
+    blocks.extend(paragraph_block(&paragraph));
+    blocks
+}
+
+/// The blocks starting at the first line and the number of lines they span,
+/// or `None` when the line is paragraph text.
+fn block_start(lines: &[String], in_paragraph: bool) -> Option<(Vec<Block>, usize)> {
+    let (indent, rest) = indentation(&lines[0]);
+
+    if indent >= 4 {
+        return None;
+    }
+
+    if let Some(block) = atx_heading(rest) {
+        return Some((vec![block], 1));
+    }
+
+    if is_thematic_break(rest) {
+        let divider = Block::Divider {
+            divider: std::collections::HashMap::new(),
+        };
+        return Some((vec![divider], 1));
+    }
+
+    fenced_code(lines)
+        .or_else(|| equation(lines))
+        .or_else(|| quote(lines))
+        .or_else(|| list(lines, in_paragraph))
+        .or_else(|| table(lines))
+}
+
+/// Whether the line starts a block, so it can't continue the paragraph of a
+/// list item or quote without the indentation or `>` marker.
+fn starts_block(line: &str) -> bool {
+    let (indent, rest) = indentation(line);
+
+    indent < 4
+        && (atx_heading(rest).is_some()
+            || is_thematic_break(rest)
+            || fence(rest).is_some()
+            || rest.starts_with("$$")
+            || rest.starts_with('>')
+            || list_marker(line).is_some())
+}
+
+/// A paragraph, or an image block for a paragraph that is only an image.
+fn paragraph_block(lines: &[&str]) -> Option<Block> {
+    if lines.is_empty() {
+        return None;
+    }
+
+    let text = join_lines(lines);
+
+    if let Some(image) = image(&text) {
+        return Some(image);
+    }
+
+    Some(Block::Paragraph {
+        paragraph: ParagraphBlock::default().rich_text(rich_text(&text)),
+    })
+}
+
+/// Join the lines of a paragraph, with line breaks where a line ends with a
+/// backslash or two spaces.
+fn join_lines(lines: &[&str]) -> String {
+    let mut text = String::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.trim_start();
+
+        if index + 1 == lines.len() {
+            text.push_str(line.trim_end());
+        } else if let Some(line) = line.strip_suffix('\\') {
+            text.push_str(line);
+            text.push('\n');
+        } else if line.ends_with("  ") {
+            text.push_str(line.trim_end());
+            text.push('\n');
+        } else {
+            text.push_str(line.trim_end());
+            text.push(' ');
+        }
+    }
+
+    text
+}
+
+fn image(text: &str) -> Option<Block> {
+    let chars = text.chars().collect::<Vec<_>>();
+
+    if !text.starts_with("![") {
+        return None;
+    }
+
+    let (alt, url, end) = link(&chars, 1)?;
+
+    if end != chars.len() || !is_absolute(&url) {
+        return None;
+    }
+
+    let mut image = File::External(ExternalFile::from(url));
+
+    if !alt.is_empty() {
+        image = image.caption(rich_text(&alt));
+    }
+
+    Some(Block::Image { image })
+}
+
+fn heading(level: usize, text: &str) -> Block {
+    let heading = HeadingBlock::default().rich_text(rich_text(text));
+
+    match level {
+        1 => Block::Heading1 { heading_1: heading },
+        2 => Block::Heading2 { heading_2: heading },
+        3 => Block::Heading3 { heading_3: heading },
+        _ => Block::Heading4 { heading_4: heading },
+    }
+}
+
+fn atx_heading(rest: &str) -> Option<Block> {
+    let level = rest.chars().take_while(|c| *c == '#').count();
+
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+
+    let text = &rest[level..];
+
+    if !(text.is_empty() || text.starts_with(' ')) {
+        return None;
+    }
+
+    // The closing sequence of `#`s is optional.
+    let text = text.trim();
+    let text = match text.trim_end_matches('#') {
+        "" => "",
+        stripped if stripped.ends_with(' ') => stripped.trim_end(),
+        _ => text,
+    };
+
+    Some(heading(level, text))
+}
+
+/// The level of the heading underlined by the line, if it is a setext
+/// heading underline.
+fn setext_level(rest: &str) -> Option<usize> {
+    let underline = rest.trim_end();
+
+    if underline.is_empty() {
+        None
+    } else if underline.chars().all(|c| c == '=') {
+        Some(1)
+    } else if underline.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn is_thematic_break(rest: &str) -> bool {
+    let mut chars = rest.chars().filter(|c| !c.is_whitespace());
+
+    let Some(first @ ('-' | '*' | '_')) = chars.next() else {
+        return false;
+    };
+
+    let mut count = 1;
+
+    for c in chars {
+        if c != first {
+            return false;
+        }
+        count += 1;
+    }
+
+    count >= 3
+}
+
+fn code_block(code: &str, language: Language) -> Block {
+    Block::Code {
+        code: CodeBlock {
+            caption: Vec::new(),
+            rich_text: plain_text(code),
+            language,
+        },
+    }
+}
+
+fn indented_code(lines: &[String]) -> (Block, usize) {
+    let mut code = Vec::new();
+
+    for line in lines {
+        if is_blank(line) {
+            code.push(line.get(4..).unwrap_or_default());
+        } else if indentation(line).0 >= 4 {
+            code.push(&line[4..]);
+        } else {
+            break;
+        }
+    }
+
+    let consumed = code.len();
+
+    while code.last().is_some_and(|line| is_blank(line)) {
+        code.pop();
+    }
+
+    (code_block(&code.join("\n"), Language::PlainText), consumed)
+}
+
+/// The character, length and info string of a code fence.
+fn fence(rest: &str) -> Option<(char, usize, &str)> {
+    let character = rest.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let length = rest.chars().take_while(|c| *c == character).count();
+    let info = rest[length..].trim();
+
+    if length < 3 || (character == '`' && info.contains('`')) {
+        return None;
+    }
+
+    Some((character, length, info))
+}
+
+fn fenced_code(lines: &[String]) -> Option<(Vec<Block>, usize)> {
+    let (indent, rest) = indentation(&lines[0]);
+    let (character, length, info) = fence(rest)?;
+
+    let mut code = Vec::new();
+    let mut end = 1;
+
+    while end < lines.len() {
+        let (line_indent, line_rest) = indentation(&lines[end]);
+
+        if line_indent < 4
+            && fence(line_rest)
+                .is_some_and(|(c, l, info)| c == character && l >= length && info.is_empty())
+        {
+            break;
+        }
+
+        // Content lines lose as much indentation as the opening fence has.
+        code.push(&lines[end][line_indent.min(indent)..]);
+        end += 1;
+    }
+
+    let consumed = (end + 1).min(lines.len());
+
+    Some((vec![code_block(&code.join("\n"), language(info))], consumed))
+}
+
+/// The language of a code block from its info string, e.g. `rust` or `py`.
+fn language(info: &str) -> Language {
+    // Rustdoc-style info strings add attributes after a comma, as in `rust,ignore`.
+    let name = info
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    let name = match name.as_str() {
+        "" | "text" | "txt" | "plain" | "plaintext" => "plain text",
+        "sh" | "zsh" | "console" | "shell-session" => "shell",
+        "rs" => "rust",
+        "py" | "python3" => "python",
+        "js" | "jsx" | "mjs" | "cjs" => "javascript",
+        "ts" | "tsx" => "typescript",
+        "rb" => "ruby",
+        "yml" => "yaml",
+        "md" => "markdown",
+        "cpp" | "cc" | "cxx" | "hpp" => "c++",
+        "cs" | "csharp" => "c#",
+        "fs" | "fsharp" => "f#",
+        "objc" | "objectivec" => "objective-c",
+        "golang" => "go",
+        "kt" | "kts" => "kotlin",
+        "dockerfile" => "docker",
+        "tex" => "latex",
+        "make" | "mk" => "makefile",
+        "hs" => "haskell",
+        "ex" | "exs" => "elixir",
+        "erl" => "erlang",
+        "ml" => "ocaml",
+        "pl" => "perl",
+        "ps1" | "pwsh" => "powershell",
+        "proto" => "protobuf",
+        "jl" => "julia",
+        "clj" => "clojure",
+        "coffee" => "coffeescript",
+        "gql" => "graphql",
+        "tf" => "hcl",
+        "patch" => "diff",
+        "jsonc" | "json5" => "json",
+        "htm" => "html",
+        "svg" => "xml",
+        "vb" => "visual basic",
+        "wasm" | "wat" => "webassembly",
+        name => name,
+    };
+
+    name.parse().unwrap_or_default()
+}
+
+fn equation(lines: &[String]) -> Option<(Vec<Block>, usize)> {
+    let equation = |expression: &str| Block::Equation {
+        equation: EquationBlock::from(expression.trim()),
+    };
+
+    let (_, rest) = indentation(&lines[0]);
+    let rest = rest.trim_end().strip_prefix("$$")?;
+
+    if let Some(expression) = rest.strip_suffix("$$") {
+        return Some((vec![equation(expression)], 1));
+    }
+
+    let mut expression = vec![rest];
+
+    for (index, line) in lines.iter().enumerate().skip(1) {
+        let line = line.trim_end();
+
+        if let Some(last) = line.strip_suffix("$$") {
+            expression.push(last);
+            return Some((vec![equation(&expression.join("\n"))], index + 1));
+        }
+
+        expression.push(line);
+    }
+
+    None
+}
+
+/// The text of the first paragraph, which Notion shows on the block itself,
+/// and the other blocks as its children.
+fn split_first_paragraph(mut blocks: Vec<Block>) -> (Vec<RichText>, Option<Vec<Block>>) {
+    let rich_text = match blocks.first_mut() {
+        Some(Block::Paragraph { paragraph }) => {
+            let rich_text = std::mem::take(&mut paragraph.rich_text);
+            blocks.remove(0);
+            rich_text
+        }
+        _ => Vec::new(),
+    };
+
+    (rich_text, (!blocks.is_empty()).then_some(blocks))
+}
+
+fn quote(lines: &[String]) -> Option<(Vec<Block>, usize)> {
+    let mut content = Vec::<String>::new();
+
+    for line in lines {
+        let (indent, rest) = indentation(line);
+
+        match rest.strip_prefix('>') {
+            Some(rest) if indent < 4 => {
+                content.push(rest.strip_prefix(' ').unwrap_or(rest).to_string())
+            }
+            // A paragraph in the quote may go on without the `>` marker.
+            _ if content.last().is_some_and(|last| !is_blank(last))
+                && !is_blank(line)
+                && !starts_block(line) =>
+            {
+                content.push(line.clone())
+            }
+            _ => break,
+        }
+    }
+
+    if content.is_empty() {
+        return None;
+    }
+
+    let consumed = content.len();
+    let (rich_text, children) = split_first_paragraph(parse_blocks(&content));
+
+    let quote = Block::Quote {
+        quote: QuoteBlock {
+            rich_text,
+            children,
+            ..Default::default()
+        },
+    };
+
+    Some((vec![quote], consumed))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ListMarker {
+    /// `-`, `+` or `*` for a bulleted list, `.` or `)` for a numbered one.
+    delimiter: char,
+
+    /// The number of a numbered list item.
+    number: Option<u32>,
+
+    /// The indentation of the lines of the item.
+    content_indent: usize,
+
+    /// Whether there is nothing after the marker.
+    empty: bool,
+}
+
+fn list_marker(line: &str) -> Option<ListMarker> {
+    let (indent, rest) = indentation(line);
+
+    if indent >= 4 {
+        return None;
+    }
+
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+
+    let (delimiter, number, marker_length) = if digits == 0 {
+        let delimiter = rest
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '-' | '+' | '*'))?;
+        (delimiter, None, 1)
+    } else if digits <= 9 {
+        let delimiter = rest[digits..]
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '.' | ')'))?;
+        (delimiter, Some(rest[..digits].parse().ok()?), digits + 1)
+    } else {
+        return None;
+    };
+
+    let after = &rest[marker_length..];
+    let spaces = after.len() - after.trim_start_matches(' ').len();
+
+    if spaces == 0 && !after.is_empty() {
+        return None;
+    }
+
+    let empty = is_blank(after);
+
+    // Content five spaces or more after the marker is an indented code block.
+    let spaces = if empty || spaces > 4 { 1 } else { spaces };
+
+    Some(ListMarker {
+        delimiter,
+        number,
+        content_indent: indent + marker_length + spaces,
+        empty,
+    })
+}
+
+fn list(lines: &[String], in_paragraph: bool) -> Option<(Vec<Block>, usize)> {
+    let first = list_marker(&lines[0])?;
+
+    // Only a list starting with `1.` or a bullet, and not empty, interrupts a paragraph.
+    if in_paragraph && (first.empty || first.number.is_some_and(|number| number != 1)) {
+        return None;
+    }
+
+    let same_list = |line: &str| {
+        list_marker(line).filter(|marker| {
+            marker.delimiter == first.delimiter && !is_thematic_break(indentation(line).1)
+        })
+    };
+
+    let mut blocks = Vec::new();
+    let mut index = 0;
+
+    while let Some(marker) = lines.get(index).and_then(|line| same_list(line)) {
+        let mut content = vec![
+            lines[index]
+                .get(marker.content_indent..)
+                .unwrap_or_default()
+                .to_string(),
+        ];
+        index += 1;
+
+        while let Some(line) = lines.get(index) {
+            if is_blank(line) {
+                content.push(String::new());
+            } else if indentation(line).0 >= marker.content_indent {
+                content.push(line[marker.content_indent..].to_string());
+            } else if content.last().is_some_and(|last| !is_blank(last)) && !starts_block(line) {
+                // A paragraph in the item may go on without the indentation.
+                content.push(line.clone());
+            } else {
+                break;
+            }
+            index += 1;
+        }
+
+        // The blank lines after the item belong to the list only when
+        // another item follows.
+        while content.len() > 1 && content.last().is_some_and(|last| is_blank(last)) {
+            content.pop();
+            index -= 1;
+        }
+
+        blocks.push(list_item(marker, content, blocks.is_empty()));
+
+        match lines[index..].iter().position(|line| !is_blank(line)) {
+            Some(blank_lines) if same_list(&lines[index + blank_lines]).is_some() => {
+                index += blank_lines;
+            }
+            _ => break,
+        }
+    }
+
+    if blocks.is_empty() {
+        return None;
+    }
+
+    Some((blocks, index))
+}
+
+fn list_item(marker: ListMarker, mut content: Vec<String>, first: bool) -> Block {
+    // GFM task list items start with `[ ]` or `[x]`.
+    let mut checked = None;
+
+    for (prefix, is_checked) in [("[ ]", false), ("[x]", true), ("[X]", true)] {
+        let rest = content[0]
+            .strip_prefix(prefix)
+            .filter(|rest| rest.is_empty() || rest.starts_with(' '))
+            .map(|rest| rest.trim_start().to_string());
+
+        if let Some(rest) = rest {
+            content[0] = rest;
+            checked = Some(is_checked);
+            break;
+        }
+    }
+
+    let (rich_text, children) = split_first_paragraph(parse_blocks(&content));
+
+    match (checked, marker.number) {
+        (Some(checked), _) => Block::ToDo {
+            to_do: ToDoBlock {
+                rich_text,
+                checked,
+                children,
+                ..Default::default()
+            },
+        },
+        (None, None) => Block::BulletedListItem {
+            bulleted_list_item: BulletedListItemBlock {
+                rich_text,
+                children,
+                ..Default::default()
+            },
+        },
+        (None, Some(number)) => Block::NumberedListItem {
+            numbered_list_item: NumberedListItemBlock {
+                rich_text,
+                children,
+                list_start_index: (first && number != 1).then_some(number),
+                ..Default::default()
+            },
+        },
+    }
+}
+
+/// The cells of a table row, or `None` when the line has no `|`.
+fn table_cells(line: &str) -> Option<Vec<String>> {
+    let (indent, rest) = indentation(line);
+
+    if indent >= 4 {
+        return None;
+    }
+
+    let row = rest.trim_end();
+    let mut cells = vec![String::new()];
+    let mut ends_with_pipe = false;
+    let mut chars = row.chars();
+
+    while let Some(c) = chars.next() {
+        let cell = cells.last_mut()?;
+        ends_with_pipe = c == '|';
+
+        match c {
+            // `\|` is a pipe in the cell, even in a code span.
+            '\\' => match chars.next() {
+                Some('|') => cell.push('|'),
+                Some(next) => {
+                    cell.push('\\');
+                    cell.push(next);
+                }
+                None => cell.push('\\'),
+            },
+            '|' => cells.push(String::new()),
+            _ => cell.push(c),
+        }
+    }
+
+    if cells.len() == 1 {
+        return None;
+    }
+
+    // The leading and trailing pipes are optional.
+    if row.starts_with('|') {
+        cells.remove(0);
+    }
+
+    if ends_with_pipe {
+        cells.pop();
+    }
+
+    Some(cells.iter().map(|cell| cell.trim().to_string()).collect())
+}
+
+fn table(lines: &[String]) -> Option<(Vec<Block>, usize)> {
+    let header = table_cells(&lines[0])?;
+    let delimiter = table_cells(lines.get(1)?)?;
+
+    let is_delimiter = |cell: &String| {
+        let cell = cell.strip_prefix(':').unwrap_or(cell);
+        let cell = cell.strip_suffix(':').unwrap_or(cell);
+        !cell.is_empty() && cell.chars().all(|c| c == '-')
+    };
+
+    if header.is_empty() || delimiter.len() != header.len() || !delimiter.iter().all(is_delimiter) {
+        return None;
+    }
+
+    let width = header.len();
+    let mut rows = vec![header];
+
+    for line in &lines[2..] {
+        if is_blank(line) || starts_block(line) {
+            break;
+        }
+
+        rows.push(table_cells(line).unwrap_or_else(|| vec![line.trim().to_string()]));
+    }
+
+    let consumed = rows.len() + 1;
+
+    let children = rows
+        .into_iter()
+        .map(|mut cells| {
+            cells.resize(width, String::new());
+
+            Block::TableRow {
+                table_row: TableRowBlock {
+                    cells: cells.iter().map(|cell| rich_text(cell)).collect(),
+                },
+            }
+        })
+        .collect();
+
+    let table = Block::Table {
+        table: TableBlock {
+            table_width: width as u16,
+            has_column_header: true,
+            has_row_header: false,
+            children: Some(children),
+        },
+    };
+
+    Some((vec![table], consumed))
+}
+
+// # --------------------------------------------------------------------------------
+//
+// inlines
+//
+// # --------------------------------------------------------------------------------
+
+/// The content of a paragraph, heading or table cell, before it becomes rich
+/// text.
+#[derive(Debug)]
+enum Inline {
+    Text(String),
+
+    Code(String),
+
+    Equation(String),
+
+    /// A link, or the text of a link Notion doesn't accept.
+    Link {
+        url: Option<String>,
+        children: Vec<Inline>,
+    },
+
+    /// A run of `*`, `_` or `~` that may open or close emphasis.
+    Delimiter {
+        character: char,
+        count: usize,
+        can_open: bool,
+        can_close: bool,
+    },
+
+    Styled {
+        style: Style,
+        children: Vec<Inline>,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Italic,
+    Bold,
+    Strikethrough,
+}
+
+fn rich_text(text: &str) -> Vec<RichText> {
+    let mut rich_text = Vec::new();
+    push_inlines(
+        &mut rich_text,
+        inlines(text),
+        RichTextAnnotations::default(),
+        None,
+    );
+    rich_text
+}
+
+fn plain_text(text: &str) -> Vec<RichText> {
+    let mut rich_text = Vec::new();
+    push_text(&mut rich_text, text, RichTextAnnotations::default(), None);
+    rich_text
+}
+
+fn push_inlines(
+    rich_text: &mut Vec<RichText>,
+    inlines: Vec<Inline>,
+    annotations: RichTextAnnotations,
+    href: Option<&str>,
+) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(text) => push_text(rich_text, &text, annotations, href),
+            Inline::Code(code) => {
+                let annotations = RichTextAnnotations {
+                    code: true,
+                    ..annotations
+                };
+                push_text(rich_text, &code, annotations, href)
+            }
+            Inline::Equation(expression) => rich_text.push(RichText::Equation {
+                equation: Equation::from(&expression),
+                annotations,
+                plain_text: expression,
+                href: href.map(str::to_string),
+            }),
+            Inline::Link { url, children } => {
+                push_inlines(rich_text, children, annotations, url.as_deref().or(href))
+            }
+            Inline::Delimiter {
+                character, count, ..
+            } => push_text(
+                rich_text,
+                &character.to_string().repeat(count),
+                annotations,
+                href,
+            ),
+            Inline::Styled { style, children } => {
+                let mut annotations = annotations;
+                match style {
+                    Style::Italic => annotations.italic = true,
+                    Style::Bold => annotations.bold = true,
+                    Style::Strikethrough => annotations.strikethrough = true,
+                }
+                push_inlines(rich_text, children, annotations, href)
+            }
+        }
+    }
+}
+
+/// Append text to the rich text, merged with the last text when it has the
+/// same style, and split to Notion's length limit.
+fn push_text(
+    rich_text: &mut Vec<RichText>,
+    text: &str,
+    annotations: RichTextAnnotations,
+    href: Option<&str>,
+) {
+    let merge = matches!(
+        rich_text.last(),
+        Some(RichText::Text { annotations: last, href: last_href, .. })
+            if *last == annotations && last_href.as_deref() == href
+    );
+
+    let text = match merge.then(|| rich_text.pop()).flatten() {
+        Some(RichText::Text { plain_text, .. }) => plain_text + text,
+        _ => text.to_string(),
+    };
+
+    for chunk in text.chars().collect::<Vec<_>>().chunks(MAX_TEXT_LENGTH) {
+        let mut segment = RichText::from(chunk.iter().collect::<String>()).annotations(annotations);
+
+        if let Some(href) = href {
+            segment = segment.href(href);
+        }
+
+        rich_text.push(segment);
+    }
+}
+
+fn inlines(text: &str) -> Vec<Inline> {
+    emphasize(tokenize(text))
+}
+
+fn tokenize(text: &str) -> Vec<Inline> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+
+        let parsed = match c {
+            '\\' => chars
+                .get(index + 1)
+                .filter(|next| next.is_ascii_punctuation())
+                .map(|next| (Inline::Text(next.to_string()), index + 2)),
+            '`' => code_span(&chars, index),
+            '$' => inline_equation(&chars, index),
+            '[' => link(&chars, index).map(|(label, url, end)| {
+                let url = is_absolute(&url).then_some(url);
+                let children = inlines(&label);
+                (Inline::Link { url, children }, end)
+            }),
+            '!' if chars.get(index + 1) == Some(&'[') => {
+                link(&chars, index + 1).map(|(alt, url, end)| {
+                    let url = is_absolute(&url).then_some(url);
+                    let children = vec![Inline::Text(alt)];
+                    (Inline::Link { url, children }, end)
+                })
+            }
+            '<' => autolink(&chars, index),
+            '*' | '_' | '~' => Some(delimiter(&chars, index)),
+            'h' | 'w' if index == 0 || !chars[index - 1].is_alphanumeric() => {
+                bare_url(&chars, index)
+            }
+            _ => None,
+        };
+
+        match parsed {
+            Some((inline, end)) => {
+                if !text.is_empty() {
+                    tokens.push(Inline::Text(std::mem::take(&mut text)));
+                }
+                tokens.push(inline);
+                index = end;
+            }
+            // A backtick run that opens no code span is text as a whole.
+            None if c == '`' => {
+                let length = run_length(&chars, index);
+                text.extend(&chars[index..index + length]);
+                index += length;
+            }
+            None => {
+                text.push(c);
+                index += 1;
+            }
+        }
+    }
+
+    if !text.is_empty() {
+        tokens.push(Inline::Text(text));
+    }
+
+    tokens
+}
+
+/// The number of times the character at `start` repeats from there.
+fn run_length(chars: &[char], start: usize) -> usize {
+    chars[start..]
+        .iter()
+        .take_while(|c| **c == chars[start])
+        .count()
+}
+
+fn is_punctuation(c: char) -> bool {
+    !c.is_alphanumeric() && !c.is_whitespace()
+}
+
+/// Whether Notion accepts the URL in a link.
+fn is_absolute(url: &str) -> bool {
+    url.contains("://") || url.starts_with("mailto:")
+}
+
+fn code_span(chars: &[char], start: usize) -> Option<(Inline, usize)> {
+    let length = run_length(chars, start);
+    let mut index = start + length;
+
+    while index < chars.len() {
+        if chars[index] != '`' {
+            index += 1;
+            continue;
+        }
+
+        let closing = run_length(chars, index);
+
+        if closing == length {
+            let code = chars[start + length..index]
+                .iter()
+                .collect::<String>()
+                .replace('\n', " ");
+
+            // A space on both sides is stripped, so code can start or end
+            // with a backtick.
+            let code = if code.len() >= 2
+                && code.starts_with(' ')
+                && code.ends_with(' ')
+                && !code.trim().is_empty()
+            {
+                code[1..code.len() - 1].to_string()
+            } else {
+                code
+            };
+
+            return Some((Inline::Code(code), index + closing));
+        }
+
+        index += closing;
+    }
+
+    None
+}
+
+/// An inline equation, `$` followed by a non-space and ended by `$` after a
+/// non-space and not before a digit, so prices like `$5` stay text.
+fn inline_equation(chars: &[char], start: usize) -> Option<(Inline, usize)> {
+    let first = *chars.get(start + 1)?;
+
+    if first.is_whitespace() || first == '$' {
+        return None;
+    }
+
+    let end =
+        (start + 2..chars.len()).find(|&index| chars[index] == '$' && chars[index - 1] != '\\')?;
+
+    if chars[end - 1].is_whitespace() || chars.get(end + 1).is_some_and(char::is_ascii_digit) {
+        return None;
+    }
+
+    let expression = chars[start + 1..end].iter().collect();
+
+    Some((Inline::Equation(expression), end + 1))
+}
+
+fn skip_whitespace(chars: &[char], start: usize) -> usize {
+    start
+        + chars[start.min(chars.len())..]
+            .iter()
+            .take_while(|c| c.is_whitespace())
+            .count()
+}
+
+/// The label, URL and end of an inline link `[label](url "title")` whose `[`
+/// is at `start`.
+fn link(chars: &[char], start: usize) -> Option<(String, String, usize)> {
+    let mut depth = 0;
+    let mut index = start;
+
+    let close = loop {
+        match chars.get(index)? {
+            '\\' => index += 1,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    break index;
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    };
+
+    if chars.get(close + 1) != Some(&'(') {
+        return None;
+    }
+
+    let mut index = skip_whitespace(chars, close + 2);
+    let mut url = String::new();
+
+    if chars.get(index) == Some(&'<') {
+        index += 1;
+
+        loop {
+            match *chars.get(index)? {
+                '>' => break,
+                '\n' | '<' => return None,
+                c => url.push(c),
+            }
+            index += 1;
+        }
+
+        index += 1;
+    } else {
+        let mut parens = 0;
+
+        while let Some(&c) = chars.get(index) {
+            match c {
+                '\\' if chars.get(index + 1).is_some_and(char::is_ascii_punctuation) => {
+                    url.push(chars[index + 1]);
+                    index += 2;
+                    continue;
+                }
+                '(' => parens += 1,
+                ')' if parens == 0 => break,
+                ')' => parens -= 1,
+                c if c.is_whitespace() => break,
+                _ => {}
+            }
+            url.push(c);
+            index += 1;
+        }
+    }
+
+    index = skip_whitespace(chars, index);
+
+    // The title has no place in rich text.
+    if let Some(&quote @ ('"' | '\'' | '(')) = chars.get(index) {
+        let closing = if quote == '(' { ')' } else { quote };
+        index += 1;
+
+        while *chars.get(index)? != closing {
+            index += if chars[index] == '\\' { 2 } else { 1 };
+        }
+
+        index = skip_whitespace(chars, index + 1);
+    }
+
+    if chars.get(index) != Some(&')') {
+        return None;
+    }
+
+    let label = chars[start + 1..close].iter().collect();
+
+    Some((label, url, index + 1))
+}
+
+/// An autolink, `<https://example.com>` or `<someone@example.com>`.
+fn autolink(chars: &[char], start: usize) -> Option<(Inline, usize)> {
+    let end = (start + 1..chars.len()).find(|&index| chars[index] == '>')?;
+    let target = chars[start + 1..end].iter().collect::<String>();
+
+    if target.is_empty() || target.chars().any(|c| c.is_whitespace() || c == '<') {
+        return None;
+    }
+
+    let url = if is_absolute(&target) {
+        target.clone()
+    } else if target.contains('@') {
+        format!("mailto:{target}")
+    } else {
+        return None;
+    };
+
+    let children = vec![Inline::Text(target)];
+
+    Some((
+        Inline::Link {
+            url: Some(url),
+            children,
+        },
+        end + 1,
+    ))
+}
+
+/// A URL in the text, starting with `http://`, `https://` or `www.`.
+fn bare_url(chars: &[char], start: usize) -> Option<(Inline, usize)> {
+    let prefix = ["https://", "http://", "www."].into_iter().find(|prefix| {
+        prefix
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| chars.get(start + offset) == Some(&c))
+    })?;
+
+    let minimum = start + prefix.len();
+    let mut end = minimum;
+
+    while end < chars.len() && !chars[end].is_whitespace() && chars[end] != '<' {
+        end += 1;
+    }
+
+    // Trailing punctuation and an unbalanced `)` are not part of the URL.
+    while end > minimum {
+        let url = &chars[start..end];
+        let unbalanced = || {
+            url.iter().filter(|c| **c == ')').count() > url.iter().filter(|c| **c == '(').count()
+        };
+
+        match chars[end - 1] {
+            '?' | '!' | '.' | ',' | ':' | ';' | '*' | '_' | '~' | '\'' | '"' => end -= 1,
+            ')' if unbalanced() => end -= 1,
+            _ => break,
+        }
+    }
+
+    if end == minimum {
+        return None;
+    }
+
+    let text = chars[start..end].iter().collect::<String>();
+    let url = if prefix == "www." {
+        format!("http://{text}")
+    } else {
+        text.clone()
+    };
+
+    let children = vec![Inline::Text(text)];
+
+    Some((
+        Inline::Link {
+            url: Some(url),
+            children,
+        },
+        end,
+    ))
+}
+
+fn delimiter(chars: &[char], start: usize) -> (Inline, usize) {
+    let character = chars[start];
+    let count = run_length(chars, start);
+
+    let before = start.checked_sub(1).map_or(' ', |index| chars[index]);
+    let after = chars.get(start + count).copied().unwrap_or(' ');
+
+    let left_flanking = !after.is_whitespace()
+        && (!is_punctuation(after) || before.is_whitespace() || is_punctuation(before));
+    let right_flanking = !before.is_whitespace()
+        && (!is_punctuation(before) || after.is_whitespace() || is_punctuation(after));
+
+    let (can_open, can_close) = match character {
+        // `_` doesn't emphasize inside words, as in `snake_case`.
+        '_' => (
+            left_flanking && (!right_flanking || is_punctuation(before)),
+            right_flanking && (!left_flanking || is_punctuation(after)),
+        ),
+        // Strikethrough takes one or two tildes.
+        '~' if count > 2 => (false, false),
+        _ => (left_flanking, right_flanking),
+    };
+
+    let delimiter = Inline::Delimiter {
+        character,
+        count,
+        can_open,
+        can_close,
+    };
+
+    (delimiter, start + count)
+}
+
+/// Match the delimiter runs into emphasis, strong emphasis and strikethrough,
+/// as CommonMark's "process emphasis" procedure does.
+fn emphasize(mut inlines: Vec<Inline>) -> Vec<Inline> {
+    let mut closer = 0;
+
+    while closer < inlines.len() {
+        let Inline::Delimiter {
+            character,
+            count,
+            can_open: closer_can_open,
+            can_close: true,
+        } = inlines[closer]
+        else {
+            closer += 1;
+            continue;
+        };
+
+        let opener = (0..closer).rev().find_map(|opener| match inlines[opener] {
+            Inline::Delimiter {
+                character: opener_character,
+                count: opener_count,
+                can_open: true,
+                can_close: opener_can_close,
+            } if opener_character == character => {
+                let matches = match character {
+                    '~' => opener_count == count,
+                    // The "rule of 3", so `*foo**bar*` is one emphasis.
+                    _ => {
+                        !((opener_can_close || closer_can_open)
+                            && (opener_count + count) % 3 == 0
+                            && !(opener_count % 3 == 0 && count % 3 == 0))
+                    }
+                };
+                matches.then_some((opener, opener_count))
+            }
+            _ => None,
+        });
+
+        let Some((opener, opener_count)) = opener else {
+            closer += 1;
+            continue;
+        };
+
+        let (used, style) = match character {
+            '~' => (count, Style::Strikethrough),
+            _ if count >= 2 && opener_count >= 2 => (2, Style::Bold),
+            _ => (1, Style::Italic),
+        };
+
+        let children = inlines.drain(opener + 1..closer).collect();
+        inlines.insert(opener + 1, Inline::Styled { style, children });
+
+        closer = opener + 2;
+
+        if consume(&mut inlines[closer], used) {
+            inlines.remove(closer);
+        }
+
+        if consume(&mut inlines[opener], used) {
+            inlines.remove(opener);
+            closer -= 1;
+        }
+    }
+
+    inlines
+}
+
+/// Take `used` characters from a delimiter run, and tell whether it is empty.
+fn consume(inline: &mut Inline, used: usize) -> bool {
+    match inline {
+        Inline::Delimiter { count, .. } => {
+            *count -= used;
+            *count == 0
+        }
+        _ => false,
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn json(markdown: &str) -> Vec<serde_json::Value> {
+        Block::from_markdown(markdown)
+            .iter()
+            .map(|block| serde_json::to_value(block).unwrap())
+            .collect()
+    }
+
+    /// The text and the annotations that are set, per rich text object.
+    fn segments(rich_text: &serde_json::Value) -> Vec<(String, Vec<String>)> {
+        rich_text
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|segment| {
+                let mut styles = ["bold", "italic", "strikethrough", "code"]
+                    .into_iter()
+                    .filter(|style| segment["annotations"][style] == true)
+                    .map(str::to_string)
+                    .collect::<Vec<_>>();
+
+                if let Some(url) = segment["href"].as_str() {
+                    styles.push(url.to_string());
+                }
+
+                (segment["plain_text"].as_str().unwrap().to_string(), styles)
+            })
+            .collect()
+    }
+
+    fn segment(text: &str, styles: &[&str]) -> (String, Vec<String>) {
+        (
+            text.to_string(),
+            styles.iter().map(|style| style.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn convert_leaf_blocks() {
+        let blocks = json(
+            "# Title\n\
+             \n\
+             Some text\n\
+             on two lines.\n\
+             \n\
+             Setext\n\
+             ------\n\
+             \n\
+             ##### Deep\n\
+             \n\
+             ```rs ignore\n\
+             fn main() {}\n\
+             \n\
+             ```\n\
+             \n\
+             ***\n\
+             \n\
+             $$\n\
+             E = mc^2\n\
+             $$\n\
+             \n\
+             ![A diagram](https://example.com/a.png \"Title\")\n\
+             \n\
+             ![Local](./a.png)\n\
+             \n\
+             \x20   indented code\n",
+        );
+
+        let types = blocks
+            .iter()
+            .map(|block| block["type"].as_str().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            types,
+            vec![
+                "heading_1",
+                "paragraph",
+                "heading_2",
+                "heading_4",
+                "code",
+                "divider",
+                "equation",
+                "image",
+                "paragraph",
+                "code",
+            ]
+        );
+
+        assert_eq!(
+            blocks[0]["heading_1"]["rich_text"][0]["plain_text"],
+            "Title"
+        );
+        assert_eq!(
+            blocks[1]["paragraph"]["rich_text"][0]["plain_text"],
+            "Some text on two lines."
+        );
+        assert_eq!(blocks[4]["code"]["language"], "rust");
+        assert_eq!(
+            blocks[4]["code"]["rich_text"][0]["plain_text"],
+            "fn main() {}\n"
+        );
+        assert_eq!(blocks[6]["equation"]["expression"], "E = mc^2");
+        assert_eq!(
+            blocks[7]["image"]["external"]["url"],
+            "https://example.com/a.png"
+        );
+        assert_eq!(blocks[7]["image"]["caption"][0]["plain_text"], "A diagram");
+        assert_eq!(
+            segments(&blocks[8]["paragraph"]["rich_text"]),
+            vec![segment("Local", &[])]
+        );
+        assert_eq!(blocks[9]["code"]["language"], "plain text");
+        assert_eq!(
+            blocks[9]["code"]["rich_text"][0]["plain_text"],
+            "indented code"
+        );
+    }
+
+    #[test]
+    fn convert_nested_lists() {
+        let blocks = json(
+            "- one\n\
+             \x20 - nested\n\
+             \n\
+             \x20   continued paragraph\n\
+             - [x] done\n\
+             - [ ] todo\n\
+             \x20 1. inner\n\
+             \n\
+             3. three\n\
+             4. four\n\
+             \n\
+             > quoted\n\
+             > - item\n",
+        );
+
+        let types = blocks
+            .iter()
+            .map(|block| block["type"].as_str().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            types,
+            vec![
+                "bulleted_list_item",
+                "to_do",
+                "to_do",
+                "numbered_list_item",
+                "numbered_list_item",
+                "quote",
+            ]
+        );
+
+        let nested = &blocks[0]["bulleted_list_item"]["children"][0];
+        assert_eq!(nested["type"], "bulleted_list_item");
+        assert_eq!(
+            nested["bulleted_list_item"]["children"][0]["paragraph"]["rich_text"][0]["plain_text"],
+            "continued paragraph"
+        );
+
+        assert_eq!(blocks[1]["to_do"]["checked"], true);
+        assert_eq!(blocks[1]["to_do"]["rich_text"][0]["plain_text"], "done");
+        assert_eq!(blocks[2]["to_do"]["checked"], false);
+        assert_eq!(
+            blocks[2]["to_do"]["children"][0]["type"],
+            "numbered_list_item"
+        );
+
+        assert_eq!(blocks[3]["numbered_list_item"]["list_start_index"], 3);
+        assert!(
+            blocks[4]["numbered_list_item"]
+                .get("list_start_index")
+                .is_none()
+        );
+
+        assert_eq!(blocks[5]["quote"]["rich_text"][0]["plain_text"], "quoted");
+        assert_eq!(
+            blocks[5]["quote"]["children"][0]["type"],
+            "bulleted_list_item"
+        );
+    }
+
+    #[test]
+    fn convert_tables() {
+        let blocks = json(
+            "| Name | `a \\| b` |\n\
+             |:-----|-------:|\n\
+             | one  | two | three |\n\
+             four\n",
+        );
+
+        assert_eq!(blocks.len(), 1);
+
+        let table = &blocks[0]["table"];
+        assert_eq!(table["table_width"], 2);
+        assert_eq!(table["has_column_header"], true);
+
+        let rows = table["children"].as_array().unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            segments(&rows[0]["table_row"]["cells"][1]),
+            vec![segment("a | b", &["code"])]
+        );
+        assert_eq!(rows[1]["table_row"]["cells"].as_array().unwrap().len(), 2);
+        assert_eq!(rows[2]["table_row"]["cells"][0][0]["plain_text"], "four");
+        assert_eq!(rows[2]["table_row"]["cells"][1], serde_json::json!([]));
+    }
+
+    #[test]
+    fn convert_inline_styles() {
+        let blocks = json(
+            "**bold *both*** _it_ `co*de*` ~~gone~~ snake_case_name \\*literal\\* \
+             [a **link**](https://example.com) [relative](./doc.md) <https://auto.link> \
+             see www.example.com. $x^2$ costs $5",
+        );
+
+        assert_eq!(
+            segments(&blocks[0]["paragraph"]["rich_text"]),
+            vec![
+                segment("bold ", &["bold"]),
+                segment("both", &["bold", "italic"]),
+                segment(" ", &[]),
+                segment("it", &["italic"]),
+                segment(" ", &[]),
+                segment("co*de*", &["code"]),
+                segment(" ", &[]),
+                segment("gone", &["strikethrough"]),
+                segment(" snake_case_name *literal* ", &[]),
+                segment("a ", &["https://example.com"]),
+                segment("link", &["bold", "https://example.com"]),
+                segment(" relative ", &[]),
+                segment("https://auto.link", &["https://auto.link"]),
+                segment(" see ", &[]),
+                segment("www.example.com", &["http://www.example.com"]),
+                segment(". ", &[]),
+                segment("x^2", &[]),
+                segment(" costs $5", &[]),
+            ]
+        );
+
+        assert_eq!(
+            blocks[0]["paragraph"]["rich_text"][16]["equation"]["expression"],
+            "x^2"
+        );
+    }
+
+    #[test]
+    fn split_long_text() {
+        let text = "a".repeat(4500);
+        let blocks = json(&format!("{text}\n\n```\n{text}\n```"));
+
+        let lengths = |rich_text: &serde_json::Value| {
+            rich_text
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|segment| segment["plain_text"].as_str().unwrap().len())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            lengths(&blocks[0]["paragraph"]["rich_text"]),
+            vec![2000, 2000, 500]
+        );
+        assert_eq!(
+            lengths(&blocks[1]["code"]["rich_text"]),
+            vec![2000, 2000, 500]
+        );
+    }
+
+    #[test]
+    fn map_code_languages() {
+        assert_eq!(language("py"), Language::Python);
+        assert_eq!(language("TypeScript"), Language::Typescript);
+        assert_eq!(language("c++"), Language::CPlusPlus);
+        assert_eq!(language("yml"), Language::Yaml);
+        assert_eq!(language("rust,ignore"), Language::Rust);
+        assert_eq!(language("unknown"), Language::PlainText);
+        assert_eq!(language(""), Language::PlainText);
+    }
+}
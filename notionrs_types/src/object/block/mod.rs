@@ -24,8 +24,11 @@ pub mod template;
 pub mod to_do;
 pub mod toggle;
 pub mod transcription;
+pub mod tree;
 pub mod unsupported;
 
+mod markdown;
+
 /// ```json
 /// {
 ///         "object": "block",
@@ -168,6 +171,10 @@ impl CreateMeetingNoteResponse {
 pub struct QueryMeetingNotesResponse {
     pub results: Vec<MeetingNotesBlockResponse>,
     pub has_more: bool,
+
+    /// Cursor for the next page of results, when `has_more` is `true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 /// <https://developers.notion.com/reference/block#block-type-objects>
@@ -293,6 +300,59 @@ pub enum Block {
     },
 }
 
+impl Block {
+    /// The nested blocks to create along with the block, for the block types
+    /// that can have them, e.g. `paragraph`, `toggle` or `table`.
+    pub fn children(&self) -> Option<&Option<Vec<Block>>> {
+        match self {
+            Block::BulletedListItem { bulleted_list_item } => Some(&bulleted_list_item.children),
+            Block::Callout { callout } => Some(&callout.children),
+            Block::ColumnList { column_list } => Some(&column_list.children),
+            Block::Column { column } => Some(&column.children),
+            Block::Heading1 { heading_1: heading }
+            | Block::Heading2 { heading_2: heading }
+            | Block::Heading3 { heading_3: heading }
+            | Block::Heading4 { heading_4: heading } => Some(&heading.children),
+            Block::NumberedListItem { numbered_list_item } => Some(&numbered_list_item.children),
+            Block::Paragraph { paragraph } => Some(&paragraph.children),
+            Block::Quote { quote } => Some(&quote.children),
+            Block::SyncedBlock { synced_block } => Some(&synced_block.children),
+            Block::Tab { tab } => Some(&tab.children),
+            Block::Table { table } => Some(&table.children),
+            Block::ToDo { to_do } => Some(&to_do.children),
+            Block::Toggle { toggle } => Some(&toggle.children),
+            _ => None,
+        }
+    }
+
+    /// Mutable access to the nested blocks, as in [`Block::children`].
+    pub fn children_mut(&mut self) -> Option<&mut Option<Vec<Block>>> {
+        match self {
+            Block::BulletedListItem { bulleted_list_item } => {
+                Some(&mut bulleted_list_item.children)
+            }
+            Block::Callout { callout } => Some(&mut callout.children),
+            Block::ColumnList { column_list } => Some(&mut column_list.children),
+            Block::Column { column } => Some(&mut column.children),
+            Block::Heading1 { heading_1: heading }
+            | Block::Heading2 { heading_2: heading }
+            | Block::Heading3 { heading_3: heading }
+            | Block::Heading4 { heading_4: heading } => Some(&mut heading.children),
+            Block::NumberedListItem { numbered_list_item } => {
+                Some(&mut numbered_list_item.children)
+            }
+            Block::Paragraph { paragraph } => Some(&mut paragraph.children),
+            Block::Quote { quote } => Some(&mut quote.children),
+            Block::SyncedBlock { synced_block } => Some(&mut synced_block.children),
+            Block::Tab { tab } => Some(&mut tab.children),
+            Block::Table { table } => Some(&mut table.children),
+            Block::ToDo { to_do } => Some(&mut to_do.children),
+            Block::Toggle { toggle } => Some(&mut toggle.children),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for Block {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+
+use super::BlockResponse;
+
+/// The blocks under a page or block, with their descendants.
+///
+/// Built by `Client::get_block_tree`. A block whose children weren't fetched,
+/// e.g. a child page or a block below the maximum depth, has `has_children`
+/// set but no `children`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BlockTree {
+    /// The ID of the page or block the tree was fetched from.
+    pub block_id: String,
+
+    /// The first-level blocks, in page order.
+    pub children: Vec<BlockNode>,
+}
+
+/// A block with its children.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BlockNode {
+    pub block: BlockResponse,
+
+    /// The children of the block, in page order. For a synced block that
+    /// copies another one, the children of the original block.
+    pub children: Vec<BlockNode>,
+}
+
+impl BlockTree {
+    /// Every block of the tree, depth first in page order, with its depth
+    /// (`0` for the first-level blocks).
+    pub fn iter(&self) -> BlockTreeIter<'_> {
+        BlockTreeIter::new(&self.children, 0)
+    }
+
+    /// The number of blocks in the tree.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl BlockNode {
+    /// The descendants of the block, depth first in page order, with their
+    /// depth below this block (`0` for its children).
+    pub fn descendants(&self) -> BlockTreeIter<'_> {
+        BlockTreeIter::new(&self.children, 0)
+    }
+}
+
+/// Depth-first iterator over the blocks of a [`BlockTree`].
+#[derive(Debug, Clone)]
+pub struct BlockTreeIter<'a> {
+    stack: Vec<(usize, &'a BlockNode)>,
+}
+
+impl<'a> BlockTreeIter<'a> {
+    fn new(nodes: &'a [BlockNode], depth: usize) -> Self {
+        Self {
+            stack: nodes.iter().rev().map(|node| (depth, node)).collect(),
+        }
+    }
+}
+
+impl<'a> Iterator for BlockTreeIter<'a> {
+    type Item = (usize, &'a BlockNode);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, node) = self.stack.pop()?;
+
+        self.stack
+            .extend(node.children.iter().rev().map(|child| (depth + 1, child)));
+
+        Some((depth, node))
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn node(id: &str, children: Vec<BlockNode>) -> BlockNode {
+        let mut block =
+            serde_json::from_str::<BlockResponse>(include_str!("./seed/paragraph.json")).unwrap();
+        block.id = id.to_string();
+        block.has_children = !children.is_empty();
+
+        BlockNode { block, children }
+    }
+
+    #[test]
+    fn iterate_depth_first() {
+        let tree = BlockTree {
+            block_id: "page-id".to_string(),
+            children: vec![
+                node(
+                    "a",
+                    vec![node("a1", vec![node("a1x", vec![])]), node("a2", vec![])],
+                ),
+                node("b", vec![]),
+            ],
+        };
+
+        let visited = tree
+            .iter()
+            .map(|(depth, node)| (depth, node.block.id.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            visited,
+            vec![(0, "a"), (1, "a1"), (2, "a1x"), (1, "a2"), (0, "b")]
+        );
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.children[0].descendants().count(), 3);
+    }
+}
@@ -17,6 +17,7 @@ pub mod file;
 pub mod file_upload;
 pub mod icon;
 pub mod language;
+pub mod oauth;
 pub mod parent;
 pub mod response;
 pub mod select;
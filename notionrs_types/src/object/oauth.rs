@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+/// Response from the `POST /v1/oauth/token` endpoint, for both the
+/// `authorization_code` and the `refresh_token` grants.
+///
+/// <https://developers.notion.com/reference/create-a-token>
+/// <https://developers.notion.com/reference/refresh-a-token>
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct OAuthTokenResponse {
+    /// The access token to authenticate requests to the workspace with.
+    pub access_token: String,
+
+    /// always "bearer"
+    pub token_type: String,
+
+    /// Token to obtain a new access token with once this one expires.
+    /// Only present for integrations that use refresh tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+
+    /// The ID of the bot user created for this authorization.
+    pub bot_id: String,
+
+    /// The ID of the workspace where the authorization took place.
+    pub workspace_id: String,
+
+    /// The name of the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_name: Option<String>,
+
+    /// The URL of the workspace icon, or an emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_icon: Option<String>,
+
+    /// Who can view and share this integration within the workspace.
+    pub owner: OAuthTokenOwner,
+
+    /// The ID of the page duplicated from the integration's template into the
+    /// workspace, if the user chose to duplicate it.
+    #[serde(default)]
+    pub duplicated_template_id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+/// The owner of an OAuth authorization.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OAuthTokenOwner {
+    /// The authorizing user. Only they can see the integration in the workspace.
+    User {
+        user: Box<crate::object::user::User>,
+    },
+
+    /// The whole workspace owns the integration.
+    Workspace { workspace: bool },
+}
+
+/// Response from the `POST /v1/oauth/introspect` endpoint.
+///
+/// <https://developers.notion.com/reference/introspect-token>
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct OAuthIntrospectResponse {
+    /// Whether the token is active, i.e. neither revoked nor expired.
+    pub active: bool,
+
+    /// Space-separated capabilities granted to the token,
+    /// e.g. `"read_content insert_content"`. Only present for active tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
+    /// When the token was issued, in milliseconds since the Unix epoch.
+    /// Only present for active tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_token_owned_by_user() {
+        let json_data = r#"
+        {
+            "access_token": "ntn_access",
+            "token_type": "bearer",
+            "refresh_token": "nrt_refresh",
+            "bot_id": "b3414d65-9d3b-4c7e-a8bb-3a3e9d5b2f2a",
+            "workspace_name": "Acme",
+            "workspace_icon": "https://example.com/icon.png",
+            "workspace_id": "e5b1a8c6-7d0f-4bfe-8a8b-2f2c8f5d6a1e",
+            "owner": {
+                "type": "user",
+                "user": {
+                    "object": "user",
+                    "id": "78126152-3c2a-4b04-860e-77fb5bdded2f",
+                    "name": "John Doe",
+                    "avatar_url": null,
+                    "type": "person",
+                    "person": {
+                        "email": "johndoe@example.com"
+                    }
+                }
+            },
+            "duplicated_template_id": "9a1f3e22-4c1d-4f5e-9a3b-1e2d3c4b5a69",
+            "request_id": "f2a7e4f5-6e27-4c83-9cb3-1d1ad0c1f2e3"
+        }
+        "#;
+
+        let token = serde_json::from_str::<OAuthTokenResponse>(json_data).unwrap();
+
+        assert_eq!(token.access_token, "ntn_access");
+        assert_eq!(token.refresh_token.as_deref(), Some("nrt_refresh"));
+        assert_eq!(token.bot_id, "b3414d65-9d3b-4c7e-a8bb-3a3e9d5b2f2a");
+        assert_eq!(token.workspace_name.as_deref(), Some("Acme"));
+        assert_eq!(
+            token.duplicated_template_id.as_deref(),
+            Some("9a1f3e22-4c1d-4f5e-9a3b-1e2d3c4b5a69")
+        );
+
+        match token.owner {
+            OAuthTokenOwner::User { user } => {
+                assert_eq!(user.id, "78126152-3c2a-4b04-860e-77fb5bdded2f")
+            }
+            OAuthTokenOwner::Workspace { .. } => panic!("The owner should be a user."),
+        }
+    }
+
+    #[test]
+    fn deserialize_token_owned_by_workspace() {
+        let json_data = r#"
+        {
+            "access_token": "ntn_access",
+            "token_type": "bearer",
+            "bot_id": "b3414d65-9d3b-4c7e-a8bb-3a3e9d5b2f2a",
+            "workspace_name": null,
+            "workspace_icon": null,
+            "workspace_id": "e5b1a8c6-7d0f-4bfe-8a8b-2f2c8f5d6a1e",
+            "owner": {
+                "type": "workspace",
+                "workspace": true
+            },
+            "duplicated_template_id": null
+        }
+        "#;
+
+        let token = serde_json::from_str::<OAuthTokenResponse>(json_data).unwrap();
+
+        assert_eq!(token.refresh_token, None);
+        assert_eq!(token.duplicated_template_id, None);
+        assert_eq!(token.owner, OAuthTokenOwner::Workspace { workspace: true });
+    }
+
+    #[test]
+    fn deserialize_introspect_response() {
+        let active = serde_json::from_str::<OAuthIntrospectResponse>(
+            r#"{"active":true,"scope":"read_content insert_content","iat":1727554061617}"#,
+        )
+        .unwrap();
+        assert!(active.active);
+        assert_eq!(active.scope.as_deref(), Some("read_content insert_content"));
+        assert_eq!(active.iat, Some(1727554061617));
+
+        let revoked =
+            serde_json::from_str::<OAuthIntrospectResponse>(r#"{"active":false}"#).unwrap();
+        assert!(!revoked.active);
+        assert_eq!(revoked.scope, None);
+    }
+}
@@ -0,0 +1,519 @@
+use std::collections::HashMap;
+
+use super::{PageProperty, PageResponse};
+use crate::object::file::File;
+use crate::object::rich_text::{RichText, RichTextAnnotations, mention::Mention};
+
+/// The property changes between two versions of a page.
+///
+/// Values are compared the way Notion stores them, so a property built
+/// locally, e.g. a select given only by name, equals the same value read back
+/// from the API. Read-only properties such as formulas and rollups are left
+/// out, since they can't be written.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use notionrs_types::prelude::*;
+///
+/// let current = HashMap::from([(
+///     "Status".to_string(),
+///     PageProperty::Select(PageSelectProperty::from("Todo")),
+/// )]);
+///
+/// let desired = HashMap::from([(
+///     "Status".to_string(),
+///     PageProperty::Select(PageSelectProperty::from("Done")),
+/// )]);
+///
+/// let diff = PropertyDiff::between(&current, &desired);
+///
+/// assert!(diff.changed.contains_key("Status"));
+/// assert_eq!(diff.update_properties().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PropertyDiff {
+    /// Properties that only the new version has.
+    pub added: HashMap<String, PageProperty>,
+
+    /// Properties whose value differs.
+    pub changed: HashMap<String, PropertyChange>,
+
+    /// Properties that only the old version has.
+    pub removed: HashMap<String, PageProperty>,
+}
+
+/// The old and new value of a changed property.
+#[derive(Debug, Clone)]
+pub struct PropertyChange {
+    pub old: PageProperty,
+    pub new: PageProperty,
+}
+
+impl PropertyDiff {
+    /// Compare two property maps.
+    pub fn between(
+        old: &HashMap<String, PageProperty>,
+        new: &HashMap<String, PageProperty>,
+    ) -> Self {
+        let mut diff = Self::default();
+
+        for (name, new_property) in new.iter().filter(|(_, p)| !p.is_read_only()) {
+            match old.get(name) {
+                None => {
+                    diff.added.insert(name.clone(), new_property.clone());
+                }
+                Some(old_property) if !same_value(old_property, new_property) => {
+                    diff.changed.insert(
+                        name.clone(),
+                        PropertyChange {
+                            old: old_property.clone(),
+                            new: new_property.clone(),
+                        },
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (name, old_property) in old.iter().filter(|(_, p)| !p.is_read_only()) {
+            if !new.contains_key(name) {
+                diff.removed.insert(name.clone(), old_property.clone());
+            }
+        }
+
+        diff
+    }
+
+    /// Whether nothing needs to be written.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+
+    /// The properties to send to update the old version into the new one:
+    /// the added and changed ones.
+    ///
+    /// Removed properties aren't included, since a page can't drop a property
+    /// of its data source. To clear a value, set the property to an empty
+    /// value instead.
+    pub fn update_properties(&self) -> HashMap<String, PageProperty> {
+        self.added
+            .iter()
+            .map(|(name, property)| (name.clone(), property.clone()))
+            .chain(
+                self.changed
+                    .iter()
+                    .map(|(name, change)| (name.clone(), change.new.clone())),
+            )
+            .collect()
+    }
+}
+
+impl PageResponse<HashMap<String, PageProperty>> {
+    /// Compare the properties of this page with those of a newer version.
+    pub fn diff(&self, new: &PageResponse<HashMap<String, PageProperty>>) -> PropertyDiff {
+        PropertyDiff::between(&self.properties, &new.properties)
+    }
+
+    /// Compare the properties of this page with the desired values of some of
+    /// them. Properties missing from `desired` are left as they are, so the
+    /// diff has no removed properties.
+    pub fn diff_properties(&self, desired: &HashMap<String, PageProperty>) -> PropertyDiff {
+        let mut diff = PropertyDiff::between(&self.properties, desired);
+        diff.removed.clear();
+        diff
+    }
+}
+
+/// Whether two properties hold the same value, ignoring what Notion adds
+/// when it returns them, such as property IDs, option colors and user names.
+fn same_value(old: &PageProperty, new: &PageProperty) -> bool {
+    match (old, new) {
+        (PageProperty::Title(old), PageProperty::Title(new)) => {
+            same_rich_text(&old.title, &new.title)
+        }
+        (PageProperty::RichText(old), PageProperty::RichText(new)) => {
+            same_rich_text(&old.rich_text, &new.rich_text)
+        }
+        (PageProperty::Select(old), PageProperty::Select(new)) => {
+            old.select.as_ref().map(|s| &s.name) == new.select.as_ref().map(|s| &s.name)
+        }
+        (PageProperty::Status(old), PageProperty::Status(new)) => {
+            old.status.name == new.status.name
+        }
+        (PageProperty::MultiSelect(old), PageProperty::MultiSelect(new)) => old
+            .multi_select
+            .iter()
+            .map(|s| &s.name)
+            .eq(new.multi_select.iter().map(|s| &s.name)),
+        (PageProperty::People(old), PageProperty::People(new)) => old
+            .people
+            .iter()
+            .map(|user| normalize_id(&user.id))
+            .eq(new.people.iter().map(|user| normalize_id(&user.id))),
+        (PageProperty::Relation(old), PageProperty::Relation(new)) => old
+            .relation
+            .iter()
+            .map(|page| normalize_id(&page.id))
+            .eq(new.relation.iter().map(|page| normalize_id(&page.id))),
+        (PageProperty::Date(old), PageProperty::Date(new)) => match (&old.date, &new.date) {
+            (Some(old), Some(new)) => {
+                old.start == new.start && old.end == new.end && old.time_zone == new.time_zone
+            }
+            (old, new) => old.is_none() && new.is_none(),
+        },
+        (PageProperty::Files(old), PageProperty::Files(new)) => {
+            old.files.len() == new.files.len()
+                && old
+                    .files
+                    .iter()
+                    .zip(&new.files)
+                    .all(|(old, new)| same_file(old, new))
+        }
+        (PageProperty::Checkbox(old), PageProperty::Checkbox(new)) => old.checkbox == new.checkbox,
+        (PageProperty::Number(old), PageProperty::Number(new)) => old.number == new.number,
+        (PageProperty::Url(old), PageProperty::Url(new)) => old.url == new.url,
+        (PageProperty::Email(old), PageProperty::Email(new)) => old.email == new.email,
+        (PageProperty::PhoneNumber(old), PageProperty::PhoneNumber(new)) => {
+            old.phone_number == new.phone_number
+        }
+        (PageProperty::Place(old), PageProperty::Place(new)) => old.place == new.place,
+        _ => false,
+    }
+}
+
+/// Notion returns IDs with dashes, but accepts them without.
+fn normalize_id(id: &str) -> String {
+    id.replace('-', "")
+}
+
+/// Compare rich text by content, merging adjacent text with the same style,
+/// since Notion may split or join it when storing it.
+fn same_rich_text(old: &[RichText], new: &[RichText]) -> bool {
+    #[derive(PartialEq)]
+    enum Segment<'a> {
+        Text {
+            content: String,
+            link: Option<&'a str>,
+            annotations: &'a RichTextAnnotations,
+        },
+        Other(&'a RichText),
+    }
+
+    fn segments(rich_text: &[RichText]) -> Vec<Segment<'_>> {
+        let mut segments = Vec::<Segment>::new();
+
+        for part in rich_text {
+            let RichText::Text {
+                text, annotations, ..
+            } = part
+            else {
+                segments.push(Segment::Other(part));
+                continue;
+            };
+
+            let link = text.link.as_ref().map(|link| link.url.as_str());
+
+            if let Some(Segment::Text {
+                content,
+                link: last_link,
+                annotations: last_annotations,
+            }) = segments.last_mut()
+            {
+                if *last_link == link && *last_annotations == annotations {
+                    content.push_str(&text.content);
+                    continue;
+                }
+            }
+
+            segments.push(Segment::Text {
+                content: text.content.clone(),
+                link,
+                annotations,
+            });
+        }
+
+        segments
+    }
+
+    fn same_segment(old: &Segment, new: &Segment) -> bool {
+        match (old, new) {
+            (
+                Segment::Other(RichText::Mention {
+                    mention: old_mention,
+                    annotations: old_annotations,
+                    plain_text: old_text,
+                    ..
+                }),
+                Segment::Other(RichText::Mention {
+                    mention: new_mention,
+                    annotations: new_annotations,
+                    plain_text: new_text,
+                    ..
+                }),
+            ) => {
+                old_annotations == new_annotations
+                    && match same_mention(old_mention, new_mention) {
+                        Some(same) => same,
+                        // Notion adds details such as titles to link mentions, so
+                        // two filled-in mentions fall back to their plain text.
+                        None => {
+                            old_mention == new_mention
+                                || (!old_text.is_empty() && old_text == new_text)
+                        }
+                    }
+            }
+            (
+                Segment::Other(RichText::Equation {
+                    equation: old_equation,
+                    annotations: old_annotations,
+                    ..
+                }),
+                Segment::Other(RichText::Equation {
+                    equation: new_equation,
+                    annotations: new_annotations,
+                    ..
+                }),
+            ) => old_equation == new_equation && old_annotations == new_annotations,
+            (old, new) => old == new,
+        }
+    }
+
+    /// Compare mentions of users, pages, databases, dates and emojis by what
+    /// they point at, or `None` for mentions without such an identity.
+    fn same_mention(old: &Mention, new: &Mention) -> Option<bool> {
+        let same = match (old, new) {
+            (Mention::User { user: old }, Mention::User { user: new }) => {
+                normalize_id(&old.id) == normalize_id(&new.id)
+            }
+            (Mention::Page { page: old }, Mention::Page { page: new }) => {
+                normalize_id(&old.id) == normalize_id(&new.id)
+            }
+            (Mention::Database { database: old }, Mention::Database { database: new }) => {
+                normalize_id(&old.id) == normalize_id(&new.id)
+            }
+            (Mention::Date { date: old }, Mention::Date { date: new }) => {
+                old.start == new.start && old.end == new.end && old.time_zone == new.time_zone
+            }
+            (
+                Mention::CustomEmoji { custom_emoji: old },
+                Mention::CustomEmoji { custom_emoji: new },
+            ) => old.id == new.id,
+            (Mention::LinkPreview { .. }, Mention::LinkPreview { .. })
+            | (Mention::LinkMention { .. }, Mention::LinkMention { .. })
+            | (Mention::TemplateMention { .. }, Mention::TemplateMention { .. }) => return None,
+            _ => false,
+        };
+
+        Some(same)
+    }
+
+    let old = segments(old);
+    let new = segments(new);
+
+    old.len() == new.len()
+        && old
+            .iter()
+            .zip(&new)
+            .all(|(old, new)| same_segment(old, new))
+}
+
+/// Files hosted by Notion are returned with a signed URL that changes on
+/// every request, so they are compared by name.
+fn same_file(old: &File, new: &File) -> bool {
+    match (old, new) {
+        (File::External(old), File::External(new)) => {
+            old.external.url == new.external.url && old.name == new.name
+        }
+        (File::NotionHosted(old), File::NotionHosted(new)) => old.name == new.name,
+        (File::ApiUploaded(old), File::ApiUploaded(new)) => {
+            old.file_upload.id == new.file_upload.id
+        }
+        _ => false,
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn fetched() -> HashMap<String, PageProperty> {
+        serde_json::from_str(
+            r#"
+            {
+                "Name": {
+                    "id": "title",
+                    "type": "title",
+                    "title": [
+                        {
+                            "type": "text",
+                            "text": { "content": "Write ", "link": null },
+                            "annotations": {
+                                "bold": false, "italic": false, "strikethrough": false,
+                                "underline": false, "code": false, "color": "default"
+                            },
+                            "plain_text": "Write ",
+                            "href": null
+                        },
+                        {
+                            "type": "text",
+                            "text": { "content": "docs", "link": null },
+                            "annotations": {
+                                "bold": false, "italic": false, "strikethrough": false,
+                                "underline": false, "code": false, "color": "default"
+                            },
+                            "plain_text": "docs",
+                            "href": null
+                        }
+                    ]
+                },
+                "Status": {
+                    "id": "s%3Ax",
+                    "type": "select",
+                    "select": { "id": "opt-1", "name": "Todo", "color": "red" }
+                },
+                "Owner": {
+                    "id": "o%3Ax",
+                    "type": "people",
+                    "people": [
+                        {
+                            "object": "user",
+                            "id": "6794760a-1f15-45cd-9c65-0dfe42f5135a",
+                            "name": "Aya",
+                            "type": "person"
+                        }
+                    ]
+                },
+                "Points": { "id": "p%3Ax", "type": "number", "number": 3 },
+                "Formula": {
+                    "id": "f%3Ax",
+                    "type": "formula",
+                    "formula": { "type": "number", "number": 6 }
+                }
+            }
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn desired(status: &str) -> HashMap<String, PageProperty> {
+        serde_json::from_value(serde_json::json!({
+            "Name": { "type": "title", "title": [{ "type": "text", "text": { "content": "Write docs", "link": null }, "annotations": { "bold": false, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default" }, "plain_text": "Write docs", "href": null }] },
+            "Status": { "type": "select", "select": { "name": status } },
+            "Owner": { "type": "people", "people": [{ "object": "user", "id": "6794760a1f1545cd9c650dfe42f5135a" }] },
+            "Points": { "type": "number", "number": 3 }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn same_values_have_no_diff() {
+        let diff = PropertyDiff::between(&fetched(), &desired("Todo"));
+
+        assert!(diff.added.is_empty());
+        assert!(diff.changed.is_empty());
+        assert_eq!(diff.removed.len(), 0, "read-only properties are ignored");
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn changed_value_is_the_only_update() {
+        let mut desired = desired("Done");
+        desired.insert(
+            "Estimate".to_string(),
+            serde_json::from_value(serde_json::json!({ "type": "number", "number": 5 })).unwrap(),
+        );
+        desired.remove("Points");
+
+        let diff = PropertyDiff::between(&fetched(), &desired);
+
+        let change = &diff.changed["Status"];
+        assert_eq!(change.old.to_string(), "Todo");
+        assert_eq!(change.new.to_string(), "Done");
+        assert_eq!(diff.changed.len(), 1);
+        assert!(diff.added.contains_key("Estimate"));
+        assert!(diff.removed.contains_key("Points"));
+
+        let mut names = diff.update_properties().into_keys().collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, vec!["Estimate", "Status"]);
+    }
+
+    #[test]
+    fn diff_properties_keeps_missing_properties() {
+        let page = serde_json::from_str::<PageResponse>(include_str!("./seeds/page.json")).unwrap();
+
+        let diff = page.diff_properties(&HashMap::new());
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn date_time_zone_is_compared() {
+        let date = |time_zone: Option<&str>| {
+            let mut property = serde_json::from_value::<PageProperty>(serde_json::json!({
+                "type": "date",
+                "date": { "start": "2024-04-04", "end": null }
+            }))
+            .unwrap();
+            if let PageProperty::Date(crate::object::page::date::PageDateProperty {
+                date: Some(date),
+                ..
+            }) = &mut property
+            {
+                date.time_zone = time_zone.map(String::from);
+            }
+            HashMap::from([("Due".to_string(), property)])
+        };
+
+        assert!(PropertyDiff::between(&date(None), &date(None)).is_empty());
+        assert!(
+            PropertyDiff::between(&date(Some("Asia/Tokyo")), &date(Some("Asia/Tokyo"))).is_empty()
+        );
+
+        let diff = PropertyDiff::between(&date(None), &date(Some("Asia/Tokyo")));
+        assert!(diff.changed.contains_key("Due"));
+    }
+
+    #[test]
+    fn mentions_are_compared_by_target() {
+        let mention = |id: &str, plain_text: &str| {
+            let property = serde_json::from_value::<PageProperty>(serde_json::json!({
+                "type": "rich_text",
+                "rich_text": [{
+                    "type": "mention",
+                    "mention": { "type": "page", "page": { "id": id } },
+                    "annotations": {
+                        "bold": false, "italic": false, "strikethrough": false,
+                        "underline": false, "code": false, "color": "default"
+                    },
+                    "plain_text": plain_text,
+                    "href": null
+                }]
+            }))
+            .unwrap();
+            HashMap::from([("Link".to_string(), property)])
+        };
+
+        let fetched = mention("6794760a-1f15-45cd-9c65-0dfe42f5135a", "Roadmap");
+
+        assert!(
+            PropertyDiff::between(&fetched, &mention("6794760a1f1545cd9c650dfe42f5135a", ""))
+                .is_empty()
+        );
+
+        let diff = PropertyDiff::between(
+            &fetched,
+            &mention("0a1b2c3d-1f15-45cd-9c65-0dfe42f5135a", "Roadmap"),
+        );
+        assert!(
+            diff.changed.contains_key("Link"),
+            "same title, different page"
+        );
+    }
+}
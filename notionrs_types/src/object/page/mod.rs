@@ -10,6 +10,7 @@ pub mod checkbox;
 pub mod created_by;
 pub mod created_time;
 pub mod date;
+pub mod diff;
 pub mod email;
 pub mod files;
 pub mod formula;
@@ -20,6 +21,7 @@ pub mod number;
 pub mod people;
 pub mod phone_number;
 pub mod place;
+pub mod property_item;
 pub mod relation;
 pub mod rich_text;
 pub mod rollup;
@@ -90,6 +92,25 @@ impl std::fmt::Display for PageProperty {
     }
 }
 
+impl PageProperty {
+    /// Whether Notion computes the property, so it can't be set when creating
+    /// or updating a page.
+    pub fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            PageProperty::Button(_)
+                | PageProperty::CreatedBy(_)
+                | PageProperty::CreatedTime(_)
+                | PageProperty::Formula(_)
+                | PageProperty::LastEditedBy(_)
+                | PageProperty::LastEditedTime(_)
+                | PageProperty::Rollup(_)
+                | PageProperty::UniqueId(_)
+                | PageProperty::Verification(_)
+        )
+    }
+}
+
 /// <https://developers.notion.com/reference/page>
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PageResponse<T = std::collections::HashMap<String, crate::object::page::PageProperty>>
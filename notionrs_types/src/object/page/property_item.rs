@@ -0,0 +1,391 @@
+use serde::{Deserialize, Serialize};
+
+use super::PageProperty;
+
+/// Response from `GET /v1/pages/:page_id/properties/:property_id`.
+///
+/// <https://developers.notion.com/reference/retrieve-a-page-property>
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum PropertyItemResponse {
+    /// The value of a property with a single value, such as `number` or `select`.
+    PropertyItem(PageProperty),
+
+    /// One page of the values of a `title`, `rich_text`, `relation`, `people`
+    /// or `rollup` property.
+    List(PropertyItemList),
+}
+
+/// One page of a paginated property item list.
+///
+/// Notion returns at most 25 items per page. Collect every page with
+/// [`PropertyItemList::extend`], then assemble the property with
+/// [`PropertyItemList::into_property`].
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PropertyItemList {
+    pub results: Vec<PropertyItem>,
+
+    pub next_cursor: Option<String>,
+
+    pub has_more: bool,
+
+    /// The property the items belong to.
+    pub property_item: PropertyItemListProperty,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+/// The property described by a [`PropertyItemList`].
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PropertyItemListProperty {
+    /// The ID of the property.
+    pub id: String,
+
+    /// The property type, e.g. `"relation"`.
+    pub r#type: String,
+
+    /// URL of the next page of items, if any.
+    #[serde(default)]
+    pub next_url: Option<String>,
+
+    /// For `rollup` properties, the value of the rollup. It is only final on
+    /// the last page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<super::rollup::Rollup>,
+}
+
+/// An item of a [`PropertyItemList`].
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(untagged)]
+pub enum PropertyItem {
+    /// A single value of a paginated property.
+    Paginated(PaginatedPropertyItem),
+
+    /// Any other property value, e.g. a `number` aggregated by an array rollup.
+    Property(PageProperty),
+}
+
+/// A single value of a `title`, `rich_text`, `relation` or `people` property.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaginatedPropertyItem {
+    Title {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        title: crate::object::rich_text::RichText,
+    },
+    RichText {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        rich_text: crate::object::rich_text::RichText,
+    },
+    Relation {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        relation: super::relation::PageRelationPropertyParameter,
+    },
+    People {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        people: crate::object::user::User,
+    },
+}
+
+impl PropertyItemList {
+    /// Append the next page of items.
+    pub fn extend(&mut self, next: PropertyItemList) {
+        self.results.extend(next.results);
+        self.next_cursor = next.next_cursor;
+        self.has_more = next.has_more;
+        self.property_item = next.property_item;
+    }
+
+    /// Assemble the items into the property value, e.g. a
+    /// [`PageRelationProperty`](super::relation::PageRelationProperty) with
+    /// every related page.
+    ///
+    /// Returns `None` when the list is of a type that isn't paginated.
+    pub fn into_property(self) -> Option<PageProperty> {
+        let id = Some(self.property_item.id);
+
+        let property = match self.property_item.r#type.as_str() {
+            "title" => PageProperty::Title(super::title::PageTitleProperty {
+                id,
+                title: self
+                    .results
+                    .into_iter()
+                    .filter_map(|item| match item {
+                        PropertyItem::Paginated(PaginatedPropertyItem::Title { title, .. }) => {
+                            Some(title)
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+            }),
+            "rich_text" => PageProperty::RichText(super::rich_text::PageRichTextProperty {
+                id,
+                rich_text: self
+                    .results
+                    .into_iter()
+                    .filter_map(|item| match item {
+                        PropertyItem::Paginated(PaginatedPropertyItem::RichText {
+                            rich_text,
+                            ..
+                        }) => Some(rich_text),
+                        _ => None,
+                    })
+                    .collect(),
+            }),
+            "relation" => PageProperty::Relation(super::relation::PageRelationProperty {
+                id,
+                relation: self
+                    .results
+                    .into_iter()
+                    .filter_map(|item| match item {
+                        PropertyItem::Paginated(PaginatedPropertyItem::Relation {
+                            relation,
+                            ..
+                        }) => Some(relation),
+                        _ => None,
+                    })
+                    .collect(),
+                has_more: self.has_more,
+            }),
+            "people" => PageProperty::People(super::people::PagePeopleProperty {
+                id,
+                people: self
+                    .results
+                    .into_iter()
+                    .filter_map(|item| match item {
+                        PropertyItem::Paginated(PaginatedPropertyItem::People {
+                            people, ..
+                        }) => Some(people),
+                        _ => None,
+                    })
+                    .collect(),
+            }),
+            "rollup" => {
+                let mut rollup = self.property_item.rollup?;
+
+                if let super::rollup::Rollup::Array(array) = &mut rollup {
+                    array.array = self
+                        .results
+                        .into_iter()
+                        .map(|item| super::rollup::RollupArrayItem::Property(item.into()))
+                        .collect();
+                }
+
+                PageProperty::Rollup(super::rollup::PageRollupProperty { id, rollup })
+            }
+            _ => return None,
+        };
+
+        Some(property)
+    }
+}
+
+impl From<PropertyItem> for PageProperty {
+    /// Wraps a single paginated value into a property holding just that value.
+    fn from(item: PropertyItem) -> Self {
+        match item {
+            PropertyItem::Property(property) => property,
+            PropertyItem::Paginated(PaginatedPropertyItem::Title { id, title }) => {
+                PageProperty::Title(super::title::PageTitleProperty {
+                    id,
+                    title: vec![title],
+                })
+            }
+            PropertyItem::Paginated(PaginatedPropertyItem::RichText { id, rich_text }) => {
+                PageProperty::RichText(super::rich_text::PageRichTextProperty {
+                    id,
+                    rich_text: vec![rich_text],
+                })
+            }
+            PropertyItem::Paginated(PaginatedPropertyItem::Relation { id, relation }) => {
+                PageProperty::Relation(super::relation::PageRelationProperty {
+                    id,
+                    relation: vec![relation],
+                    has_more: false,
+                })
+            }
+            PropertyItem::Paginated(PaginatedPropertyItem::People { id, people }) => {
+                PageProperty::People(super::people::PagePeopleProperty {
+                    id,
+                    people: vec![people],
+                })
+            }
+        }
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_property_item() {
+        let json_data = r#"
+        {
+            "object": "property_item",
+            "id": "kjPO",
+            "type": "number",
+            "number": 2
+        }
+        "#;
+
+        let response = serde_json::from_str::<PropertyItemResponse>(json_data).unwrap();
+
+        match response {
+            PropertyItemResponse::PropertyItem(PageProperty::Number(number)) => {
+                assert_eq!(number.number, Some(2.0));
+            }
+            other => panic!("Expected a number property item, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn assemble_relation_pages() {
+        let page = |ids: &[&str], next_cursor: Option<&str>| {
+            serde_json::json!({
+                "object": "list",
+                "results": ids
+                    .iter()
+                    .map(|id| serde_json::json!({
+                        "object": "property_item",
+                        "id": "vYdV",
+                        "type": "relation",
+                        "relation": { "id": id }
+                    }))
+                    .collect::<Vec<_>>(),
+                "next_cursor": next_cursor,
+                "has_more": next_cursor.is_some(),
+                "type": "property_item",
+                "property_item": {
+                    "id": "vYdV",
+                    "next_url": null,
+                    "type": "relation",
+                    "relation": {}
+                }
+            })
+        };
+
+        let list =
+            |value: serde_json::Value| match serde_json::from_value::<PropertyItemResponse>(value)
+                .unwrap()
+            {
+                PropertyItemResponse::List(list) => list,
+                other => panic!("Expected a list, got {other:?}"),
+            };
+
+        let mut relation = list(page(&["page-1", "page-2"], Some("cursor")));
+        relation.extend(list(page(&["page-3"], None)));
+
+        match relation.into_property() {
+            Some(PageProperty::Relation(relation)) => {
+                assert_eq!(relation.id.as_deref(), Some("vYdV"));
+                assert_eq!(relation.to_string(), "page-1, page-2, page-3");
+                assert!(!relation.has_more);
+            }
+            other => panic!("Expected a relation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn assemble_title() {
+        let json_data = r#"
+        {
+            "object": "list",
+            "results": [
+                {
+                    "object": "property_item",
+                    "id": "title",
+                    "type": "title",
+                    "title": {
+                        "type": "text",
+                        "text": { "content": "Hello, ", "link": null },
+                        "annotations": {
+                            "bold": false, "italic": false, "strikethrough": false,
+                            "underline": false, "code": false, "color": "default"
+                        },
+                        "plain_text": "Hello, ",
+                        "href": null
+                    }
+                },
+                {
+                    "object": "property_item",
+                    "id": "title",
+                    "type": "title",
+                    "title": {
+                        "type": "text",
+                        "text": { "content": "world", "link": null },
+                        "annotations": {
+                            "bold": true, "italic": false, "strikethrough": false,
+                            "underline": false, "code": false, "color": "default"
+                        },
+                        "plain_text": "world",
+                        "href": null
+                    }
+                }
+            ],
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": { "id": "title", "next_url": null, "type": "title", "title": {} },
+            "request_id": "8c5a8a47-3a27-4bd6-9bd5-8e2f1a1f5c2e"
+        }
+        "#;
+
+        let PropertyItemResponse::List(list) =
+            serde_json::from_str::<PropertyItemResponse>(json_data).unwrap()
+        else {
+            panic!("Expected a list");
+        };
+
+        let property = list.into_property().unwrap();
+        assert!(matches!(property, PageProperty::Title(_)));
+        assert_eq!(property.to_string(), "Hello, world");
+    }
+
+    #[test]
+    fn assemble_rollup() {
+        let json_data = r#"
+        {
+            "object": "list",
+            "results": [
+                { "object": "property_item", "id": "aBcD", "type": "number", "number": 3 },
+                { "object": "property_item", "id": "aBcD", "type": "number", "number": 4 }
+            ],
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": {
+                "id": "aBcD",
+                "next_url": null,
+                "type": "rollup",
+                "rollup": { "type": "array", "array": [], "function": "show_original" }
+            }
+        }
+        "#;
+
+        let PropertyItemResponse::List(list) =
+            serde_json::from_str::<PropertyItemResponse>(json_data).unwrap()
+        else {
+            panic!("Expected a list");
+        };
+
+        match list.into_property() {
+            Some(PageProperty::Rollup(rollup)) => match rollup.rollup {
+                super::super::rollup::Rollup::Array(array) => assert_eq!(array.array.len(), 2),
+                other => panic!("Expected an array rollup, got {other:?}"),
+            },
+            other => panic!("Expected a rollup, got {other:?}"),
+        }
+    }
+}
@@ -16,7 +16,7 @@ pub enum Parent {
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct DatabaseParent {
     /// always "database_id"
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub r#type: String,
     pub database_id: String,
 }
@@ -43,7 +43,7 @@ impl From<String> for DatabaseParent {
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct DataSourceParent {
     /// always "data_source_id"
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub r#type: String,
     pub data_source_id: String,
 }
@@ -96,7 +96,7 @@ impl From<String> for PageParent {
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct WorkspaceParent {
     /// always "workspace"
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub r#type: String,
     /// always `true`
     pub workspace: bool,
@@ -106,7 +106,7 @@ pub struct WorkspaceParent {
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct BlockParent {
     /// always "block_id"
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub r#type: String,
     pub block_id: String,
 }
@@ -133,7 +133,7 @@ impl From<String> for BlockParent {
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct AgentIdParent {
     /// always "agent_id"
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub r#type: String,
     pub agent_id: String,
 }
@@ -158,6 +158,16 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn request_parents_round_trip() {
+        let parent = Parent::DataSourceParent(DataSourceParent::from("ds1"));
+        let json = serde_json::to_string(&parent).unwrap();
+        assert_eq!(json, r#"{"data_source_id":"ds1"}"#);
+
+        let parent = serde_json::from_str::<Parent>(&json).unwrap();
+        assert!(matches!(parent, Parent::DataSourceParent(p) if p.data_source_id == "ds1"));
+    }
+
     #[test]
     fn deserialize_block_parent() {
         let json = r#"{"type":"block_id","block_id":"block-abc"}"#;
@@ -20,8 +20,8 @@ pub struct Filter {
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
     pub condition: Option<Condition>,
 
-    /// Always either a "created_time" or null.
-    /// When it's "created_time", apply a timestamp filter.
+    /// Either "created_time", "last_edited_time" or null.
+    /// When it's set, apply a timestamp filter.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<String>,
 }
@@ -50,6 +50,7 @@ pub enum Condition {
     Status(StatusFilter),
     #[serde(rename = "created_time")]
     Timestamp(Box<TimestampFilter>),
+    LastEditedTime(Box<TimestampFilter>),
     UniqueId(UniqueIdFilter),
     Verification(VerificationFilter),
 }
@@ -2844,6 +2845,66 @@ impl Filter {
         }
     }
 
+    /// Returns database entries that were last edited after the provided timestamp.
+    ///
+    /// - `timestamp`: ISO 8601 timestamp or a relative date value (e.g. `"today"`, `"tomorrow"`)
+    ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`, `"today"`
+    pub fn last_edited_time_after<T: Into<DateOrRelativeDate>>(timestamp: T) -> Self {
+        Filter {
+            condition: Some(Condition::LastEditedTime(Box::new(TimestampFilter {
+                after: Some(timestamp.into()),
+                ..Default::default()
+            }))),
+            timestamp: Some("last_edited_time".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns database entries that were last edited before the provided timestamp.
+    ///
+    /// - `timestamp`: ISO 8601 timestamp or a relative date value (e.g. `"today"`, `"tomorrow"`)
+    ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`, `"today"`
+    pub fn last_edited_time_before<T: Into<DateOrRelativeDate>>(timestamp: T) -> Self {
+        Filter {
+            condition: Some(Condition::LastEditedTime(Box::new(TimestampFilter {
+                before: Some(timestamp.into()),
+                ..Default::default()
+            }))),
+            timestamp: Some("last_edited_time".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns database entries that were last edited on or after the provided timestamp.
+    ///
+    /// - `timestamp`: ISO 8601 timestamp or a relative date value (e.g. `"today"`, `"tomorrow"`)
+    ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`, `"today"`
+    pub fn last_edited_time_on_or_after<T: Into<DateOrRelativeDate>>(timestamp: T) -> Self {
+        Filter {
+            condition: Some(Condition::LastEditedTime(Box::new(TimestampFilter {
+                on_or_after: Some(timestamp.into()),
+                ..Default::default()
+            }))),
+            timestamp: Some("last_edited_time".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns database entries that were last edited on or before the provided timestamp.
+    ///
+    /// - `timestamp`: ISO 8601 timestamp or a relative date value (e.g. `"today"`, `"tomorrow"`)
+    ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`, `"today"`
+    pub fn last_edited_time_on_or_before<T: Into<DateOrRelativeDate>>(timestamp: T) -> Self {
+        Filter {
+            condition: Some(Condition::LastEditedTime(Box::new(TimestampFilter {
+                on_or_before: Some(timestamp.into()),
+                ..Default::default()
+            }))),
+            timestamp: Some("last_edited_time".to_string()),
+            ..Default::default()
+        }
+    }
+
     // # --------------------------------------------------------------------------------
     //
     // ID unique_id <https://developers.notion.com/reference/post-database-query-filter#id>
@@ -3609,6 +3670,23 @@ mod unit_tests {
         check(Filter::timestamp_this_week());
     }
 
+    #[test]
+    fn last_edited_time_filters() {
+        check(Filter::last_edited_time_after("2024-01-01"));
+        check(Filter::last_edited_time_before("2024-01-01"));
+        check(Filter::last_edited_time_on_or_after("2024-01-01"));
+        check(Filter::last_edited_time_on_or_before("2024-01-01"));
+
+        let json = serde_json::to_value(Filter::last_edited_time_before("2024-01-01")).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "timestamp": "last_edited_time",
+                "last_edited_time": { "before": "2024-01-01" }
+            })
+        );
+    }
+
     #[test]
     fn unique_id_filters() {
         check(Filter::unique_id_equals("u", 1));
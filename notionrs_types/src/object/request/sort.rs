@@ -2,13 +2,39 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Default, notionrs_macro::Setter)]
 pub struct Sort {
-    /// Specify the name of the property
-    pub property: String,
+    /// Specify the property or timestamp to sort by
+    #[serde(flatten)]
+    pub target: SortTarget,
 
     /// Specify the sort order
     pub direction: SortDirection,
 }
 
+/// What a [`Sort`] orders pages by.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortTarget {
+    /// The name of a property
+    Property(String),
+
+    /// A timestamp of the page
+    Timestamp(SortTimestamp),
+}
+
+impl Default for SortTarget {
+    fn default() -> Self {
+        SortTarget::Property(String::new())
+    }
+}
+
+/// A page timestamp to sort by.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortTimestamp {
+    CreatedTime,
+    LastEditedTime,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum SortDirection {
@@ -25,7 +51,7 @@ impl Sort {
         T: AsRef<str>,
     {
         Self {
-            property: property_name.as_ref().to_string(),
+            target: SortTarget::Property(property_name.as_ref().to_string()),
             direction: SortDirection::Ascending,
         }
     }
@@ -37,10 +63,52 @@ impl Sort {
         T: AsRef<str>,
     {
         Self {
-            property: property_name.as_ref().to_string(),
+            target: SortTarget::Property(property_name.as_ref().to_string()),
             direction: SortDirection::Descending,
         }
     }
+
+    /// Helper function to sort by when pages were created, oldest first
+    pub fn created_time_asc() -> Self {
+        Self::by_timestamp(SortTimestamp::CreatedTime, SortDirection::Ascending)
+    }
+
+    /// Helper function to sort by when pages were created, newest first
+    pub fn created_time_desc() -> Self {
+        Self::by_timestamp(SortTimestamp::CreatedTime, SortDirection::Descending)
+    }
+
+    /// Helper function to sort by when pages were last edited, least recent first
+    pub fn last_edited_time_asc() -> Self {
+        Self::by_timestamp(SortTimestamp::LastEditedTime, SortDirection::Ascending)
+    }
+
+    /// Helper function to sort by when pages were last edited, most recent first
+    pub fn last_edited_time_desc() -> Self {
+        Self::by_timestamp(SortTimestamp::LastEditedTime, SortDirection::Descending)
+    }
+
+    /// Sort by the property with the given name
+    pub fn property<T>(mut self, property_name: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.target = SortTarget::Property(property_name.as_ref().to_string());
+        self
+    }
+
+    /// Sort by a timestamp of the page instead of a property
+    pub fn timestamp(mut self, timestamp: SortTimestamp) -> Self {
+        self.target = SortTarget::Timestamp(timestamp);
+        self
+    }
+
+    fn by_timestamp(timestamp: SortTimestamp, direction: SortDirection) -> Self {
+        Self {
+            target: SortTarget::Timestamp(timestamp),
+            direction,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -84,6 +152,20 @@ mod unit_tests {
         let s = Sort::default()
             .property("p")
             .direction(SortDirection::Descending);
-        assert_eq!(s.property, "p");
+        assert_eq!(s.target, SortTarget::Property("p".to_string()));
+    }
+
+    #[test]
+    fn serialize_timestamp_sort() {
+        assert_eq!(
+            serde_json::to_value(Sort::last_edited_time_desc()).unwrap(),
+            serde_json::json!({ "timestamp": "last_edited_time", "direction": "descending" })
+        );
+
+        let sort = serde_json::from_value::<Sort>(
+            serde_json::json!({ "timestamp": "created_time", "direction": "ascending" }),
+        )
+        .unwrap();
+        assert_eq!(sort, Sort::created_time_asc());
     }
 }
@@ -5,7 +5,7 @@ pub use crate::object::{
         bulleted_list_item::*, callout::*, child_database::*, child_page::*, code::*, column::*,
         column_list::*, embed::*, equation::*, heading::*, link_preview::*, numbered_list_item::*,
         paragraph::*, quote::*, synced_block::*, tab::*, table::*, table_of_contents::*,
-        table_row::*, template::*, to_do::*, toggle::*, transcription::*,
+        table_row::*, template::*, to_do::*, toggle::*, transcription::*, tree::*,
     },
     data_source::{
         DataSourceProperty, button::*, checkbox::*, created_by::*, created_time::*, date::*,
@@ -16,9 +16,9 @@ pub use crate::object::{
     database::DatabaseResponse,
     page::{
         PageProperty, PageResponse, button::*, checkbox::*, created_by::*, created_time::*,
-        date::*, email::*, files::*, formula::*, last_edited_by::*, last_edited_time::*,
-        multi_select::*, number::*, people::*, phone_number::*, place::*, relation::*,
-        rich_text::*, rollup::*, select::*, status::*, title::*, unique_id::*, url::*,
+        date::*, diff::*, email::*, files::*, formula::*, last_edited_by::*, last_edited_time::*,
+        multi_select::*, number::*, people::*, phone_number::*, place::*, property_item::*,
+        relation::*, rich_text::*, rollup::*, select::*, status::*, title::*, unique_id::*, url::*,
         verification::*,
     },
     request::{filter::*, meeting_notes::*, search::*, sort::*},
@@ -27,6 +27,6 @@ pub use crate::object::{
 
 pub use crate::object::{
     async_task::*, color::*, comment::*, date::*, emoji::*, emoji_and_icon::*, file::*,
-    file_upload::*, icon::*, language::*, page_markdown::*, parent::*, response::*, select::*,
-    user::*, view::*,
+    file_upload::*, icon::*, language::*, oauth::*, page_markdown::*, parent::*, response::*,
+    select::*, user::*, view::*,
 };